@@ -0,0 +1,14 @@
+#version 330 core
+out vec4 FragColor;
+
+in vec2 TexCoords;
+
+uniform sampler2D screenTexture;
+uniform sampler2D bloomTexture;
+uniform float intensity;
+
+void main() {
+    vec4 color = texture(screenTexture, TexCoords);
+    vec3 bloom = texture(bloomTexture, TexCoords).rgb;
+    FragColor = vec4(color.rgb + bloom * intensity, color.a);
+}
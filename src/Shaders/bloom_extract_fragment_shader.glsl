@@ -0,0 +1,21 @@
+#version 330 core
+out vec4 FragColor;
+
+in vec2 TexCoords;
+
+uniform sampler2D screenTexture;
+uniform float threshold;
+uniform float softKnee;
+
+void main() {
+    vec3 color = texture(screenTexture, TexCoords).rgb;
+    float brightness = max(color.r, max(color.g, color.b));
+
+    // Soft threshold so that pixels fade into the bloom instead of popping in
+    float knee = threshold * softKnee;
+    float soft = clamp(brightness - threshold + knee, 0.0, 2.0 * knee);
+    soft = soft * soft / (4.0 * knee + 0.00001);
+    float contribution = max(soft, brightness - threshold) / max(brightness, 0.00001);
+
+    FragColor = vec4(color * contribution, 1.0);
+}
@@ -0,0 +1,22 @@
+#version 330 core
+out vec4 FragColor;
+
+in vec2 TexCoords;
+
+uniform sampler2D screenTexture;
+uniform bool horizontal;
+
+const float weights[5] = float[](0.227027, 0.1945946, 0.1216216, 0.054054, 0.016216);
+
+void main() {
+    vec2 texelSize = 1.0 / vec2(textureSize(screenTexture, 0));
+    vec2 direction = horizontal ? vec2(texelSize.x, 0.0) : vec2(0.0, texelSize.y);
+
+    vec3 result = texture(screenTexture, TexCoords).rgb * weights[0];
+    for (int i = 1; i < 5; ++i) {
+        result += texture(screenTexture, TexCoords + direction * float(i)).rgb * weights[i];
+        result += texture(screenTexture, TexCoords - direction * float(i)).rgb * weights[i];
+    }
+
+    FragColor = vec4(result, 1.0);
+}
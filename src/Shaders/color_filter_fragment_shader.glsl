@@ -0,0 +1,24 @@
+#version 330 core
+out vec4 FragColor;
+
+in vec2 TexCoords;
+
+uniform sampler2D screenTexture;
+uniform int mode;  // 0 = grayscale, 1 = sepia
+uniform float strength;
+
+void main() {
+    vec4 color = texture(screenTexture, TexCoords);
+    vec3 filtered;
+
+    if (mode == 1) {
+        filtered = vec3(
+            dot(color.rgb, vec3(0.393, 0.769, 0.189)),
+            dot(color.rgb, vec3(0.349, 0.686, 0.168)),
+            dot(color.rgb, vec3(0.272, 0.534, 0.131)));
+    } else {
+        filtered = vec3(dot(color.rgb, vec3(0.2126, 0.7152, 0.0722)));
+    }
+
+    FragColor = vec4(mix(color.rgb, filtered, strength), color.a);
+}
@@ -0,0 +1,10 @@
+#version 330 core
+out vec4 FragColor;
+
+in vec4 VertexColor;
+
+uniform vec4 color;  // Fill color of the shape, multiplied by its per-vertex colors
+
+void main() {
+    FragColor = color * VertexColor;
+}
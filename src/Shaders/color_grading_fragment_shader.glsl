@@ -0,0 +1,29 @@
+#version 330 core
+out vec4 FragColor;
+
+in vec2 TexCoords;
+
+uniform sampler2D screenTexture;
+uniform sampler2D lutTexture;  // size*size x size strip, blue selects the slice
+uniform float lutSize;
+uniform float strength;
+
+vec3 sampleLut(vec3 color) {
+    float blue = color.b * (lutSize - 1.0);
+    float sliceLow = floor(blue);
+    float sliceHigh = min(sliceLow + 1.0, lutSize - 1.0);
+
+    // Sample texel centers so neighbouring slices do not bleed into each other
+    float x = (color.r * (lutSize - 1.0) + 0.5) / (lutSize * lutSize);
+    float y = (color.g * (lutSize - 1.0) + 0.5) / lutSize;
+
+    vec3 low = texture(lutTexture, vec2(x + sliceLow / lutSize, y)).rgb;
+    vec3 high = texture(lutTexture, vec2(x + sliceHigh / lutSize, y)).rgb;
+    return mix(low, high, blue - sliceLow);
+}
+
+void main() {
+    vec4 color = texture(screenTexture, TexCoords);
+    vec3 graded = sampleLut(clamp(color.rgb, 0.0, 1.0));
+    FragColor = vec4(mix(color.rgb, graded, strength), color.a);
+}
@@ -0,0 +1,19 @@
+#version 330 core
+layout (location = 0) in vec3 aPos;
+layout (location = 2) in vec4 aColor;
+
+out vec4 VertexColor;
+
+uniform mat4 model;
+uniform mat4 projection;
+uniform bool linearColors;
+
+// Converts an sRGB color to linear space so per-vertex colors are interpolated and blended linearly
+vec3 srgbToLinear(vec3 c) {
+    return mix(c / 12.92, pow((c + 0.055) / 1.055, vec3(2.4)), greaterThan(c, vec3(0.04045)));
+}
+
+void main() {
+    gl_Position = projection * model * vec4(aPos, 1.0);
+    VertexColor = linearColors ? vec4(srgbToLinear(aColor.rgb), aColor.a) : aColor;
+}
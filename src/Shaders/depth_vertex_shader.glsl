@@ -0,0 +1,13 @@
+#version 330 core
+layout (location = 0) in vec3 aPos;
+// Per-instance transform, laid out as in instanced_vertex_shader.glsl; only read when instanced is set
+layout (location = 3) in mat4 aInstanceModel;
+
+uniform mat4 lightSpace;
+uniform mat4 model;
+uniform bool instanced;
+
+void main() {
+    mat4 world = instanced ? model * aInstanceModel : model;
+    gl_Position = lightSpace * world * vec4(aPos, 1.0);
+}
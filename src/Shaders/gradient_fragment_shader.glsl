@@ -3,6 +3,13 @@ out vec4 FragColor;
 
 in vec2 TexCoords;
 
+uniform bool linearColors;
+
+// Converts an sRGB color to linear space when a post effect encodes the output to sRGB
+vec3 srgbToLinear(vec3 c) {
+    return mix(c / 12.92, pow((c + 0.055) / 1.055, vec3(2.4)), greaterThan(c, vec3(0.04045)));
+}
+
 void main() {
     // Create a vertical gradient from orange to blue
     vec3 topColor = vec3(1.0, 0.5, 0.2); // Orange
@@ -11,5 +18,5 @@ void main() {
     // Interpolate between the two colors based on the y-coordinate
     vec3 gradientColor = mix(bottomColor, topColor, TexCoords.y);
     
-    FragColor = vec4(gradientColor, 1.0);
+    FragColor = vec4(linearColors ? srgbToLinear(gradientColor) : gradientColor, 1.0);
 }
\ No newline at end of file
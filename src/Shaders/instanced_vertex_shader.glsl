@@ -0,0 +1,40 @@
+#version 330 core
+layout (location = 0) in vec3 aPos;
+layout (location = 1) in vec3 aNormal;
+layout (location = 2) in vec2 aTexCoord;
+// Per-instance attributes, advanced once per instance
+layout (location = 3) in mat4 aInstanceModel;  // Occupies locations 3-6
+layout (location = 7) in vec4 aInstanceColor;
+layout (location = 8) in vec4 aInstanceData;
+
+uniform mat4 model;
+uniform mat4 view;
+uniform mat4 projection;
+uniform bool linearColors;
+
+out vec3 FragPos;
+out vec3 Normal;
+out float ViewDepth;
+out vec4 InstanceColor;
+out vec4 InstanceData;
+out vec2 TexCoords;
+
+// Converts an sRGB color to linear space so instance colors match the other shapes' colors
+vec3 srgbToLinear(vec3 c) {
+    return mix(c / 12.92, pow((c + 0.055) / 1.055, vec3(2.4)), greaterThan(c, vec3(0.04045)));
+}
+
+void main() {
+    mat4 world = model * aInstanceModel;
+    vec4 worldPos = world * vec4(aPos, 1.0);
+    vec4 viewPos = view * worldPos;
+
+    FragPos = worldPos.xyz;
+    Normal = mat3(transpose(inverse(world))) * aNormal;
+    ViewDepth = -viewPos.z;
+    InstanceColor = linearColors ? vec4(srgbToLinear(aInstanceColor.rgb), aInstanceColor.a) : aInstanceColor;
+    InstanceData = aInstanceData;
+    TexCoords = aTexCoord;
+
+    gl_Position = projection * viewPos;
+}
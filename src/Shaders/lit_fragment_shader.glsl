@@ -0,0 +1,154 @@
+#version 330 core
+#define MAX_SPOT_LIGHTS 4
+#define MAX_CASCADES 4
+
+out vec4 FragColor;
+
+in vec3 FragPos;
+in vec3 Normal;
+in float ViewDepth;
+in vec4 InstanceColor;  // Multiplies the diffuse color: the instance color, or the shape's tint and vertex colors
+in vec2 TexCoords;
+
+struct Material {
+    vec3 diffuse;
+    vec3 specular;
+    float shininess;
+};
+
+struct DirLight {
+    bool enabled;
+    vec3 direction;
+    vec3 color;
+};
+
+struct SpotLight {
+    vec3 position;
+    vec3 direction;
+    vec3 color;
+    float innerCos;
+    float outerCos;
+    float range;
+};
+
+struct DirShadow {
+    bool enabled;
+    int cascadeCount;
+    float splits[MAX_CASCADES];
+    mat4 matrices[MAX_CASCADES];
+    float bias;
+    float normalBias;
+    int pcfRadius;
+};
+
+struct SpotShadow {
+    bool enabled;
+    mat4 matrix;
+    float bias;
+    float normalBias;
+    int pcfRadius;
+};
+
+uniform Material material;
+uniform vec3 ambient;
+uniform vec3 viewPos;
+
+uniform DirLight dirLight;
+uniform SpotLight spotLights[MAX_SPOT_LIGHTS];
+uniform int spotLightCount;
+
+uniform DirShadow dirShadow;
+uniform SpotShadow spotShadows[MAX_SPOT_LIGHTS];
+uniform bool useDiffuseMap;
+uniform sampler2D diffuseMap;  // Base color texture, multiplies the diffuse color when useDiffuseMap is set
+uniform sampler2DArray dirShadowMap;
+uniform sampler2DArray spotShadowMap;
+
+// Percentage-closer filtering over a (2r+1)^2 texel kernel, returns 1.0 when fully lit
+float sampleShadow(sampler2DArray shadowMap, int layer, vec4 lightSpacePos, float bias, int radius) {
+    vec3 coords = lightSpacePos.xyz / lightSpacePos.w;
+    coords = coords * 0.5 + 0.5;
+    if (coords.z > 1.0) {
+        return 1.0;
+    }
+
+    vec2 texelSize = 1.0 / vec2(textureSize(shadowMap, 0).xy);
+    float lit = 0.0;
+    for (int x = -radius; x <= radius; ++x) {
+        for (int y = -radius; y <= radius; ++y) {
+            float closest = texture(shadowMap, vec3(coords.xy + vec2(x, y) * texelSize, float(layer))).r;
+            lit += coords.z - bias > closest ? 0.0 : 1.0;
+        }
+    }
+    float samples = float((2 * radius + 1) * (2 * radius + 1));
+    return lit / samples;
+}
+
+float directionalShadow(vec3 normal, vec3 lightDir) {
+    if (!dirShadow.enabled) {
+        return 1.0;
+    }
+
+    int cascade = -1;
+    for (int i = 0; i < dirShadow.cascadeCount; ++i) {
+        if (ViewDepth < dirShadow.splits[i]) {
+            cascade = i;
+            break;
+        }
+    }
+    if (cascade < 0) {
+        return 1.0;
+    }
+
+    float slope = 1.0 - max(dot(normal, lightDir), 0.0);
+    vec3 offsetPos = FragPos + normal * dirShadow.normalBias * slope;
+    // Farther cascades cover more world space per texel, so they need a larger bias
+    float bias = dirShadow.bias * (1.0 + float(cascade));
+    return sampleShadow(dirShadowMap, cascade, dirShadow.matrices[cascade] * vec4(offsetPos, 1.0), bias, dirShadow.pcfRadius);
+}
+
+float spotShadow(int index, vec3 normal, vec3 lightDir) {
+    if (!spotShadows[index].enabled) {
+        return 1.0;
+    }
+
+    float slope = 1.0 - max(dot(normal, lightDir), 0.0);
+    vec3 offsetPos = FragPos + normal * spotShadows[index].normalBias * slope;
+    return sampleShadow(spotShadowMap, index, spotShadows[index].matrix * vec4(offsetPos, 1.0), spotShadows[index].bias, spotShadows[index].pcfRadius);
+}
+
+vec3 shade(vec3 normal, vec3 lightDir, vec3 viewDir, vec3 lightColor, vec3 diffuse) {
+    float diff = max(dot(normal, lightDir), 0.0);
+    vec3 halfway = normalize(lightDir + viewDir);
+    float spec = pow(max(dot(normal, halfway), 0.0), material.shininess);
+    return lightColor * (diff * diffuse + spec * material.specular);
+}
+
+void main() {
+    vec3 normal = normalize(Normal);
+    vec3 viewDir = normalize(viewPos - FragPos);
+    vec3 diffuse = material.diffuse * InstanceColor.rgb;
+    if (useDiffuseMap) {
+        diffuse *= texture(diffuseMap, TexCoords).rgb;
+    }
+    vec3 color = ambient * diffuse;
+
+    if (dirLight.enabled) {
+        vec3 lightDir = normalize(-dirLight.direction);
+        color += shade(normal, lightDir, viewDir, dirLight.color, diffuse) * directionalShadow(normal, lightDir);
+    }
+
+    for (int i = 0; i < spotLightCount; ++i) {
+        vec3 toLight = spotLights[i].position - FragPos;
+        float distance = length(toLight);
+        vec3 lightDir = toLight / distance;
+
+        float theta = dot(lightDir, normalize(-spotLights[i].direction));
+        float cone = clamp((theta - spotLights[i].outerCos) / (spotLights[i].innerCos - spotLights[i].outerCos), 0.0, 1.0);
+        float falloff = clamp(1.0 - distance / spotLights[i].range, 0.0, 1.0);
+
+        color += shade(normal, lightDir, viewDir, spotLights[i].color, diffuse) * cone * falloff * spotShadow(i, normal, lightDir);
+    }
+
+    FragColor = vec4(color, InstanceColor.a);
+}
@@ -0,0 +1,37 @@
+#version 330 core
+layout (location = 0) in vec3 aPos;
+layout (location = 1) in vec3 aNormal;
+layout (location = 2) in vec2 aTexCoord;
+layout (location = 3) in vec4 aColor;  // Only read when vertexColors is set
+
+uniform mat4 model;
+uniform mat4 view;
+uniform mat4 projection;
+uniform vec4 tint;
+uniform bool vertexColors;
+uniform bool linearColors;
+
+out vec3 FragPos;
+out vec3 Normal;
+out float ViewDepth;
+out vec4 InstanceColor;
+out vec2 TexCoords;
+
+// Converts an sRGB color to linear space so per-vertex colors are interpolated and blended linearly
+vec3 srgbToLinear(vec3 c) {
+    return mix(c / 12.92, pow((c + 0.055) / 1.055, vec3(2.4)), greaterThan(c, vec3(0.04045)));
+}
+
+void main() {
+    vec4 worldPos = model * vec4(aPos, 1.0);
+    vec4 viewPos = view * worldPos;
+
+    FragPos = worldPos.xyz;
+    Normal = mat3(transpose(inverse(model))) * aNormal;
+    ViewDepth = -viewPos.z;
+    TexCoords = aTexCoord;
+    vec4 color = linearColors ? vec4(srgbToLinear(aColor.rgb), aColor.a) : aColor;
+    InstanceColor = vertexColors ? tint * color : tint;
+
+    gl_Position = projection * viewPos;
+}
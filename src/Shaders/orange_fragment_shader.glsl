@@ -1,6 +1,14 @@
 #version 330 core
 out vec4 FragColor;
 
+uniform bool linearColors;
+
+// Converts an sRGB color to linear space when a post effect encodes the output to sRGB
+vec3 srgbToLinear(vec3 c) {
+    return mix(c / 12.92, pow((c + 0.055) / 1.055, vec3(2.4)), greaterThan(c, vec3(0.04045)));
+}
+
 void main() {
-    FragColor = vec4(1.0, 0.5, 0.2, 1.0); // Orange color
+    vec3 color = vec3(1.0, 0.5, 0.2); // Orange color
+    FragColor = vec4(linearColors ? srgbToLinear(color) : color, 1.0);
 }
@@ -0,0 +1,11 @@
+#version 330 core
+out vec4 FragColor;
+
+in vec2 TexCoords;
+in vec4 Color;
+
+uniform sampler2D spriteTexture;  // 1x1 white texture for untextured primitives
+
+void main() {
+    FragColor = texture(spriteTexture, TexCoords) * Color;
+}
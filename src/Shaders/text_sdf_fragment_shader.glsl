@@ -0,0 +1,15 @@
+#version 330 core
+out vec4 FragColor;
+
+in vec2 TexCoords;
+in vec4 Color;
+
+uniform sampler2D spriteTexture;  // Signed distance field in alpha, 0.5 on the glyph outline
+
+void main() {
+    float distance = texture(spriteTexture, TexCoords).a;
+    // Screen-space derivatives keep the edge about one pixel wide at any text size
+    float width = max(fwidth(distance), 1e-4);
+    float coverage = smoothstep(0.5 - width, 0.5 + width, distance);
+    FragColor = vec4(Color.rgb, Color.a * coverage);
+}
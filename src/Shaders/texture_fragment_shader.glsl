@@ -0,0 +1,18 @@
+#version 330 core
+out vec4 FragColor;
+
+in vec2 TexCoords;
+
+uniform sampler2D image;
+uniform vec4 color;  // Tint of the shape, multiplied by the texture
+uniform bool linearColors;
+
+// Converts an sRGB color to linear space when a post effect encodes the output to sRGB
+vec3 srgbToLinear(vec3 c) {
+    return mix(c / 12.92, pow((c + 0.055) / 1.055, vec3(2.4)), greaterThan(c, vec3(0.04045)));
+}
+
+void main() {
+    vec4 texel = texture(image, TexCoords);
+    FragColor = color * vec4(linearColors ? srgbToLinear(texel.rgb) : texel.rgb, texel.a);
+}
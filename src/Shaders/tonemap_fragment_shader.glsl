@@ -0,0 +1,30 @@
+#version 330 core
+out vec4 FragColor;
+
+in vec2 TexCoords;
+
+uniform sampler2D screenTexture;
+uniform float exposure;
+uniform int operatorType;  // 0 = Reinhard, 1 = ACES filmic
+
+vec3 aces(vec3 x) {
+    const float a = 2.51;
+    const float b = 0.03;
+    const float c = 2.43;
+    const float d = 0.59;
+    const float e = 0.14;
+    return clamp((x * (a * x + b)) / (x * (c * x + d) + e), 0.0, 1.0);
+}
+
+void main() {
+    vec4 hdr = texture(screenTexture, TexCoords);
+    vec3 color = hdr.rgb * exposure;
+
+    if (operatorType == 1) {
+        color = aces(color);
+    } else {
+        color = color / (color + vec3(1.0));
+    }
+
+    FragColor = vec4(color, hdr.a);
+}
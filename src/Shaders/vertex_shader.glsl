@@ -6,8 +6,11 @@ out vec2 TexCoords;
 out vec3 Normal;
 out vec2 Position;
 
+uniform mat4 model;
+uniform mat4 projection;
+
 void main() {
-    gl_Position = vec4(aPos.x, aPos.y, aPos.z, 1.0);
+    gl_Position = projection * model * vec4(aPos, 1.0);
     TexCoords = aNormalOrTexCoord.xy;
     Normal = aNormalOrTexCoord;
     Position = aPos.xy;
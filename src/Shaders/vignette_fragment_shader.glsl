@@ -0,0 +1,16 @@
+#version 330 core
+out vec4 FragColor;
+
+in vec2 TexCoords;
+
+uniform sampler2D screenTexture;
+uniform float radius;
+uniform float softness;
+uniform float strength;
+
+void main() {
+    vec4 color = texture(screenTexture, TexCoords);
+    float distance = length(TexCoords - vec2(0.5));
+    float vignette = smoothstep(radius, radius - softness, distance);
+    FragColor = vec4(color.rgb * mix(1.0, vignette, strength), color.a);
+}
@@ -1,107 +1,260 @@
 use glfw::Context;
-use std::rc::Rc;
+use std::io;
+use std::path::{Path, PathBuf};
 
-use crate::window::GlWindow;
-use crate::draw::Shape;
-use crate::triangle::Triangle;
-use crate::rectangle::Rectangle;
-use crate::circle::Circle;
+use crate::window::{self, GlWindow};
+use crate::timing::{Clock, FixedTimestep, FpsCounter, FrameLimiter};
+use crate::capture::{self, Image, Recorder};
+use crate::draw::{self, RenderContext};
+use crate::debug_draw::{self, DebugRenderer};
+use crate::gui::{self, FrameStats, Gui};
+use crate::assets::AssetManager;
+use crate::export::{self, ExportFormat};
+use crate::scene::Scene;
+use crate::scene_file::{self, SceneDescription};
 use crate::shader::Shader;
+use crate::sprite_batch::SpriteBatch;
+use crate::text::{Font, GlyphMode, TextRenderer, TextStyle};
 use crate::vector2d::Vector2D;
 use crate::vector3d::Vector3D;
-use crate::cube::Cube;
+use crate::matrix4::Matrix4;
+use crate::camera::Camera;
+use crate::light::Lighting;
+use crate::shadow::ShadowRenderer;
+use crate::framebuffer::{Framebuffer, FramebufferSpec};
+use crate::texture::TextureFormat;
+use crate::postprocess::{PostProcessStack, DEFAULT_STACK};
+
+// Pencere ve çizim ayarları
+#[derive(Debug, Clone)]
+pub struct AppSettings {
+    pub title: String,
+    pub width: u32,
+    pub height: u32,
+    pub fullscreen: bool,
+    pub vsync: bool,
+    pub samples: u32,  // MSAA samples of the scene target, 1 disables multisampling
+    pub max_fps: Option<u32>,  // Frame rate cap of the window loop, None draws as fast as vsync allows
+    pub font: Option<PathBuf>,  // Font of the on-screen FPS counter, None hides it
+    pub hidden: Vec<String>,  // Names of the nodes hidden once the scene is built
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        AppSettings {
+            title: "OpenGL Shape Renderer".to_string(),
+            width: 800,
+            height: 600,
+            fullscreen: false,
+            vsync: true,
+            samples: 4,
+            max_fps: None,
+            font: None,
+            hidden: Vec::new(),
+        }
+    }
+}
 
 pub struct Application {
     window: GlWindow,
-    shapes: Vec<Box<dyn Shape>>,
-    cube: Option<Cube>,
+    samples: u32,
+    assets: AssetManager,
+    scene: Scene,
+    description: Option<SceneDescription>,
+    camera: Camera,
+    lighting: Lighting,
+    shadows: Option<ShadowRenderer>,
+    scene_target: Option<Framebuffer>,
+    post: Option<PostProcessStack>,
+    recorder: Option<Recorder>,
+    timestep: FixedTimestep,
+    limiter: FrameLimiter,
+    font: Option<PathBuf>,
+    hud: Option<Hud>,
+    debug: Option<DebugRenderer>,
+    gui: Option<Gui>,
+    debug_overlay: bool,  // F2 draws the shadow frusta of the lights and the world axes
+    fps: FpsCounter,
+    hidden: Vec<String>,
+    drawn_shapes: usize,  // Visible shapes of the last rendered frame, shown by the inspector
 }
 
+// Sahnenin üzerine ekran uzayında çizilen bilgi katmanı
+struct Hud {
+    batch: SpriteBatch,
+    text: TextRenderer,
+    fps: bool,  // The FPS counter is only shown when a font was given
+}
+
+// F12 ekran görüntülerinin ve F9 kayıtlarının kaydedildiği klasörler
+const SCREENSHOT_DIR: &str = "screenshots";
+const RECORDING_DIR: &str = "recordings";
+
+// Kaydedilen PNG dizilerinin kare hızı
+const RECORDING_FPS: u32 = 60;
+
+// Saniyedeki sabit güncelleme sayısı
+const UPDATES_PER_SECOND: u32 = 60;
+
 impl Application {
     // Yeni bir uygulama oluşturur
-    pub fn new() -> Application {
-        let window = GlWindow::new("OpenGL Shape Renderer", 800, 600);
-        Application { window, shapes: Vec::new(), cube: None }
+    pub fn new(settings: &AppSettings) -> Application {
+        let mut window = GlWindow::new(&settings.title, settings.width, settings.height, settings.fullscreen);
+        window.set_vsync(settings.vsync);
+        Application::with_window(window, settings)
+    }
+
+    // Görünmez pencerede çalışan ekransız bir uygulama oluşturur
+    pub fn new_headless(settings: &AppSettings, api: glfw::ContextCreationApi) -> Result<Application, String> {
+        let window = GlWindow::new_headless(settings.width, settings.height, api)?;
+        Ok(Application::with_window(window, settings))
     }
 
-    // OpenGL'i başlatır ve şekilleri oluşturur
-    pub fn init_gl(&mut self) {
+    // Verilen pencere için uygulama durumunu hazırlar
+    fn with_window(window: GlWindow, settings: &AppSettings) -> Application {
+        let (width, height) = window.window.get_framebuffer_size();
+        let camera = Camera::new(
+            Vector3D::new(1.5, 1.2, 2.5),
+            Vector3D::new(0.0, -0.2, 0.0),
+            width as f32 / height.max(1) as f32,
+        );
+        let lighting = Lighting::new(Vector3D::new(0.15, 0.15, 0.15));
+        Application {
+            window,
+            samples: settings.samples.max(1),
+            assets: AssetManager::new(),
+            scene: Scene::new(),
+            description: None,
+            camera,
+            lighting,
+            shadows: None,
+            scene_target: None,
+            post: None,
+            recorder: None,
+            timestep: FixedTimestep::new(UPDATES_PER_SECOND),
+            limiter: FrameLimiter::new(settings.max_fps),
+            font: settings.font.clone(),
+            hud: None,
+            debug: None,
+            gui: None,
+            debug_overlay: false,
+            fps: FpsCounter::new(),
+            hidden: settings.hidden.clone(),
+            drawn_shapes: 0,
+        }
+    }
+
+    // Sahne dosyasını okur ve doğrular; sahne init_gl sırasında oluşturulur
+    pub fn load_scene<P: AsRef<Path>>(&mut self, path: P) -> Result<(), String> {
+        self.description = Some(scene_file::load(path)?);
+        Ok(())
+    }
+
+    // OpenGL'i başlatır ve yüklenen sahneyi oluşturur; bir varlık yüklenemezse hata döndürür
+    pub fn init_gl(&mut self) -> Result<(), String> {
         self.window.init_gl();
 
         unsafe {
             gl::Enable(gl::DEPTH_TEST);
         }
 
-        // Load different fragment shaders and wrap them in Rc
-        let orange_shader = Rc::new(Shader::new("src/Shaders/vertex_shader.glsl", "src/Shaders/orange_fragment_shader.glsl"));
-        let red_shader = Rc::new(Shader::new("src/Shaders/vertex_shader.glsl", "src/Shaders/red_fragment_shader.glsl"));
-        let gradient_shader = Rc::new(Shader::new("src/Shaders/vertex_shader.glsl", "src/Shaders/gradient_fragment_shader.glsl"));
-        let normal_shader = Rc::new(Shader::new("src/Shaders/vertex_shader.glsl", "src/Shaders/normal_fragment_shader.glsl"));
-        let cube_shader = Rc::new(Shader::new("src/Shaders/cube_vertex_shader.glsl", "src/Shaders/cube_fragment_shader.glsl"));
-
-        // Initialize shapes with shared Rc<Shader>
-        let triangle = Box::new(Triangle::new(
-            orange_shader.clone(),
-            Vector2D::new(-0.5, 0.6),
-            Vector2D::new(0.5, 0.6),
-            Vector2D::new(0.0, 0.9)
-        ));
-        self.shapes.push(triangle);
-
-        let normal_triangle = Box::new(Triangle::new(
-            normal_shader.clone(),
-            Vector2D::new(-0.9, 0.8),
-            Vector2D::new(-0.9, -0.8),
-            Vector2D::new(-0.7, 0.0)
-        ));
-        self.shapes.push(normal_triangle);
-
-        let rectangle = Box::new(Rectangle::new(
-            red_shader.clone(),
-            Vector2D::new(0.5, -0.5),   // top_right
-            Vector2D::new(0.9, -0.9)  // bottom_left
-        ));
-        self.shapes.push(rectangle);
-        
-        let circle = Box::new(Circle::new(Vector2D::new(0.75, 0.75), 0.2, gradient_shader.clone()));
-        self.shapes.push(circle);
-
-        self.cube = Some(Cube::new(
-            cube_shader.clone(),
-            Vector3D::new(-0.3, -0.3, -0.3),  // bottom_left_back
-            Vector3D::new(0.3, 0.3, 0.3)      // top_right_front
-        ));  // Create the cube
+        // The scene is drawn offscreen into a multisampled HDR target and post-processed every frame
+        let (width, height) = self.window.window.get_framebuffer_size();
+        let mut spec = FramebufferSpec::new(width as u32, height as u32);
+        spec.color_formats = vec![TextureFormat::Rgba16F];
+        spec.samples = self.samples;
+        self.scene_target = Some(Framebuffer::new(spec)?);
+
+        // Number keys 1-8 toggle the effects in this order
+        let mut post = PostProcessStack::new(width as u32, height as u32)?;
+        for (kind, enabled) in DEFAULT_STACK {
+            let mut effect = kind.create();
+            effect.set_enabled(enabled);
+            post.push(effect);
+        }
+        self.post = Some(post);
+        self.debug = Some(DebugRenderer::new());
+        self.gui = Some(Gui::new());
+
+        // Debug text falls back to egui's built-in font so it is drawn even without --font
+        let font = match &self.font {
+            Some(path) => Font::load(path)?,
+            None => Font::fallback()?,
+        };
+        self.hud = Some(Hud { batch: SpriteBatch::new(), text: TextRenderer::new(font, GlyphMode::Bitmap), fps: self.font.is_some() });
+
+        let depth_shader = Shader::load("depth_vertex_shader.glsl", "depth_fragment_shader.glsl");
+        self.shadows = Some(ShadowRenderer::new(depth_shader));
+
+        // Shapes, camera and lights come from the loaded scene file
+        if let Some(description) = self.description.take() {
+            description.build(&mut self.assets, &mut self.scene, &mut self.camera, &mut self.lighting)?;
+        }
+        for name in &self.hidden {
+            match self.scene.find(name) {
+                Some(id) => self.scene.node_mut(id).visible = false,
+                None => eprintln!("Cannot hide node '{}': the scene has no node with that name", name),
+            }
+        }
+
+        // Shaders declared by the scene file but not used by any node are released right away
+        let unused = self.assets.collect_unused();
+        let stats = self.assets.stats();
+        println!(
+            "Loaded {} shaders, {} textures and {} meshes ({} unused assets released)",
+            stats.shaders, stats.textures, stats.meshes, unused
+        );
+        Ok(())
     }
 
     // Ana uygulama döngüsünü çalıştırır
     pub fn run(&mut self) {
+        let mut clock = Clock::new();
+
         while !self.window.window.should_close() {
             self.window.glfw.poll_events();
 
-            unsafe {
-                gl::Clear(gl::COLOR_BUFFER_BIT | gl::DEPTH_BUFFER_BIT);
-            }
-
-            // Update cube rotation
-            if let Some(cube) = &mut self.cube {
-                cube.rotation += 0.01;
+            // While recording, every frame advances the simulation by exactly one recorded frame
+            let delta = clock.tick();
+            self.fps.tick(delta);
+            let delta = self.recorder.as_ref().map_or(delta, |recorder| recorder.timestep());
+            for _ in 0..self.timestep.advance(delta) {
+                self.update(self.timestep.step());
             }
 
-            // Loop over each shape and use its assigned shader before drawing
-            for shape in &self.shapes {
-                shape.draw();
-            }
-
-            // Draw cube
-            if let Some(cube) = &self.cube {
-                cube.draw();
-            }
+            self.render_frame(self.timestep.alpha());
+            self.process_captures();
+            // The overlay is drawn after captures so screenshots and recordings only show the scene
+            self.draw_gui();
+            debug_draw::with(|debug| debug.end_frame(delta));
 
+            self.limiter.wait();
             self.window.window.swap_buffers();
 
             let events: Vec<(f64, glfw::WindowEvent)> =
                 glfw::flush_messages(&self.window.events).collect();
             for (_, event) in events {
+                // Keys typed into a focused GUI text field must not also toggle effects or close the window
+                if let Some(gui) = &mut self.gui {
+                    gui.handle_event(&event);
+                    if gui.wants_keyboard() && matches!(event, glfw::WindowEvent::Key(..)) {
+                        continue;
+                    }
+                }
+                match event {
+                    glfw::WindowEvent::FramebufferSize(width, height) => self.resize(width, height),
+                    glfw::WindowEvent::Key(glfw::Key::F1, _, glfw::Action::Press, _) => {
+                        if let Some(gui) = &mut self.gui {
+                            gui.toggle();
+                        }
+                    }
+                    glfw::WindowEvent::Key(glfw::Key::F2, _, glfw::Action::Press, _) => {
+                        self.debug_overlay = !self.debug_overlay;
+                        println!("debug overlay: {}", if self.debug_overlay { "on" } else { "off" });
+                    }
+                    glfw::WindowEvent::Key(key, _, glfw::Action::Press, _) => self.toggle_effect(key),
+                    _ => {}
+                }
                 self.window.handle_event(event);
             }
         }
@@ -109,9 +262,259 @@ impl Application {
         self.cleanup();
     }
 
+    // Verilen sayıda kareyi ekransız çizer ve son kareyi PNG olarak kaydeder
+    pub fn run_headless<P: AsRef<Path>>(&mut self, frames: u32, output: P) -> io::Result<()> {
+        let image = self.render_frames(frames);
+        self.cleanup();
+        image.save_png(output)
+    }
+
+    // Sahnenin görünür şekillerini dünya koordinatlarında dosyaya yazar ve yazılan nesne sayısını döndürür.
+    // Biçim verilmezse dosya uzantısından seçilir.
+    pub fn export_scene<P: AsRef<Path>>(&mut self, path: P, format: Option<ExportFormat>) -> Result<usize, String> {
+        self.scene.update_world(1.0);
+        let objects = export::scene_objects(&self.scene);
+        export::export(path, format, &objects)?;
+        Ok(objects.len())
+    }
+
+    // Verilen sayıda kareyi çizer ve son karenin görüntüsünü döndürür.
+    // Her kare tam olarak bir sabit güncelleme adımı ilerler, böylece sonuç deterministiktir.
+    pub fn render_frames(&mut self, frames: u32) -> Image {
+        let step = self.timestep.step();
+        for _ in 1..frames.max(1) {
+            self.update(step);
+            self.render_frame(1.0);
+            self.window.window.swap_buffers();
+            debug_draw::with(|debug| debug.end_frame(step));
+        }
+
+        self.update(step);
+        self.render_frame(1.0);
+        let image = self.capture_frame();
+        self.window.window.swap_buffers();
+        image
+    }
+
+    // Son çizilen kareyi (swap_buffers'tan önce) okur
+    pub fn capture_frame(&self) -> Image {
+        let (width, height) = self.window.window.get_framebuffer_size();
+        Image::capture_screen(width as u32, height as u32)
+    }
+
+    // Bekleyen ekran görüntüsü ve kayıt isteklerini işler, kayıt sürüyorsa kareyi diziye ekler.
+    // Arka tampon okunduğu için swap_buffers'tan önce çağrılmalıdır.
+    fn process_captures(&mut self) {
+        if self.window.take_recording_toggle_request() {
+            match self.recorder.take() {
+                Some(recorder) => Application::finish_recording(recorder),
+                None => match Recorder::start(RECORDING_DIR, RECORDING_FPS) {
+                    Ok(recorder) => {
+                        println!("Recording to {}", recorder.directory().display());
+                        self.recorder = Some(recorder);
+                    }
+                    Err(e) => eprintln!("Failed to start recording: {}", e),
+                },
+            }
+        }
+
+        let screenshot = self.window.take_screenshot_request();
+        if !screenshot && self.recorder.is_none() {
+            return;
+        }
+
+        let image = self.capture_frame();
+        if screenshot {
+            match capture::save_screenshot(&image, SCREENSHOT_DIR) {
+                Ok(path) => println!("Saved screenshot to {}", path.display()),
+                Err(e) => eprintln!("Failed to save screenshot: {}", e),
+            }
+        }
+        if let Some(recorder) = &mut self.recorder {
+            if let Err(e) = recorder.record(&image) {
+                eprintln!("Failed to record frame, stopping: {}", e);
+                if let Some(recorder) = self.recorder.take() {
+                    Application::finish_recording(recorder);
+                }
+            }
+        }
+    }
+
+    // Kaydı sonlandırır ve videoya çevirme komutunu yazdırır
+    fn finish_recording(recorder: Recorder) {
+        println!("Recorded {} frames to {}", recorder.frames(), recorder.directory().display());
+        println!("  {}", recorder.ffmpeg_command());
+    }
+
+    // Sahne durumunu bir sabit zaman adımı (saniye) kadar ilerletir
+    fn update(&mut self, dt: f32) {
+        self.scene.update(dt);
+    }
+
+    // Gölge, sahne ve son işleme geçişleriyle bir kare çizer.
+    // `alpha` son iki güncelleme arasındaki konumdur ve hareketli nesnelerin ara değeri için kullanılır.
+    fn render_frame(&mut self, alpha: f32) {
+        self.scene.update_world(alpha);
+        // The visible shapes are gathered once and shared by the shadow and scene passes
+        let renderables = self.scene.renderables();
+        self.drawn_shapes = renderables.len();
+
+        // Render the depth maps of every shadow casting light
+        if let Some(shadows) = &mut self.shadows {
+            shadows.update(&self.lighting, &self.camera);
+            shadows.render(&renderables);
+        }
+
+        let ctx = RenderContext {
+            view: self.camera.view_matrix(),
+            projection: self.camera.projection_matrix(),
+            projection_2d: draw::projection_2d(self.camera.aspect),
+            camera_position: self.camera.position,
+            lighting: Some(&self.lighting),
+            shadows: self.shadows.as_ref(),
+            linear_colors: self.post.as_ref().is_some_and(|post| post.expects_linear()),
+        };
+
+        if let Some(target) = &self.scene_target {
+            target.bind();
+        }
+
+        let [r, g, b, a] = ctx.color(window::CLEAR_COLOR);
+        unsafe {
+            gl::ClearColor(r, g, b, a);
+            gl::Clear(gl::COLOR_BUFFER_BIT | gl::DEPTH_BUFFER_BIT);
+        }
+
+        // Every visible node draws its shape with its own shader and world matrix
+        for (shape, world) in &renderables {
+            shape.draw(&ctx, world);
+        }
+
+        if let Some(target) = &self.scene_target {
+            let (width, height) = self.window.window.get_framebuffer_size();
+            match &mut self.post {
+                Some(post) => post.render(target, width as u32, height as u32),
+                None => target.blit_to_screen(0, width as u32, height as u32),
+            }
+        }
+
+        if self.debug_overlay {
+            self.draw_debug_overlay();
+        }
+
+        // Debug lines are drawn over the final image so they are never hidden or post-processed
+        if let Some(debug) = &self.debug {
+            let view_projection = ctx.projection * ctx.view;
+            debug_draw::with(|lines| debug.render(lines, &view_projection));
+        }
+
+        self.draw_hud();
+    }
+
+    // Gölge düşüren ışıkların görüş hacimlerini ve dünya eksenlerini bu kare için hata ayıklama listesine ekler
+    fn draw_debug_overlay(&self) {
+        if let Some(shadows) = &self.shadows {
+            for matrix in shadows.light_matrices() {
+                debug_draw::frustum(&matrix, debug_draw::YELLOW, 0.0);
+            }
+        }
+        debug_draw::axis_gizmo(&Matrix4::identity(), 1.0, 0.0);
+    }
+
+    // Sahne, kamera, ışık ve istatistik panellerini çizer
+    fn draw_gui(&mut self) {
+        let Some(gui) = &mut self.gui else {
+            return;
+        };
+        let mut nodes = 0;
+        self.scene.visit(|_, _| nodes += 1);
+        let stats = FrameStats {
+            fps: self.fps.fps(),
+            nodes,
+            shapes: self.drawn_shapes,
+            debug_lines: debug_draw::with(|debug| debug.line_count()),
+        };
+        let (scene, camera, lighting) = (&mut self.scene, &mut self.camera, &mut self.lighting);
+        gui.render(self.window.window.get_size(), self.window.window.get_framebuffer_size(), |context| {
+            gui::inspector(context, scene, camera, lighting, &stats)
+        });
+    }
+
+    // Kare hızını (yazı tipi verildiyse) pencerenin sol üst köşesine, hata ayıklama yazılarını da dünyadaki konumlarına yazar
+    fn draw_hud(&mut self) {
+        let Some(hud) = &mut self.hud else {
+            return;
+        };
+        let (width, height) = self.window.window.get_framebuffer_size();
+        if hud.fps {
+            let label = format!("{:.0} FPS", self.fps.fps());
+            hud.text.draw(&mut hud.batch, &label, Vector2D::new(8.0, 8.0), &TextStyle::new(16.0));
+        }
+
+        let view_projection = self.camera.projection_matrix() * self.camera.view_matrix();
+        debug_draw::with(|debug| {
+            for text in debug.texts() {
+                if let Some(position) = debug_draw::screen_position(&view_projection, text.position, width as u32, height as u32) {
+                    let style = TextStyle::new(14.0).with_color(text.color);
+                    hud.text.draw(&mut hud.batch, &text.text, position, &style);
+                }
+            }
+        });
+        hud.batch.flush(&SpriteBatch::screen_projection(width as u32, height as u32));
+    }
+
+    // Pencere boyutu değiştiğinde kamera oranını ve çizim hedeflerini günceller
+    fn resize(&mut self, width: i32, height: i32) {
+        if width <= 0 || height <= 0 {
+            return;
+        }
+        self.camera.aspect = width as f32 / height as f32;
+        let mut result = Ok(());
+        if let Some(target) = &mut self.scene_target {
+            result = target.resize(width as u32, height as u32);
+        }
+        if let Some(post) = &mut self.post {
+            result = result.and(post.resize(width as u32, height as u32));
+        }
+        if let Err(e) = result {
+            eprintln!("Failed to resize the render targets to {}x{}: {}", width, height, e);
+        }
+    }
+
+    // Sayı tuşuna karşılık gelen son işleme efektini açar/kapatır
+    fn toggle_effect(&mut self, key: glfw::Key) {
+        let index = match key {
+            glfw::Key::Num1 => 0,
+            glfw::Key::Num2 => 1,
+            glfw::Key::Num3 => 2,
+            glfw::Key::Num4 => 3,
+            glfw::Key::Num5 => 4,
+            glfw::Key::Num6 => 5,
+            glfw::Key::Num7 => 6,
+            glfw::Key::Num8 => 7,
+            _ => return,
+        };
+        if let Some(post) = &mut self.post {
+            if let Some(enabled) = post.toggle(index) {
+                let name = post.effects()[index].name();
+                println!("{}: {}", name, if enabled { "on" } else { "off" });
+            }
+        }
+    }
+
     // Kaynakları temizler
     pub fn cleanup(&mut self) {
-        self.shapes.clear();
-        self.cube = None;
+        if let Some(recorder) = self.recorder.take() {
+            Application::finish_recording(recorder);
+        }
+        self.scene.clear();
+        self.assets.clear();
+        self.shadows = None;
+        self.scene_target = None;
+        self.post = None;
+        self.hud = None;
+        self.debug = None;
+        self.gui = None;
+        debug_draw::with(|debug| debug.clear());
     }
 }
@@ -0,0 +1,276 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use crate::capture::Image;
+use crate::locator;
+use crate::mesh::{Mesh, MeshData};
+use crate::shader::{self, Shader, ShaderStage};
+use crate::texture::{Texture, TextureFormat};
+
+// Yöneticideki bir varlığa paylaşılan, tipli tanıtıcı.
+// Tanıtıcılar kopyalandıkça varlık canlı kalır; hiçbir tanıtıcı kalmadığında collect_unused varlığı siler.
+pub struct Handle<T> {
+    key: Rc<str>,
+    asset: Rc<T>,
+}
+
+impl<T> Handle<T> {
+    // Varlığın yöneticideki anahtarını döndürür
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    // Şekillere verilebilecek paylaşılan işaretçiyi döndürür
+    pub fn shared(&self) -> Rc<T> {
+        self.asset.clone()
+    }
+}
+
+impl<T> Clone for Handle<T> {
+    fn clone(&self) -> Self {
+        Handle { key: self.key.clone(), asset: self.asset.clone() }
+    }
+}
+
+impl<T> Deref for Handle<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.asset
+    }
+}
+
+impl<T> fmt::Debug for Handle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Handle({})", self.key)
+    }
+}
+
+// Anahtarla erişilen tek tipteki varlıkların önbelleği
+struct Cache<T> {
+    entries: HashMap<Rc<str>, Rc<T>>,
+}
+
+impl<T> Cache<T> {
+    fn new() -> Cache<T> {
+        Cache { entries: HashMap::new() }
+    }
+
+    // Anahtar önbellekteyse tanıtıcısını döndürür
+    fn get(&self, key: &str) -> Option<Handle<T>> {
+        self.entries
+            .get_key_value(key)
+            .map(|(key, asset)| Handle { key: key.clone(), asset: asset.clone() })
+    }
+
+    // Varlığı önbelleğe ekler ve tanıtıcısını döndürür
+    fn insert(&mut self, key: &str, asset: T) -> Handle<T> {
+        let key: Rc<str> = Rc::from(key);
+        let asset = Rc::new(asset);
+        self.entries.insert(key.clone(), asset.clone());
+        Handle { key, asset }
+    }
+
+    // Yalnızca önbellekte tutulan (dışarıda tanıtıcısı kalmamış) varlıkları siler
+    fn collect_unused(&mut self) -> usize {
+        let before = self.entries.len();
+        self.entries.retain(|_, asset| Rc::strong_count(asset) > 1);
+        before - self.entries.len()
+    }
+}
+
+// Derlenmiş shader aşamalarını çözülmüş dosya yoluna göre, bağlanmış programları da aşama çiftine göre önbellekler.
+// Birçok programda kullanılan bir aşama (örneğin vertex_shader.glsl) yalnızca bir kez okunup derlenir.
+struct ProgramCache<S, P> {
+    stages: Cache<S>,
+    programs: Cache<P>,
+}
+
+impl<S, P> ProgramCache<S, P> {
+    fn new() -> ProgramCache<S, P> {
+        ProgramCache { stages: Cache::new(), programs: Cache::new() }
+    }
+
+    // Programı önbellekten döndürür; yoksa eksik aşamaları `compile` ile derler ve `link` ile bağlar
+    fn load(
+        &mut self,
+        vertex: &Path,
+        fragment: &Path,
+        mut compile: impl FnMut(&Path, gl::types::GLenum) -> Result<S, String>,
+        link: impl FnOnce(&S, &S) -> Result<P, String>,
+    ) -> Result<Handle<P>, String> {
+        let key = format!("{}|{}", vertex.display(), fragment.display());
+        if let Some(handle) = self.programs.get(&key) {
+            return Ok(handle);
+        }
+
+        let mut stage = |path: &Path, kind: gl::types::GLenum| -> Result<Handle<S>, String> {
+            let key = format!("{}#{}", path.display(), kind);
+            match self.stages.get(&key) {
+                Some(handle) => Ok(handle),
+                None => Ok(self.stages.insert(&key, compile(path, kind)?)),
+            }
+        };
+        let vertex = stage(vertex, gl::VERTEX_SHADER)?;
+        let fragment = stage(fragment, gl::FRAGMENT_SHADER)?;
+        let program = link(&vertex, &fragment)?;
+        Ok(self.programs.insert(&key, program))
+    }
+}
+
+// Yüklenen varlık sayıları
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AssetStats {
+    pub shaders: usize,
+    pub textures: usize,
+    pub meshes: usize,
+}
+
+// Shader, doku ve mesh'leri yol/anahtar ile yükleyen ve aynı istekleri tek kopyada toplayan yönetici
+pub struct AssetManager {
+    shaders: ProgramCache<ShaderStage, Shader>,
+    textures: Cache<Texture>,
+    meshes: Cache<Mesh>,
+}
+
+// Doku önbelleğinin anahtarı; aynı görüntünün sRGB ve doğrusal yüklemeleri ayrı dokulardır
+fn texture_key(key: &str, srgb: bool) -> String {
+    format!("{}{}", key, if srgb { "#srgb" } else { "" })
+}
+
+impl AssetManager {
+    // Boş bir varlık yöneticisi oluşturur
+    pub fn new() -> AssetManager {
+        AssetManager { shaders: ProgramCache::new(), textures: Cache::new(), meshes: Cache::new() }
+    }
+
+    // Shader klasöründeki vertex ve fragment dosyalarından bir programı yükler veya önbellekten döndürür
+    // Aşamalar çözülmüş yollarına göre paylaşılır, böylece aynı dosya farklı eşleşmelerde yeniden derlenmez.
+    pub fn load_shader(&mut self, vertex_name: &str, fragment_name: &str) -> Result<Handle<Shader>, String> {
+        // Embedded shaders have no file on disk and keep their relative path as the key
+        let resolve = |name: &str| -> PathBuf {
+            let path = shader::path(name);
+            locator::resolve(&path).unwrap_or(path)
+        };
+        self.shaders.load(&resolve(vertex_name), &resolve(fragment_name), ShaderStage::load, Shader::link)
+    }
+
+    // PNG dokusunu yükler veya önbellekten döndürür. Renk dokuları `srgb` ile doğrusal renge çevrilerek örneklenir.
+    pub fn load_texture<P: AsRef<Path>>(&mut self, path: P, srgb: bool) -> Result<Handle<Texture>, String> {
+        let path = path.as_ref();
+        let resolved = locator::resolve(path)
+            .ok_or_else(|| format!("{}: texture not found in any asset directory", path.display()))?;
+        // Keyed by the resolved file, so different spellings of one path share a texture
+        let key = resolved.display().to_string();
+        if let Some(handle) = self.textures.get(&texture_key(&key, srgb)) {
+            return Ok(handle);
+        }
+
+        let mut image = Image::load_png(&resolved).map_err(|e| format!("{}: {}", resolved.display(), e))?;
+        // OpenGL expects the bottom row first
+        image.flip_vertical();
+        Ok(self.add_texture(&key, &image, srgb))
+    }
+
+    // Bellekteki RGBA8 görüntüyü verilen anahtarla dokuya yükler veya önbellekten döndürür.
+    // Görüntünün ilk satırı doku koordinatlarında v = 0'dır.
+    pub fn add_texture(&mut self, key: &str, image: &Image, srgb: bool) -> Handle<Texture> {
+        let key = texture_key(key, srgb);
+        match self.textures.get(&key) {
+            Some(handle) => handle,
+            None => {
+                let format = if srgb { TextureFormat::Srgb8Alpha8 } else { TextureFormat::Rgba8 };
+                self.textures.insert(&key, Texture::from_data(image.width, image.height, format, Some(&image.pixels)))
+            }
+        }
+    }
+
+    // Verilen anahtar altında mesh verisini GPU'ya yükler; anahtar zaten yüklüyse mevcut mesh'i döndürür
+    pub fn add_mesh(&mut self, key: &str, data: &MeshData) -> Handle<Mesh> {
+        match self.meshes.get(key) {
+            Some(handle) => handle,
+            None => self.meshes.insert(key, Mesh::new(data)),
+        }
+    }
+
+    // Anahtarı yüklü mesh'in tanıtıcısını döndürür
+    pub fn mesh(&self, key: &str) -> Option<Handle<Mesh>> {
+        self.meshes.get(key)
+    }
+
+    // Hiçbir tanıtıcının kullanmadığı varlıkları siler ve silinen varlık sayısını döndürür.
+    // Derlenmiş aşamalar yalnızca bağlama sırasında gerektiği için sayılmadan silinir.
+    pub fn collect_unused(&mut self) -> usize {
+        self.shaders.stages.entries.clear();
+        self.shaders.programs.collect_unused() + self.textures.collect_unused() + self.meshes.collect_unused()
+    }
+
+    // Yüklü varlık sayılarını döndürür
+    pub fn stats(&self) -> AssetStats {
+        AssetStats {
+            shaders: self.shaders.programs.entries.len(),
+            textures: self.textures.entries.len(),
+            meshes: self.meshes.entries.len(),
+        }
+    }
+
+    // Bütün varlıkları önbellekten çıkarır (dışarıda tutulan tanıtıcılar geçerli kalır)
+    pub fn clear(&mut self) {
+        self.shaders.stages.entries.clear();
+        self.shaders.programs.entries.clear();
+        self.textures.entries.clear();
+        self.meshes.entries.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_deduplicates_and_collects_unused_entries() {
+        let mut cache = Cache::new();
+        let first = cache.insert("a", 1);
+        let again = cache.get("a").unwrap();
+        assert!(Rc::ptr_eq(&first.asset, &again.asset));
+        assert_eq!(again.key(), "a");
+
+        let _second = cache.insert("b", 2);
+        drop(first);
+        assert_eq!(cache.collect_unused(), 0);
+        drop(again);
+        assert_eq!(cache.collect_unused(), 1);
+        assert!(cache.get("a").is_none());
+        assert_eq!(*cache.get("b").unwrap(), 2);
+    }
+
+    #[test]
+    fn shared_stages_are_compiled_once() {
+        let mut cache = ProgramCache::new();
+        let mut compiled = Vec::new();
+        let mut load = |cache: &mut ProgramCache<String, (String, String)>, vertex: &str, fragment: &str| {
+            let compile = |path: &Path, kind| {
+                compiled.push((path.display().to_string(), kind));
+                Ok(path.display().to_string())
+            };
+            cache.load(Path::new(vertex), Path::new(fragment), compile, |v: &String, f: &String| Ok((v.clone(), f.clone())))
+        };
+
+        for fragment in ["orange.glsl", "red.glsl", "gradient.glsl", "red.glsl"] {
+            let program = load(&mut cache, "vertex_shader.glsl", fragment).unwrap();
+            assert_eq!(*program, ("vertex_shader.glsl".to_string(), fragment.to_string()));
+        }
+        // A file used as both stages is compiled once per stage type
+        load(&mut cache, "both.glsl", "both.glsl").unwrap();
+
+        let vertex_loads = compiled.iter().filter(|(path, _)| path == "vertex_shader.glsl").count();
+        assert_eq!(vertex_loads, 1);
+        assert_eq!(compiled.len(), 1 + 3 + 2);
+        assert_eq!(cache.programs.entries.len(), 4);
+        assert!(compiled.contains(&("both.glsl".to_string(), gl::VERTEX_SHADER)));
+        assert!(compiled.contains(&("both.glsl".to_string(), gl::FRAGMENT_SHADER)));
+    }
+}
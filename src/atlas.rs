@@ -0,0 +1,325 @@
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+use crate::capture::Image;
+use crate::sprite_batch::UvRect;
+use crate::texture::{Texture, TextureFormat};
+
+// Piksel cinsinden dikdörtgen bölge; (x, y) sol üst köşedir
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rect {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Rect {
+    // Yeni bir bölge oluşturur
+    pub fn new(x: u32, y: u32, width: u32, height: u32) -> Rect {
+        Rect { x, y, width, height }
+    }
+
+    // Bölgenin verilen boyuttaki dokudaki doku koordinatlarını döndürür.
+    // v = 0 görüntünün üst satırıdır; bkz. TextureAtlas::upload.
+    pub fn uv(&self, width: u32, height: u32) -> UvRect {
+        let (width, height) = (width as f32, height as f32);
+        UvRect::new(
+            [self.x as f32 / width, self.y as f32 / height],
+            [(self.x + self.width) as f32 / width, (self.y + self.height) as f32 / height],
+        )
+    }
+
+    // İki bölge örtüşüyorsa true döndürür
+    pub fn intersects(&self, other: &Rect) -> bool {
+        self.x < other.x + other.width
+            && other.x < self.x + self.width
+            && self.y < other.y + other.height
+            && other.y < self.y + self.height
+    }
+}
+
+// Skyline'ın yatay bir parçası: x'ten başlayıp width genişliğinde, y yüksekliğine kadar dolu
+#[derive(Debug, Clone, Copy)]
+struct Segment {
+    x: u32,
+    y: u32,
+    width: u32,
+}
+
+// Dikdörtgenleri sabit boyutlu bir alana skyline (bottom-left) yöntemiyle yerleştiren paketleyici.
+// Dolu alanın üst sınırı, soldan sağa parçalardan oluşan bir çizgi olarak tutulur.
+#[derive(Debug, Clone)]
+pub struct SkylinePacker {
+    width: u32,
+    height: u32,
+    padding: u32,
+    skyline: Vec<Segment>,
+}
+
+impl SkylinePacker {
+    // Verilen boyutta boş bir paketleyici oluşturur; padding her dikdörtgenin sağına ve altına bırakılır
+    pub fn new(width: u32, height: u32, padding: u32) -> SkylinePacker {
+        SkylinePacker { width, height, padding, skyline: vec![Segment { x: 0, y: 0, width }] }
+    }
+
+    // Alan genişliğini döndürür
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    // Alan yüksekliğini döndürür
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    // Dikdörtgen index'inci parçadan başlarsa oturacağı y'yi döndürür; sığmıyorsa None
+    fn fit(&self, index: usize, width: u32, height: u32) -> Option<u32> {
+        let x = self.skyline[index].x;
+        if x + width > self.width {
+            return None;
+        }
+        let (mut y, mut remaining) = (0, width);
+        for segment in &self.skyline[index..] {
+            if remaining == 0 {
+                break;
+            }
+            y = y.max(segment.y);
+            if y + height > self.height {
+                return None;
+            }
+            remaining = remaining.saturating_sub(segment.width);
+        }
+        Some(y)
+    }
+
+    // Dikdörtgeni en alçak (eşitlikte en dar parçaya) yerleştirir ve bölgesini döndürür; yer yoksa None
+    pub fn pack(&mut self, width: u32, height: u32) -> Option<Rect> {
+        let (padded_width, padded_height) = (width + self.padding, height + self.padding);
+        let mut best: Option<(usize, u32)> = None;
+        for index in 0..self.skyline.len() {
+            if let Some(y) = self.fit(index, padded_width, padded_height) {
+                let better = match best {
+                    None => true,
+                    Some((best_index, best_y)) => {
+                        y < best_y || (y == best_y && self.skyline[index].width < self.skyline[best_index].width)
+                    }
+                };
+                if better {
+                    best = Some((index, y));
+                }
+            }
+        }
+
+        let (index, y) = best?;
+        let x = self.skyline[index].x;
+        self.skyline.insert(index, Segment { x, y: y + padded_height, width: padded_width });
+
+        // Trim or remove the segments now covered by the new one
+        let end = x + padded_width;
+        while index + 1 < self.skyline.len() && self.skyline[index + 1].x < end {
+            let next = &mut self.skyline[index + 1];
+            let overlap = end - next.x;
+            if overlap >= next.width {
+                self.skyline.remove(index + 1);
+            } else {
+                next.x += overlap;
+                next.width -= overlap;
+                break;
+            }
+        }
+
+        // Merge neighbours at the same height
+        let mut i = 0;
+        while i + 1 < self.skyline.len() {
+            if self.skyline[i].y == self.skyline[i + 1].y {
+                self.skyline[i].width += self.skyline[i + 1].width;
+                self.skyline.remove(i + 1);
+            } else {
+                i += 1;
+            }
+        }
+
+        Some(Rect::new(x, y, width, height))
+    }
+}
+
+// Paketlenmiş atlas: birleşik görüntü ve her görüntünün bölgesi
+#[derive(Debug, Clone)]
+pub struct PackedAtlas {
+    pub image: Image,
+    pub regions: BTreeMap<String, Rect>,
+}
+
+impl PackedAtlas {
+    // Adı verilen görüntünün doku koordinatlarını döndürür
+    pub fn uv(&self, name: &str) -> Option<UvRect> {
+        self.regions.get(name).map(|rect| rect.uv(self.image.width, self.image.height))
+    }
+}
+
+// Çalışma zamanında birçok görüntüyü tek dokuda birleştiren atlas oluşturucu
+pub struct AtlasBuilder {
+    images: Vec<(String, Image)>,
+    max_size: u32,
+    padding: u32,
+}
+
+impl AtlasBuilder {
+    // Kenarı en fazla max_size piksel olan atlaslar üreten bir oluşturucu oluşturur
+    pub fn new(max_size: u32) -> AtlasBuilder {
+        AtlasBuilder { images: Vec::new(), max_size, padding: 1 }
+    }
+
+    // Görüntüler arasında bırakılacak boşluğu ayarlar (varsayılan 1 piksel, doku süzgecinin taşmasını önler)
+    pub fn with_padding(mut self, padding: u32) -> AtlasBuilder {
+        self.padding = padding;
+        self
+    }
+
+    // Görüntüyü verilen adla ekler; aynı ad tekrar eklenirse eskisinin yerini alır
+    pub fn add(&mut self, name: &str, image: Image) {
+        self.images.retain(|(existing, _)| existing != name);
+        self.images.push((name.to_string(), image));
+    }
+
+    // PNG dosyasını dosya adıyla ekler
+    pub fn add_png<P: AsRef<std::path::Path>>(&mut self, name: &str, path: P) -> Result<(), String> {
+        let path = path.as_ref();
+        let resolved = crate::locator::resolve(path)
+            .ok_or_else(|| format!("{}: image not found in any asset directory", path.display()))?;
+        let image = Image::load_png(&resolved).map_err(|e| format!("{}: {}", resolved.display(), e))?;
+        self.add(name, image);
+        Ok(())
+    }
+
+    // Görüntüleri verilen boyutta yerleştirmeyi dener
+    fn layout(&self, order: &[usize], width: u32, height: u32) -> Option<Vec<Rect>> {
+        let mut packer = SkylinePacker::new(width, height, self.padding);
+        let mut rects = vec![Rect::new(0, 0, 0, 0); self.images.len()];
+        for &i in order {
+            let image = &self.images[i].1;
+            rects[i] = packer.pack(image.width, image.height)?;
+        }
+        Some(rects)
+    }
+
+    // Görüntüleri paketler. Atlas, toplam alana yetecek en küçük ikinin kuvveti kenarla başlar
+    // ve görüntüler sığana kadar max_size'a kadar büyütülür.
+    pub fn build(&self) -> Result<PackedAtlas, String> {
+        // Placing tall images first leaves a flatter skyline and less wasted space
+        let mut order: Vec<usize> = (0..self.images.len()).collect();
+        order.sort_by_key(|&i| std::cmp::Reverse((self.images[i].1.height, self.images[i].1.width)));
+
+        let area: u64 = self
+            .images
+            .iter()
+            .map(|(_, image)| (image.width + self.padding) as u64 * (image.height + self.padding) as u64)
+            .sum();
+        let (mut width, mut height) = (1u32, 1u32);
+        while (width as u64) * (height as u64) < area {
+            if width <= height { width *= 2 } else { height *= 2 }
+        }
+
+        loop {
+            if width > self.max_size || height > self.max_size {
+                return Err(format!(
+                    "{} images do not fit in a {}x{} atlas",
+                    self.images.len(),
+                    self.max_size,
+                    self.max_size
+                ));
+            }
+            if let Some(rects) = self.layout(&order, width, height) {
+                let mut image = Image::new(width, height, [0, 0, 0, 0]);
+                let mut regions = BTreeMap::new();
+                for ((name, source), rect) in self.images.iter().zip(rects) {
+                    image.blit(source, rect.x, rect.y);
+                    regions.insert(name.clone(), rect);
+                }
+                return Ok(PackedAtlas { image, regions });
+            }
+            if width <= height { width *= 2 } else { height *= 2 }
+        }
+    }
+}
+
+// GPU'ya yüklenmiş atlas
+pub struct TextureAtlas {
+    pub texture: Rc<Texture>,
+    regions: BTreeMap<String, Rect>,
+}
+
+impl TextureAtlas {
+    // Paketlenmiş atlası dokuya yükler. Satırlar çevrilmeden yüklenir, böylece v = 0 görüntünün
+    // üst satırı olur ve SpriteBatch'in y ekseni aşağı bakan ekran izdüşümüyle doğrudan eşleşir.
+    pub fn upload(atlas: &PackedAtlas, srgb: bool) -> TextureAtlas {
+        let format = if srgb { TextureFormat::Srgb8Alpha8 } else { TextureFormat::Rgba8 };
+        let texture = Texture::from_data(atlas.image.width, atlas.image.height, format, Some(&atlas.image.pixels));
+        TextureAtlas { texture: Rc::new(texture), regions: atlas.regions.clone() }
+    }
+
+    // Adı verilen görüntünün piksel bölgesini döndürür
+    pub fn region(&self, name: &str) -> Option<Rect> {
+        self.regions.get(name).copied()
+    }
+
+    // Adı verilen görüntünün doku koordinatlarını döndürür
+    pub fn uv(&self, name: &str) -> Option<UvRect> {
+        self.region(name).map(|rect| rect.uv(self.texture.width(), self.texture.height()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn packed_rects_stay_inside_and_never_overlap() {
+        let mut packer = SkylinePacker::new(128, 128, 1);
+        let mut rects = Vec::new();
+        // A deterministic mix of sizes
+        for i in 0..40u32 {
+            let (width, height) = (4 + (i * 7) % 23, 3 + (i * 11) % 17);
+            rects.push(packer.pack(width, height).expect("space left"));
+        }
+
+        for (i, a) in rects.iter().enumerate() {
+            assert!(a.x + a.width <= 128 && a.y + a.height <= 128, "{:?}", a);
+            for b in &rects[i + 1..] {
+                assert!(!a.intersects(b), "{:?} overlaps {:?}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn packer_fills_rows_before_going_up_and_reports_full() {
+        let mut packer = SkylinePacker::new(8, 8, 0);
+        assert_eq!(packer.pack(4, 4), Some(Rect::new(0, 0, 4, 4)));
+        assert_eq!(packer.pack(4, 2), Some(Rect::new(4, 0, 4, 2)));
+        assert_eq!(packer.pack(4, 2), Some(Rect::new(4, 2, 4, 2)));
+        assert_eq!(packer.pack(8, 4), Some(Rect::new(0, 4, 8, 4)));
+        assert_eq!(packer.pack(1, 1), None);
+    }
+
+    #[test]
+    fn builder_grows_and_copies_pixels() {
+        let mut builder = AtlasBuilder::new(256).with_padding(0);
+        builder.add("red", Image::new(20, 10, [255, 0, 0, 255]));
+        builder.add("blue", Image::new(10, 20, [0, 0, 255, 255]));
+        let atlas = builder.build().unwrap();
+
+        assert!(atlas.image.width.is_power_of_two() && atlas.image.height.is_power_of_two());
+        let red = atlas.regions["red"];
+        assert_eq!(atlas.image.pixel(red.x + 19, red.y + 9), [255, 0, 0, 255]);
+        let blue = atlas.regions["blue"];
+        assert_eq!(atlas.image.pixel(blue.x, blue.y), [0, 0, 255, 255]);
+
+        let uv = atlas.uv("red").unwrap();
+        assert_eq!(uv.min, [red.x as f32 / atlas.image.width as f32, red.y as f32 / atlas.image.height as f32]);
+
+        let mut tiny = AtlasBuilder::new(16);
+        tiny.add("big", Image::new(32, 4, [0; 4]));
+        assert!(tiny.build().is_err());
+    }
+}
@@ -0,0 +1,57 @@
+use crate::matrix4::Matrix4;
+use crate::vector3d::Vector3D;
+
+pub struct Camera {
+    pub position: Vector3D,
+    pub target: Vector3D,
+    pub up: Vector3D,
+    pub fov_y: f32,  // Degrees
+    pub aspect: f32,
+    pub near: f32,
+    pub far: f32,
+}
+
+impl Camera {
+    // Yeni bir perspektif kamera oluşturur
+    pub fn new(position: Vector3D, target: Vector3D, aspect: f32) -> Camera {
+        Camera {
+            position,
+            target,
+            up: Vector3D::new(0.0, 1.0, 0.0),
+            fov_y: 45.0,
+            aspect,
+            near: 0.1,
+            far: 50.0,
+        }
+    }
+
+    // Görünüm matrisini döndürür
+    pub fn view_matrix(&self) -> Matrix4 {
+        Matrix4::look_at(self.position, self.target, self.up)
+    }
+
+    // İzdüşüm matrisini döndürür
+    pub fn projection_matrix(&self) -> Matrix4 {
+        Matrix4::perspective(self.fov_y.to_radians(), self.aspect, self.near, self.far)
+    }
+
+    // Verilen yakın/uzak aralığındaki görüş hacminin 8 köşesini dünya uzayında döndürür
+    pub fn frustum_corners(&self, near: f32, far: f32) -> [Vector3D; 8] {
+        let projection = Matrix4::perspective(self.fov_y.to_radians(), self.aspect, near, far);
+        let inverse = (projection * self.view_matrix())
+            .inverse()
+            .unwrap_or_else(Matrix4::identity);
+
+        let mut corners = [Vector3D::zero(); 8];
+        let mut i = 0;
+        for &x in &[-1.0, 1.0] {
+            for &y in &[-1.0, 1.0] {
+                for &z in &[-1.0, 1.0] {
+                    corners[i] = inverse.transform_point(Vector3D::new(x, y, z));
+                    i += 1;
+                }
+            }
+        }
+        corners
+    }
+}
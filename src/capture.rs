@@ -0,0 +1,257 @@
+extern crate gl;
+
+use std::ffi::c_void;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::framebuffer::Framebuffer;
+
+// Satırları yukarıdan aşağıya sıralı RGBA8 görüntü
+#[derive(Debug, Clone, PartialEq)]
+pub struct Image {
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<u8>,
+}
+
+impl Image {
+    // Verilen boyutta, tek renkle doldurulmuş bir görüntü oluşturur
+    pub fn new(width: u32, height: u32, fill: [u8; 4]) -> Image {
+        let pixels = fill.iter().copied().cycle().take((width * height * 4) as usize).collect();
+        Image { width, height, pixels }
+    }
+
+    // Bağlı okuma framebuffer'ının (varsayılan olarak pencerenin arka tamponu) içeriğini okur
+    pub fn read_pixels(x: i32, y: i32, width: u32, height: u32) -> Image {
+        let mut pixels = vec![0u8; (width * height * 4) as usize];
+        unsafe {
+            gl::PixelStorei(gl::PACK_ALIGNMENT, 1);
+            gl::ReadPixels(
+                x,
+                y,
+                width as i32,
+                height as i32,
+                gl::RGBA,
+                gl::UNSIGNED_BYTE,
+                pixels.as_mut_ptr() as *mut c_void,
+            );
+            gl::PixelStorei(gl::PACK_ALIGNMENT, 4);
+        }
+
+        // OpenGL returns the bottom row first
+        let mut image = Image { width, height, pixels };
+        image.flip_vertical();
+        image
+    }
+
+    // Pencerenin arka tamponunu (swap_buffers'tan önce) okur
+    pub fn capture_screen(width: u32, height: u32) -> Image {
+        unsafe {
+            gl::BindFramebuffer(gl::READ_FRAMEBUFFER, 0);
+            gl::ReadBuffer(gl::BACK);
+        }
+        Image::read_pixels(0, 0, width, height)
+    }
+
+    // Bir framebuffer'ın verilen renk ekini okur
+    pub fn capture_framebuffer(framebuffer: &Framebuffer, attachment: usize) -> Image {
+        framebuffer.resolve();
+        let image;
+        unsafe {
+            gl::BindFramebuffer(gl::READ_FRAMEBUFFER, framebuffer.id());
+            gl::ReadBuffer(gl::COLOR_ATTACHMENT0 + attachment as u32);
+            image = Image::read_pixels(0, 0, framebuffer.width(), framebuffer.height());
+            gl::BindFramebuffer(gl::READ_FRAMEBUFFER, 0);
+        }
+        image
+    }
+
+    // Görüntüyü dikey olarak çevirir
+    pub fn flip_vertical(&mut self) {
+        let row = (self.width * 4) as usize;
+        let height = self.height as usize;
+        for y in 0..height / 2 {
+            let (top, bottom) = self.pixels.split_at_mut((height - 1 - y) * row);
+            top[y * row..(y + 1) * row].swap_with_slice(&mut bottom[..row]);
+        }
+    }
+
+    // (x, y) konumundaki pikseli döndürür
+    pub fn pixel(&self, x: u32, y: u32) -> [u8; 4] {
+        let i = ((y * self.width + x) * 4) as usize;
+        [self.pixels[i], self.pixels[i + 1], self.pixels[i + 2], self.pixels[i + 3]]
+    }
+
+    // (x, y) konumundaki pikseli değiştirir
+    pub fn set_pixel(&mut self, x: u32, y: u32, color: [u8; 4]) {
+        let i = ((y * self.width + x) * 4) as usize;
+        self.pixels[i..i + 4].copy_from_slice(&color);
+    }
+
+    // Başka bir görüntüyü sol üst köşesi (x, y) olacak şekilde bu görüntünün üzerine kopyalar; taşan kısım kırpılır
+    pub fn blit(&mut self, source: &Image, x: u32, y: u32) {
+        if x >= self.width || y >= self.height {
+            return;
+        }
+        let columns = source.width.min(self.width.saturating_sub(x)) as usize;
+        let rows = source.height.min(self.height.saturating_sub(y));
+        for row in 0..rows {
+            let from = (row * source.width * 4) as usize;
+            let to = (((y + row) * self.width + x) * 4) as usize;
+            self.pixels[to..to + columns * 4].copy_from_slice(&source.pixels[from..from + columns * 4]);
+        }
+    }
+
+    // Görüntüyü PNG dosyası olarak kaydeder
+    pub fn save_png<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        if let Some(parent) = path.as_ref().parent() {
+            if !parent.as_os_str().is_empty() {
+                std::fs::create_dir_all(parent)?;
+            }
+        }
+
+        let file = File::create(path)?;
+        let mut encoder = png::Encoder::new(BufWriter::new(file), self.width, self.height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        writer.write_image_data(&self.pixels).map_err(io::Error::other)?;
+        Ok(())
+    }
+
+    // PNG dosyasını RGBA8 görüntü olarak yükler
+    pub fn load_png<P: AsRef<Path>>(path: P) -> io::Result<Image> {
+        let file = File::open(path)?;
+        let mut decoder = png::Decoder::new(BufReader::new(file));
+        decoder.set_transformations(png::Transformations::normalize_to_color8());
+        let mut reader = decoder.read_info().map_err(io::Error::other)?;
+        let mut buffer = vec![0u8; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut buffer).map_err(io::Error::other)?;
+        buffer.truncate(info.buffer_size());
+
+        let pixels = match info.color_type {
+            png::ColorType::Rgba => buffer,
+            png::ColorType::Rgb => buffer.chunks(3).flat_map(|p| [p[0], p[1], p[2], 255]).collect(),
+            png::ColorType::GrayscaleAlpha => buffer.chunks(2).flat_map(|p| [p[0], p[0], p[0], p[1]]).collect(),
+            png::ColorType::Grayscale => buffer.iter().flat_map(|&v| [v, v, v, 255]).collect(),
+            png::ColorType::Indexed => {
+                return Err(io::Error::new(io::ErrorKind::InvalidData, "indexed PNG was not expanded"));
+            }
+        };
+
+        Ok(Image { width: info.width, height: info.height, pixels })
+    }
+}
+
+// Dosya adlarında kullanılan UTC zaman damgasını döndürür (YYYYMMDD_HHMMSS_mmm)
+pub fn timestamp() -> String {
+    let elapsed = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+    let seconds = elapsed.as_secs();
+    let (year, month, day) = civil_from_days((seconds / 86400) as i64);
+    let time = seconds % 86400;
+    format!(
+        "{:04}{:02}{:02}_{:02}{:02}{:02}_{:03}",
+        year,
+        month,
+        day,
+        time / 3600,
+        time / 60 % 60,
+        time % 60,
+        elapsed.subsec_millis()
+    )
+}
+
+// 1970-01-01'den itibaren geçen gün sayısını (yıl, ay, gün) tarihine çevirir
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    // Howard Hinnant's days_from_civil inverse, valid for the proleptic Gregorian calendar
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+// Ekran görüntüsünü verilen klasöre zaman damgalı bir PNG olarak kaydeder
+pub fn save_screenshot<P: AsRef<Path>>(image: &Image, directory: P) -> io::Result<PathBuf> {
+    let path = directory.as_ref().join(format!("screenshot_{}.png", timestamp()));
+    image.save_png(&path)?;
+    Ok(path)
+}
+
+// Kareleri numaralı PNG dizisi olarak kaydeden kayıt aracı.
+// Her kare sabit bir zaman adımına karşılık gelir, böylece dizi verilen fps ile videoya çevrilebilir.
+pub struct Recorder {
+    directory: PathBuf,
+    fps: u32,
+    frames: u32,
+}
+
+impl Recorder {
+    // Verilen klasörün altında zaman damgalı yeni bir kayıt klasörü açar
+    pub fn start<P: AsRef<Path>>(root: P, fps: u32) -> io::Result<Recorder> {
+        let directory = root.as_ref().join(format!("recording_{}", timestamp()));
+        std::fs::create_dir_all(&directory)?;
+        Ok(Recorder { directory, fps: fps.max(1), frames: 0 })
+    }
+
+    // Bir karenin temsil ettiği sabit süreyi saniye cinsinden döndürür
+    pub fn timestep(&self) -> f32 {
+        1.0 / self.fps as f32
+    }
+
+    // Kareyi dizinin bir sonraki numarasıyla kaydeder
+    pub fn record(&mut self, image: &Image) -> io::Result<()> {
+        let path = self.directory.join(format!("frame_{:05}.png", self.frames));
+        image.save_png(path)?;
+        self.frames += 1;
+        Ok(())
+    }
+
+    // Kaydedilen kare sayısını döndürür
+    pub fn frames(&self) -> u32 {
+        self.frames
+    }
+
+    // Kayıt klasörünü döndürür
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    // Kayıt dizisini videoya çevirmek için örnek ffmpeg komutunu döndürür
+    pub fn ffmpeg_command(&self) -> String {
+        format!(
+            "ffmpeg -framerate {} -i {}/frame_%05d.png -pix_fmt yuv420p recording.mp4",
+            self.fps,
+            self.directory.display()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blit_clips_at_the_edges() {
+        let mut image = Image::new(4, 3, [0, 0, 0, 255]);
+        let source = Image::new(2, 2, [255, 255, 255, 255]);
+
+        image.blit(&source, 3, 2);
+        assert_eq!(image.pixel(3, 2), [255, 255, 255, 255]);
+        assert_eq!(image.pixel(2, 2), [0, 0, 0, 255]);
+
+        // Sources starting past the right or bottom edge are dropped instead of writing out of bounds
+        let before = image.clone();
+        image.blit(&source, 4, 0);
+        image.blit(&source, 9, 1);
+        image.blit(&source, 0, 3);
+        assert_eq!(image, before);
+    }
+}
@@ -1,7 +1,10 @@
 extern crate gl;
 
+use crate::color::{self, Color, VertexColors};
 use crate::shader::Shader;
-use crate::draw::Shape;
+use crate::draw::{RenderContext, Shape};
+use crate::matrix4::Matrix4;
+use crate::mesh::MeshData;
 use crate::vector2d::Vector2D;
 use std::rc::Rc;
 use std::ffi::c_void;
@@ -14,6 +17,21 @@ pub struct Circle {
     vbo: gl::types::GLuint,
     shader: Rc<Shader>,
     num_vertices: i32,
+    pub color: Color,  // Fill color, used by the flat color shader
+    colors: VertexColors,
+}
+
+// Çevre boyunca eşit aralıklarla dizilmiş renkleri (ilki 0 radyanda) `count` köşeye açıya göre karıştırır.
+// Son köşe ilk köşeyle aynı noktadadır.
+fn blend_around(colors: &[Color], count: usize) -> Vec<Color> {
+    let segments = count.saturating_sub(1).max(1) as f32;
+    (0..count)
+        .map(|i| {
+            let t = i as f32 / segments * colors.len() as f32;
+            let from = t.floor() as usize;
+            colors[from % colors.len()].lerp(colors[(from + 1) % colors.len()], t.fract())
+        })
+        .collect()
 }
 
 impl Circle {
@@ -26,11 +44,23 @@ impl Circle {
             vbo: 0,
             shader,
             num_vertices: 0,
+            color: Color::WHITE,
+            colors: VertexColors::new(),
         };
         circle.init();
         circle
     }
 
+    // Çevre boyunca, sağdan (0 radyan) başlayıp saat yönünün tersine eşit aralıklarla dizilen renkler verir;
+    // renkler aralarında açıya göre karışır. Tek renk bütün daireyi boyar.
+    pub fn set_vertex_colors(&mut self, colors: &[Color]) -> Result<(), String> {
+        if colors.is_empty() {
+            return Err("a circle needs at least one vertex color".to_string());
+        }
+        self.colors.set(blend_around(colors, self.num_vertices as usize));
+        Ok(())
+    }
+
     // Daire köşe noktalarını oluşturur
     fn generate_circle_vertices(radius: f32, segments: i32, center: &Vector2D) -> (Vec<f32>, Vec<f32>) {
         let mut vertices = Vec::new();
@@ -97,19 +127,35 @@ impl Shape for Circle {
             );
             gl::EnableVertexAttribArray(1);
 
+            self.colors.attach(2, self.num_vertices as usize);
+
             gl::BindBuffer(gl::ARRAY_BUFFER, 0);
             gl::BindVertexArray(0);
         }
     }
 
     // Daireyi çizer
-    fn draw(&self) {
-        unsafe {
-            self.shader.use_program();
+    fn draw(&self, ctx: &RenderContext, model: &Matrix4) {
+        self.shader.use_program();
+        ctx.apply_2d(&self.shader, model);
+        self.shader.set_vec4("color", ctx.color(self.color));
+        color::draw_blended(self.color.a < 1.0 || self.colors.translucent(), || unsafe {
             gl::BindVertexArray(self.vao);
             gl::DrawArrays(gl::TRIANGLE_FAN, 0, self.num_vertices);
             gl::BindVertexArray(0);
-        }
+        });
+    }
+
+    // Çizimdeki üçgen yelpazesini ayrı üçgenler olarak döndürür
+    fn mesh_data(&self) -> Option<MeshData> {
+        let (vertices, tex_coords) = Circle::generate_circle_vertices(self.radius, 40, &self.center);
+        let count = vertices.len() as u32 / 3;
+        Some(MeshData {
+            positions: vertices.chunks_exact(3).map(|v| [v[0], v[1], v[2]]).collect(),
+            normals: vec![[0.0, 0.0, 1.0]; count as usize],
+            uvs: tex_coords.chunks_exact(2).map(|t| [t[0], t[1]]).collect(),
+            indices: (1..count.saturating_sub(1)).flat_map(|i| [0, i, i + 1]).collect(),
+        })
     }
 }
 
@@ -122,3 +168,22 @@ impl Drop for Circle {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn colors_blend_around_the_circle() {
+        let (red, blue) = (Color::rgb(1.0, 0.0, 0.0), Color::rgb(0.0, 0.0, 1.0));
+        let colors = blend_around(&[red, blue], 41);
+        assert_eq!(colors.len(), 41);
+        assert_eq!(colors[0], red);
+        assert_eq!(colors[20], blue);
+        assert_eq!(colors[10], Color::from_linear([0.5, 0.0, 0.5, 1.0]));
+        // The closing vertex wraps back to the first color
+        assert_eq!(colors[40], red);
+
+        assert!(blend_around(&[blue], 5).iter().all(|&c| c == blue));
+    }
+}
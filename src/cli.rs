@@ -0,0 +1,275 @@
+use std::path::PathBuf;
+
+use crate::app::AppSettings;
+use crate::export::ExportFormat;
+
+// Varsayılan olarak yüklenen sahne dosyası
+pub const DEFAULT_SCENE: &str = "scenes/demo.ron";
+
+pub const USAGE: &str = "\
+Usage: opengl_renderer [OPTIONS]
+
+Options:
+      --scene <FILE>        Scene file to load (.ron or .json) [default: scenes/demo.ron]
+      --width <PIXELS>      Window width [default: 800]
+      --height <PIXELS>     Window height [default: 600]
+      --size <WxH>          Window width and height, e.g. 1280x720
+      --title <TEXT>        Window title
+      --fullscreen          Open the window fullscreen on the primary monitor
+      --vsync <on|off>      Wait for vertical sync when presenting frames [default: on]
+      --msaa <SAMPLES>      Multisample anti-aliasing samples, 1 disables it [default: 4]
+      --max-fps <FPS>       Cap the frame rate of the window, also with vsync off [default: unlimited]
+      --hide <NAME>         Hide the scene node with this name and its children (repeatable)
+      --font <FILE>         TrueType/OpenType font of the on-screen FPS counter (hidden without it) and debug text
+      --shader-dir <DIR>    Directory shader file names are resolved against [default: src/Shaders]
+      --asset-dir <DIR>     Extra directory searched for scenes and shaders before RENDERER_ASSET_PATH,
+                            the working directory and the executable's directory (repeatable)
+      --headless            Render without a visible window and save the last frame as PNG
+      --output <FILE>       Output image of --headless [default: frame.png]
+      --frames <COUNT>      Number of frames rendered by --headless [default: 1]
+      --export <FILE>       Write the scene geometry with transforms applied to an .obj, .ply or .stl file and exit
+      --export-format <FMT> Format of --export: obj, ply, stl or stl-ascii [default: from the file extension]
+      --context-api <API>   OpenGL context of --headless and --export: native, egl or osmesa [default: native];
+                            egl and osmesa can render with Mesa's software rasterizer without a display
+  -h, --help                Print this help
+";
+
+// Ekransız çalıştırma seçenekleri
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeadlessOptions {
+    pub output: PathBuf,
+    pub frames: u32,
+}
+
+// Sahne geometrisini dışa aktarma seçenekleri
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportOptions {
+    pub path: PathBuf,
+    pub format: Option<ExportFormat>,
+}
+
+// Komut satırından okunan seçenekler
+#[derive(Debug, Clone)]
+pub struct CliOptions {
+    pub settings: AppSettings,
+    pub scene: PathBuf,
+    pub shader_dir: Option<PathBuf>,
+    pub asset_dirs: Vec<PathBuf>,
+    pub headless: Option<HeadlessOptions>,
+    pub export: Option<ExportOptions>,
+    pub context_api: glfw::ContextCreationApi,
+}
+
+#[derive(Debug)]
+pub enum Command {
+    Run(Box<CliOptions>),
+    Help,
+}
+
+// Sıfırdan büyük sayısal bir argümanı ayrıştırır
+fn parse_number(option: &str, value: &str) -> Result<u32, String> {
+    match value.parse() {
+        Ok(number) if number > 0 => Ok(number),
+        _ => Err(format!("invalid value '{}' for {}: expected a positive integer", value, option)),
+    }
+}
+
+// "on/off" biçimindeki bir argümanı ayrıştırır
+fn parse_switch(option: &str, value: &str) -> Result<bool, String> {
+    match value {
+        "on" | "true" | "1" => Ok(true),
+        "off" | "false" | "0" => Ok(false),
+        _ => Err(format!("invalid value '{}' for {}: expected on or off", value, option)),
+    }
+}
+
+// Ekransız bağlam tipini (native, egl, osmesa) ayrıştırır
+pub fn parse_context_api(value: &str) -> Result<glfw::ContextCreationApi, String> {
+    match value.to_lowercase().as_str() {
+        "native" => Ok(glfw::ContextCreationApi::Native),
+        "egl" => Ok(glfw::ContextCreationApi::Egl),
+        "osmesa" => Ok(glfw::ContextCreationApi::OsMesa),
+        _ => Err(format!("invalid context API '{}': expected native, egl or osmesa", value)),
+    }
+}
+
+// Program adı hariç komut satırı argümanlarını ayrıştırır.
+// Değer alan seçenekler "--width 1280" ve "--width=1280" biçimlerinin ikisini de kabul eder.
+pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Command, String> {
+    let mut settings = AppSettings::default();
+    let mut scene = PathBuf::from(DEFAULT_SCENE);
+    let mut shader_dir = None;
+    let mut asset_dirs = Vec::new();
+    let mut headless = false;
+    let mut output = None;
+    let mut frames = None;
+    let mut export = None;
+    let mut export_format = None;
+    let mut context_api = None;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let (option, inline_value) = match arg.split_once('=') {
+            Some((option, value)) if option.starts_with("--") => (option.to_string(), Some(value.to_string())),
+            _ => (arg.clone(), None),
+        };
+
+        let takes_value = !matches!(option.as_str(), "-h" | "--help" | "--fullscreen" | "--headless");
+        if !takes_value && inline_value.is_some() {
+            return Err(format!("{} does not take a value", option));
+        }
+        let mut value = || -> Result<String, String> {
+            match &inline_value {
+                Some(value) => Ok(value.clone()),
+                None => args.next().ok_or_else(|| format!("{} requires a value", option)),
+            }
+        };
+
+        match option.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "--fullscreen" => settings.fullscreen = true,
+            "--headless" => headless = true,
+            "--scene" => scene = PathBuf::from(value()?),
+            "--title" => settings.title = value()?,
+            "--width" => settings.width = parse_number(&option, &value()?)?,
+            "--height" => settings.height = parse_number(&option, &value()?)?,
+            "--size" => {
+                let size = value()?;
+                let (width, height) = size
+                    .split_once('x')
+                    .ok_or_else(|| format!("invalid value '{}' for --size: expected WIDTHxHEIGHT", size))?;
+                settings.width = parse_number(&option, width)?;
+                settings.height = parse_number(&option, height)?;
+            }
+            "--vsync" => settings.vsync = parse_switch(&option, &value()?)?,
+            "--msaa" => settings.samples = parse_number(&option, &value()?)?,
+            "--max-fps" => settings.max_fps = Some(parse_number(&option, &value()?)?),
+            "--font" => settings.font = Some(PathBuf::from(value()?)),
+            "--hide" => settings.hidden.push(value()?),
+            "--shader-dir" => shader_dir = Some(PathBuf::from(value()?)),
+            "--asset-dir" => asset_dirs.push(PathBuf::from(value()?)),
+            "--output" => output = Some(PathBuf::from(value()?)),
+            "--frames" => frames = Some(parse_number(&option, &value()?)?),
+            "--export" => export = Some(PathBuf::from(value()?)),
+            "--export-format" => export_format = Some(ExportFormat::parse(&value()?)?),
+            "--context-api" => context_api = Some(parse_context_api(&value()?)?),
+            _ => return Err(format!("unknown argument '{}'", arg)),
+        }
+    }
+
+    if !headless && (output.is_some() || frames.is_some()) {
+        return Err("--output and --frames can only be used with --headless".to_string());
+    }
+    if headless && export.is_some() {
+        return Err("--export cannot be combined with --headless".to_string());
+    }
+    if export.is_none() && export_format.is_some() {
+        return Err("--export-format can only be used with --export".to_string());
+    }
+    if !headless && export.is_none() && context_api.is_some() {
+        return Err("--context-api can only be used with --headless or --export".to_string());
+    }
+
+    let headless = headless.then(|| HeadlessOptions {
+        output: output.unwrap_or_else(|| PathBuf::from("frame.png")),
+        frames: frames.unwrap_or(1),
+    });
+    let export = export.map(|path| ExportOptions { path, format: export_format });
+    let context_api = context_api.unwrap_or(glfw::ContextCreationApi::Native);
+    Ok(Command::Run(Box::new(CliOptions { settings, scene, shader_dir, asset_dirs, headless, export, context_api })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_args(args: &[&str]) -> Result<Command, String> {
+        parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    fn options(args: &[&str]) -> CliOptions {
+        match parse_args(args) {
+            Ok(Command::Run(options)) => *options,
+            other => panic!("expected options, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn defaults_without_arguments() {
+        let options = options(&[]);
+        assert_eq!(options.settings.width, 800);
+        assert_eq!(options.settings.height, 600);
+        assert!(options.settings.vsync);
+        assert_eq!(options.settings.max_fps, None);
+        assert_eq!(options.scene, PathBuf::from(DEFAULT_SCENE));
+        assert!(options.headless.is_none());
+        assert!(options.export.is_none());
+        assert_eq!(options.context_api, glfw::ContextCreationApi::Native);
+    }
+
+    #[test]
+    fn parses_export_options() {
+        let options = options(&["--export", "scene.stl", "--export-format=stl-ascii"]);
+        assert_eq!(
+            options.export,
+            Some(ExportOptions { path: PathBuf::from("scene.stl"), format: Some(ExportFormat::StlAscii) })
+        );
+    }
+
+    #[test]
+    fn parses_window_and_headless_options() {
+        let options = options(&[
+            "--size=1280x720",
+            "--vsync",
+            "off",
+            "--msaa",
+            "8",
+            "--max-fps=144",
+            "--scene",
+            "scenes/other.json",
+            "--asset-dir=assets",
+            "--asset-dir",
+            "/opt/renderer",
+            "--headless",
+            "--frames=30",
+            "--context-api=EGL",
+            "--font",
+            "fonts/DejaVuSans.ttf",
+            "--hide=cube",
+            "--hide",
+            "orbit",
+        ]);
+        assert_eq!((options.settings.width, options.settings.height), (1280, 720));
+        assert!(!options.settings.vsync);
+        assert_eq!(options.settings.samples, 8);
+        assert_eq!(options.settings.max_fps, Some(144));
+        assert_eq!(options.settings.font, Some(PathBuf::from("fonts/DejaVuSans.ttf")));
+        assert_eq!(options.settings.hidden, ["cube", "orbit"]);
+        assert_eq!(options.scene, PathBuf::from("scenes/other.json"));
+        assert_eq!(options.asset_dirs, [PathBuf::from("assets"), PathBuf::from("/opt/renderer")]);
+        assert_eq!(options.headless, Some(HeadlessOptions { output: PathBuf::from("frame.png"), frames: 30 }));
+        assert_eq!(options.context_api, glfw::ContextCreationApi::Egl);
+    }
+
+    #[test]
+    fn reports_invalid_arguments() {
+        assert!(matches!(parse_args(&["--help"]), Ok(Command::Help)));
+        assert_eq!(parse_args(&["--width", "wide"]).unwrap_err(), "invalid value 'wide' for --width: expected a positive integer");
+        assert_eq!(parse_args(&["--size", "0x600"]).unwrap_err(), "invalid value '0' for --size: expected a positive integer");
+        assert_eq!(parse_args(&["--msaa", "0"]).unwrap_err(), "invalid value '0' for --msaa: expected a positive integer");
+        assert_eq!(parse_args(&["--max-fps=0"]).unwrap_err(), "invalid value '0' for --max-fps: expected a positive integer");
+        assert_eq!(
+            parse_args(&["--headless", "--frames", "0"]).unwrap_err(),
+            "invalid value '0' for --frames: expected a positive integer"
+        );
+        assert_eq!(parse_args(&["--scene"]).unwrap_err(), "--scene requires a value");
+        assert_eq!(parse_args(&["--frames", "2"]).unwrap_err(), "--output and --frames can only be used with --headless");
+        assert_eq!(parse_args(&["--bogus"]).unwrap_err(), "unknown argument '--bogus'");
+        assert_eq!(parse_args(&["--export-format", "stl"]).unwrap_err(), "--export-format can only be used with --export");
+        assert_eq!(parse_args(&["--context-api", "egl"]).unwrap_err(), "--context-api can only be used with --headless or --export");
+        assert_eq!(
+            parse_args(&["--headless", "--context-api", "wayland"]).unwrap_err(),
+            "invalid context API 'wayland': expected native, egl or osmesa"
+        );
+    }
+}
@@ -0,0 +1,325 @@
+extern crate gl;
+
+use gl::types::GLuint;
+use serde::de::{self, Deserializer};
+use serde::Deserialize;
+use std::ffi::c_void;
+use std::mem;
+
+// sRGB uzayında, 0-1 aralığında bileşenleri ve düz (önceden çarpılmamış) alfası olan bir renk.
+// Bileşenler gölgelendiricilere olduğu gibi, tıpkı sabit renkli fragment shader'lardaki değerler gibi verilir.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Color {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl Color {
+    pub const WHITE: Color = Color::rgb(1.0, 1.0, 1.0);
+    pub const BLACK: Color = Color::rgb(0.0, 0.0, 0.0);
+    pub const TRANSPARENT: Color = Color::new(0.0, 0.0, 0.0, 0.0);
+
+    // Bileşenleri verilen bir renk oluşturur
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Color {
+        Color { r, g, b, a }
+    }
+
+    // Opak bir renk oluşturur
+    pub const fn rgb(r: f32, g: f32, b: f32) -> Color {
+        Color::new(r, g, b, 1.0)
+    }
+
+    // Aynı rengi verilen alfa ile döndürür
+    pub fn with_alpha(self, a: f32) -> Color {
+        Color { a, ..self }
+    }
+
+    // "#rgb", "#rgba", "#rrggbb" veya "#rrggbbaa" biçimindeki bir rengi okur; baştaki '#' isteğe bağlıdır
+    pub fn from_hex(hex: &str) -> Result<Color, String> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        let error = || format!("invalid hex color '{}', expected #rgb, #rgba, #rrggbb or #rrggbbaa", hex);
+        if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(error());
+        }
+
+        // Short forms repeat each digit, so #f80 is #ff8800
+        let channels: Vec<u8> = match digits.len() {
+            3 | 4 => digits.bytes().map(|b| (b as char).to_digit(16).unwrap() as u8 * 17).collect(),
+            6 | 8 => (0..digits.len()).step_by(2).map(|i| u8::from_str_radix(&digits[i..i + 2], 16).unwrap()).collect(),
+            _ => return Err(error()),
+        };
+        let channel = |i: usize| channels.get(i).map_or(1.0, |&c| c as f32 / 255.0);
+        Ok(Color::new(channel(0), channel(1), channel(2), channel(3)))
+    }
+
+    // Rengi "#rrggbb" olarak, opak değilse "#rrggbbaa" olarak yazar
+    pub fn to_hex(self) -> String {
+        let [r, g, b, a] = self.to_array().map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8);
+        if a == 255 {
+            format!("#{:02x}{:02x}{:02x}", r, g, b)
+        } else {
+            format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a)
+        }
+    }
+
+    // Ton (derece), doygunluk ve parlaklıktan (0-1) opak bir renk oluşturur
+    pub fn from_hsv(hue: f32, saturation: f32, value: f32) -> Color {
+        let h = hue.rem_euclid(360.0) / 60.0;
+        let chroma = value * saturation;
+        let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+        let m = value - chroma;
+        let (r, g, b) = match h as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        Color::rgb(r + m, g + m, b + m)
+    }
+
+    // Rengi [ton (derece, 0-360), doygunluk, parlaklık] olarak döndürür; gri tonlarda ton 0'dır
+    pub fn to_hsv(self) -> [f32; 3] {
+        let max = self.r.max(self.g).max(self.b);
+        let delta = max - self.r.min(self.g).min(self.b);
+        let hue = if delta <= 0.0 {
+            0.0
+        } else if max == self.r {
+            60.0 * ((self.g - self.b) / delta).rem_euclid(6.0)
+        } else if max == self.g {
+            60.0 * ((self.b - self.r) / delta + 2.0)
+        } else {
+            60.0 * ((self.r - self.g) / delta + 4.0)
+        };
+        let saturation = if max <= 0.0 { 0.0 } else { delta / max };
+        [hue, saturation, max]
+    }
+
+    // Rengi doğrusal uzaya çevirir; alfa değişmez
+    pub fn to_linear(self) -> [f32; 4] {
+        [srgb_to_linear(self.r), srgb_to_linear(self.g), srgb_to_linear(self.b), self.a]
+    }
+
+    // Doğrusal uzaydaki bir rengi sRGB'ye çevirir; alfa değişmez
+    pub fn from_linear(rgba: [f32; 4]) -> Color {
+        Color::new(linear_to_srgb(rgba[0]), linear_to_srgb(rgba[1]), linear_to_srgb(rgba[2]), rgba[3])
+    }
+
+    // İki renk arasında doğrusal uzayda ara değer alır, böylece geçişlerin ortası kararmaz
+    pub fn lerp(self, other: Color, t: f32) -> Color {
+        // The end points are returned as is so the round trip through linear space cannot shift them
+        if t <= 0.0 || self == other {
+            return self;
+        }
+        if t >= 1.0 {
+            return other;
+        }
+        let (a, b) = (self.to_linear(), other.to_linear());
+        Color::from_linear([0, 1, 2, 3].map(|i| a[i] + (b[i] - a[i]) * t))
+    }
+
+    // Bileşenleri [r, g, b, a] olarak döndürür
+    pub fn to_array(self) -> [f32; 4] {
+        [self.r, self.g, self.b, self.a]
+    }
+}
+
+impl Default for Color {
+    fn default() -> Self {
+        Color::WHITE
+    }
+}
+
+// Sahne dosyalarında renkler hex metni ("#ff8800") ya da 3 veya 4 bileşenli bir dizi ((1.0, 0.5, 0.0, 0.8)) olabilir
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Hex(String),
+            Components(Vec<f32>),
+        }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::Hex(hex) => Color::from_hex(&hex).map_err(de::Error::custom),
+            Repr::Components(components) => match components[..] {
+                [r, g, b] => Ok(Color::rgb(r, g, b)),
+                [r, g, b, a] => Ok(Color::new(r, g, b, a)),
+                _ => Err(de::Error::custom(format!("expected 3 or 4 color components, got {}", components.len()))),
+            },
+        }
+    }
+}
+
+// sRGB bileşenini doğrusal uzaya çevirir
+pub fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+// Doğrusal bileşeni sRGB uzayına çevirir
+pub fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+// Bir şeklin köşe başına renklerini tutan ayrı köşe tamponu. Renk verilmemişse bütün köşeler beyazdır,
+// böylece gölgelendiricideki dolgu rengi olduğu gibi görünür.
+pub struct VertexColors {
+    vbo: GLuint,
+    count: usize,
+    colors: Vec<Color>,
+}
+
+impl VertexColors {
+    // Henüz OpenGL tamponu olmayan, beyaz köşe renkleri oluşturur
+    pub fn new() -> VertexColors {
+        VertexColors { vbo: 0, count: 0, colors: Vec::new() }
+    }
+
+    // Tamponu oluşturur ve bağlı VAO'ya `location` konumunda vec4 özniteliği olarak bağlar
+    pub fn attach(&mut self, location: GLuint, count: usize) {
+        self.count = count;
+        unsafe {
+            gl::GenBuffers(1, &mut self.vbo);
+            gl::BindBuffer(gl::ARRAY_BUFFER, self.vbo);
+            gl::BufferData(
+                gl::ARRAY_BUFFER,
+                (count * 4 * mem::size_of::<f32>()) as gl::types::GLsizeiptr,
+                self.data().as_ptr() as *const c_void,
+                gl::STATIC_DRAW,
+            );
+            gl::VertexAttribPointer(location, 4, gl::FLOAT, gl::FALSE, 4 * mem::size_of::<f32>() as gl::types::GLsizei, std::ptr::null());
+            gl::EnableVertexAttribArray(location);
+        }
+    }
+
+    // Her köşe için bir renk verir; tampon oluşturulmuşsa renkleri hemen yükler
+    pub fn set(&mut self, colors: Vec<Color>) {
+        self.colors = colors;
+        if self.vbo == 0 {
+            return;
+        }
+        unsafe {
+            gl::BindBuffer(gl::ARRAY_BUFFER, self.vbo);
+            gl::BufferSubData(
+                gl::ARRAY_BUFFER,
+                0,
+                (self.count * 4 * mem::size_of::<f32>()) as gl::types::GLsizeiptr,
+                self.data().as_ptr() as *const c_void,
+            );
+            gl::BindBuffer(gl::ARRAY_BUFFER, 0);
+        }
+    }
+
+    // Köşelerden biri yarı saydamsa true döndürür
+    pub fn translucent(&self) -> bool {
+        self.colors.iter().any(|c| c.a < 1.0)
+    }
+
+    // Renkleri köşe sayısına tamamlanmış düz bir dizi olarak döndürür
+    fn data(&self) -> Vec<f32> {
+        (0..self.count).flat_map(|i| self.colors.get(i).copied().unwrap_or(Color::WHITE).to_array()).collect()
+    }
+}
+
+impl Drop for VertexColors {
+    // Köşe renkleri silindiğinde OpenGL tamponunu temizler
+    fn drop(&mut self) {
+        if self.vbo != 0 {
+            unsafe {
+                gl::DeleteBuffers(1, &self.vbo);
+            }
+        }
+    }
+}
+
+// Yarı saydam çizimler için alfa karıştırmayı açar, çizer ve önceki durumu geri yükler
+pub fn draw_blended(translucent: bool, draw: impl FnOnce()) {
+    if !translucent {
+        draw();
+        return;
+    }
+
+    let mut blend = 0;
+    unsafe {
+        gl::GetIntegerv(gl::BLEND, &mut blend);
+        gl::Enable(gl::BLEND);
+        gl::BlendFunc(gl::SRC_ALPHA, gl::ONE_MINUS_SRC_ALPHA);
+    }
+    draw();
+    if blend == 0 {
+        unsafe {
+            gl::Disable(gl::BLEND);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: Color, b: Color) {
+        let close = a.to_array().iter().zip(b.to_array()).all(|(x, y)| (x - y).abs() < 1e-4);
+        assert!(close, "{:?} != {:?}", a, b);
+    }
+
+    #[test]
+    fn hex_colors_round_trip() {
+        assert_eq!(Color::from_hex("#ff8800").unwrap(), Color::rgb(1.0, 136.0 / 255.0, 0.0));
+        assert_eq!(Color::from_hex("f80").unwrap(), Color::from_hex("#ff8800").unwrap());
+        assert_eq!(Color::from_hex("#ff880080").unwrap().a, 128.0 / 255.0);
+        assert_eq!(Color::from_hex("#0008").unwrap().to_hex(), "#00000088");
+        assert_eq!(Color::from_hex("#1A2b3C").unwrap().to_hex(), "#1a2b3c");
+
+        for bad in ["", "#12", "#12345", "#ggg", "#ééé"] {
+            assert!(Color::from_hex(bad).is_err(), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn hsv_round_trip() {
+        assert_close(Color::from_hsv(0.0, 1.0, 1.0), Color::rgb(1.0, 0.0, 0.0));
+        assert_close(Color::from_hsv(120.0, 1.0, 1.0), Color::rgb(0.0, 1.0, 0.0));
+        assert_close(Color::from_hsv(-120.0, 1.0, 0.5), Color::rgb(0.0, 0.0, 0.5));
+        assert_eq!(Color::rgb(0.4, 0.4, 0.4).to_hsv(), [0.0, 0.0, 0.4]);
+
+        for hue in (0..360).step_by(15) {
+            let color = Color::from_hsv(hue as f32, 0.75, 0.6);
+            let [h, s, v] = color.to_hsv();
+            assert!((h - hue as f32).abs() < 1e-3 && (s - 0.75).abs() < 1e-4 && (v - 0.6).abs() < 1e-4, "{}", hue);
+        }
+    }
+
+    #[test]
+    fn linear_conversion_round_trips() {
+        assert_eq!(srgb_to_linear(0.0), 0.0);
+        assert!((srgb_to_linear(1.0) - 1.0).abs() < 1e-6);
+        assert!((srgb_to_linear(0.5) - 0.214).abs() < 1e-3);
+
+        let color = Color::new(0.02, 0.5, 0.9, 0.3);
+        let linear = color.to_linear();
+        assert_eq!(linear[3], 0.3);
+        assert_close(Color::from_linear(linear), color);
+    }
+
+    #[test]
+    fn colors_deserialize_from_hex_or_components() {
+        let colors: Vec<Color> = ron::from_str(r##"["#f00", (0.0, 1.0, 0.0), (0.0, 0.0, 1.0, 0.5)]"##).unwrap();
+        assert_eq!(colors, vec![Color::rgb(1.0, 0.0, 0.0), Color::rgb(0.0, 1.0, 0.0), Color::new(0.0, 0.0, 1.0, 0.5)]);
+
+        let colors: Vec<Color> = serde_json::from_str(r##"["#00ff0080", [1, 1, 1]]"##).unwrap();
+        assert_eq!(colors[1], Color::WHITE);
+        assert!(serde_json::from_str::<Color>("[1, 1]").is_err());
+        assert!(serde_json::from_str::<Color>(r#""orange""#).is_err());
+    }
+}
@@ -1,5 +1,9 @@
+use crate::color::{self, Color, VertexColors};
 use crate::shader::Shader;
-use crate::draw::Shape;
+use crate::draw::{RenderContext, Shape};
+use crate::material::Material;
+use crate::matrix4::Matrix4;
+use crate::mesh::MeshData;
 use crate::vector3d::Vector3D;
 use std::rc::Rc;
 use std::ffi::c_void;
@@ -9,19 +13,25 @@ pub struct Cube {
     vao: gl::types::GLuint,
     vbo: gl::types::GLuint,
     shader: Rc<Shader>,
-    pub rotation: f32,
+    pub material: Material,
+    pub color: Color,  // Tints the material's diffuse color, alpha makes the cube translucent
+    colors: VertexColors,
     bottom_left_back: Vector3D,
     top_right_front: Vector3D,
 }
 
 impl Cube {
+    pub const CORNER_COLORS: usize = 8;
+
     // Yeni bir küp oluşturur
     pub fn new(shader: Rc<Shader>, bottom_left_back: Vector3D, top_right_front: Vector3D) -> Self {
         let mut cube = Cube {
             vao: 0,
             vbo: 0,
             shader,
-            rotation: 0.0,
+            material: Material::new(Vector3D::new(0.5, 0.5, 1.0), Vector3D::new(0.5, 0.5, 0.5), 32.0),
+            color: Color::WHITE,
+            colors: VertexColors::new(),
             bottom_left_back,
             top_right_front,
         };
@@ -29,11 +39,21 @@ impl Cube {
         cube
     }
 
-    // Küp köşe noktalarını oluşturur
-    fn generate_vertices(&self) -> [f32; 108] {
-        let blb = self.bottom_left_back;
+    // Küpün 8 köşesine birer renk verir. Köşe indeksinin 0. biti x, 1. biti y, 2. biti z ekseninde büyük
+    // köşeyi seçer: 0 sol alt arka (min), 7 sağ üst ön (max) köşedir. Renkler yüzeyler boyunca karışır.
+    pub fn set_vertex_colors(&mut self, colors: &[Color]) -> Result<(), String> {
+        if colors.len() != Cube::CORNER_COLORS {
+            return Err(format!("a cube takes {} corner colors, got {}", Cube::CORNER_COLORS, colors.len()));
+        }
         let trf = self.top_right_front;
+        let corner = |p: &[f32]| (p[0] == trf.x) as usize + 2 * (p[1] == trf.y) as usize + 4 * (p[2] == trf.z) as usize;
+        let corners = Cube::generate_vertices(self.bottom_left_back, trf).chunks_exact(3).map(|p| colors[corner(p)]).collect();
+        self.colors.set(corners);
+        Ok(())
+    }
 
+    // Küp köşe noktalarını oluşturur
+    fn generate_vertices(blb: Vector3D, trf: Vector3D) -> [f32; 108] {
         [
             blb.x, blb.y, trf.z, trf.x, blb.y, trf.z, trf.x, trf.y, trf.z,
             trf.x, trf.y, trf.z, blb.x, trf.y, trf.z, blb.x, blb.y, trf.z,
@@ -49,12 +69,53 @@ impl Cube {
             trf.x, blb.y, trf.z, blb.x, blb.y, trf.z, blb.x, blb.y, blb.z
         ]
     }
+
+    // Köşeleri verilen kutunun 12 üçgenini yüz normalleriyle döndürür (ör. örneklenmiş çizim için)
+    pub fn mesh(bottom_left_back: Vector3D, top_right_front: Vector3D) -> MeshData {
+        let positions = Cube::generate_vertices(bottom_left_back, top_right_front);
+        let normals = Cube::generate_normals();
+        MeshData {
+            positions: positions.chunks_exact(3).map(|p| [p[0], p[1], p[2]]).collect(),
+            normals: normals.chunks_exact(3).map(|n| [n[0], n[1], n[2]]).collect(),
+            uvs: Vec::new(),
+            indices: (0..36).collect(),
+        }
+    }
+
+    // Her yüzün normalini köşe sırasına uygun olarak oluşturur
+    fn generate_normals() -> [f32; 108] {
+        let face_normals: [[f32; 3]; 6] = [
+            [0.0, 0.0, 1.0],
+            [0.0, 0.0, -1.0],
+            [-1.0, 0.0, 0.0],
+            [1.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0],
+            [0.0, -1.0, 0.0],
+        ];
+
+        let mut normals = [0.0; 108];
+        for (face, normal) in face_normals.iter().enumerate() {
+            for vertex in 0..6 {
+                let offset = (face * 6 + vertex) * 3;
+                normals[offset..offset + 3].copy_from_slice(normal);
+            }
+        }
+        normals
+    }
+
 }
 
 impl Shape for Cube {
     // Küpü başlatır
     fn init(&mut self) {
-        let vertices = self.generate_vertices();
+        let positions = Cube::generate_vertices(self.bottom_left_back, self.top_right_front);
+        let normals = Cube::generate_normals();
+
+        let mut vertices = Vec::with_capacity(216);
+        for i in 0..36 {
+            vertices.extend_from_slice(&positions[i * 3..(i + 1) * 3]);
+            vertices.extend_from_slice(&normals[i * 3..(i + 1) * 3]);
+        }
 
         unsafe {
             gl::GenVertexArrays(1, &mut self.vao);
@@ -75,43 +136,64 @@ impl Shape for Cube {
                 3,
                 gl::FLOAT,
                 gl::FALSE,
-                3 * mem::size_of::<f32>() as gl::types::GLsizei,
+                6 * mem::size_of::<f32>() as gl::types::GLsizei,
                 std::ptr::null(),
             );
             gl::EnableVertexAttribArray(0);
 
+            gl::VertexAttribPointer(
+                1,
+                3,
+                gl::FLOAT,
+                gl::FALSE,
+                6 * mem::size_of::<f32>() as gl::types::GLsizei,
+                (3 * mem::size_of::<f32>()) as *const c_void,
+            );
+            gl::EnableVertexAttribArray(1);
+
+            self.colors.attach(3, 36);
+
             gl::BindBuffer(gl::ARRAY_BUFFER, 0);
             gl::BindVertexArray(0);
         }
     }
 
     // Küpü çizer
-    fn draw(&self) {
-        unsafe {
-            self.shader.use_program();
-            
-            let cos_x = f32::cos(self.rotation * 0.3);
-            let sin_x = f32::sin(self.rotation * 0.3);
-            let cos_y = f32::cos(self.rotation * 0.3);
-            let sin_y = f32::sin(self.rotation * 0.3);
-            let cos_z = f32::cos(self.rotation * 0.3);
-            let sin_z = f32::sin(self.rotation * 0.3);
-
-            let rotation_matrix = [
-                cos_y * cos_z, -cos_x * sin_z + sin_x * sin_y * cos_z, sin_x * sin_z + cos_x * sin_y * cos_z, 0.0,
-                cos_y * sin_z, cos_x * cos_z + sin_x * sin_y * sin_z, -sin_x * cos_z + cos_x * sin_y * sin_z, 0.0,
-                -sin_y, sin_x * cos_y, cos_x * cos_y, 0.0,
-                0.0, 0.0, 0.0, 1.0
-            ];
-
-            let rotation_loc = gl::GetUniformLocation(self.shader.id(), b"rotation\0".as_ptr() as *const i8);
-            gl::UniformMatrix4fv(rotation_loc, 1, gl::FALSE, rotation_matrix.as_ptr());
+    fn draw(&self, ctx: &RenderContext, model: &Matrix4) {
+        self.shader.use_program();
+        ctx.apply(&self.shader);
+        self.shader.set_mat4("model", model);
+        self.material.apply(&self.shader, ctx);
+        self.shader.set_vec4("tint", ctx.color(self.color));
+        self.shader.set_bool("vertexColors", true);
 
+        color::draw_blended(self.color.a < 1.0 || self.colors.translucent(), || unsafe {
+            gl::BindVertexArray(self.vao);
+            gl::DrawArrays(gl::TRIANGLES, 0, 36);
+            gl::BindVertexArray(0);
+        });
+    }
+
+    // Küpü gölge haritasına çizer
+    fn draw_depth(&self, depth_shader: &Shader, model: &Matrix4) {
+        depth_shader.set_mat4("model", model);
+
+        unsafe {
             gl::BindVertexArray(self.vao);
             gl::DrawArrays(gl::TRIANGLES, 0, 36);
             gl::BindVertexArray(0);
         }
     }
+
+    // Küpün 12 üçgenini yüz normalleriyle döndürür
+    fn mesh_data(&self) -> Option<MeshData> {
+        Some(Cube::mesh(self.bottom_left_back, self.top_right_front))
+    }
+
+    // Şeklin malzemesini döndürür
+    fn material_mut(&mut self) -> Option<&mut Material> {
+        Some(&mut self.material)
+    }
 }
 
 impl Drop for Cube {
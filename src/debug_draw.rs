@@ -0,0 +1,390 @@
+use std::ffi::c_void;
+use std::mem;
+use std::sync::Mutex;
+
+use crate::matrix4::Matrix4;
+use crate::shader::Shader;
+use crate::vector2d::Vector2D;
+use crate::vector3d::Vector3D;
+
+// Bir köşenin kayan nokta sayısı: konum (3), renk (4)
+const VERTEX_FLOATS: usize = 7;
+
+// Kürelerin her çemberindeki çizgi sayısı
+const SPHERE_SEGMENTS: usize = 24;
+
+pub const RED: [f32; 4] = [1.0, 0.2, 0.2, 1.0];
+pub const GREEN: [f32; 4] = [0.2, 1.0, 0.2, 1.0];
+pub const BLUE: [f32; 4] = [0.3, 0.4, 1.0, 1.0];
+pub const YELLOW: [f32; 4] = [1.0, 0.9, 0.2, 1.0];
+
+// Ekranda kalma süresi dolana kadar çizilen bir çizgi
+#[derive(Debug, Clone, Copy)]
+struct DebugLine {
+    from: Vector3D,
+    to: Vector3D,
+    color: [f32; 4],
+    remaining: f32,
+}
+
+// Dünya uzayındaki bir noktaya yazılan hata ayıklama yazısı
+#[derive(Debug, Clone)]
+pub struct DebugText {
+    pub position: Vector3D,
+    pub text: String,
+    pub color: [f32; 4],
+    remaining: f32,
+}
+
+// Kare boyunca biriktirilen hata ayıklama çizimleri. Süre saniye cinsindendir;
+// sıfır süreli çizimler yalnızca bir sonraki karede görünür.
+#[derive(Debug, Default)]
+pub struct DebugDraw {
+    lines: Vec<DebugLine>,
+    texts: Vec<DebugText>,
+}
+
+impl DebugDraw {
+    // Boş bir çizim listesi oluşturur
+    pub const fn new() -> DebugDraw {
+        DebugDraw { lines: Vec::new(), texts: Vec::new() }
+    }
+
+    // İki nokta arasına çizgi ekler
+    pub fn line(&mut self, from: Vector3D, to: Vector3D, color: [f32; 4], duration: f32) {
+        self.lines.push(DebugLine { from, to, color, remaining: duration });
+    }
+
+    // Ucunda ok başı olan bir çizgi ekler; ok başı çizgi boyunun beşte biridir
+    pub fn arrow(&mut self, from: Vector3D, to: Vector3D, color: [f32; 4], duration: f32) {
+        self.line(from, to, color, duration);
+        let shaft = to - from;
+        let length = shaft.magnitude();
+        if length == 0.0 {
+            return;
+        }
+
+        let direction = shaft / length;
+        // Any axis that is not parallel to the shaft gives two perpendicular directions for the head
+        let helper = if direction.y.abs() < 0.9 { Vector3D::new(0.0, 1.0, 0.0) } else { Vector3D::new(1.0, 0.0, 0.0) };
+        let side = direction.cross(&helper).normalize();
+        let up = side.cross(&direction);
+        let head = length * 0.2;
+        let base = to - direction * head;
+        for offset in [side, side * -1.0, up, up * -1.0] {
+            self.line(to, base + offset * (head * 0.5), color, duration);
+        }
+    }
+
+    // Eksenlere hizalı bir kutunun 12 kenarını ekler
+    pub fn aabb(&mut self, min: Vector3D, max: Vector3D, color: [f32; 4], duration: f32) {
+        let corner = |i: usize| {
+            Vector3D::new(
+                if i & 1 == 0 { min.x } else { max.x },
+                if i & 2 == 0 { min.y } else { max.y },
+                if i & 4 == 0 { min.z } else { max.z },
+            )
+        };
+        self.box_edges(corner, color, duration);
+    }
+
+    // Köşeleri bit indeksiyle (bit 0: x, bit 1: y, bit 2: z) verilen bir kutunun kenarlarını ekler
+    fn box_edges(&mut self, corner: impl Fn(usize) -> Vector3D, color: [f32; 4], duration: f32) {
+        for i in 0..8 {
+            for bit in [1, 2, 4] {
+                // Each edge joins two corners that differ in one bit; visit it from the lower corner only
+                if i & bit == 0 {
+                    self.line(corner(i), corner(i | bit), color, duration);
+                }
+            }
+        }
+    }
+
+    // Kürenin üç eksendeki büyük çemberlerini ekler
+    pub fn sphere(&mut self, center: Vector3D, radius: f32, color: [f32; 4], duration: f32) {
+        let point = |axis: usize, angle: f32| {
+            let (sin, cos) = angle.sin_cos();
+            let (a, b) = (radius * cos, radius * sin);
+            center
+                + match axis {
+                    0 => Vector3D::new(0.0, a, b),
+                    1 => Vector3D::new(a, 0.0, b),
+                    _ => Vector3D::new(a, b, 0.0),
+                }
+        };
+        for axis in 0..3 {
+            for i in 0..SPHERE_SEGMENTS {
+                let step = std::f32::consts::TAU / SPHERE_SEGMENTS as f32;
+                self.line(point(axis, i as f32 * step), point(axis, (i + 1) as f32 * step), color, duration);
+            }
+        }
+    }
+
+    // Dönüşümün konumuna x (kırmızı), y (yeşil) ve z (mavi) eksenlerini verilen uzunlukta ekler
+    pub fn axis_gizmo(&mut self, transform: &Matrix4, size: f32, duration: f32) {
+        let origin = transform.transform_point(Vector3D::zero());
+        for (axis, color) in [(Vector3D::new(1.0, 0.0, 0.0), RED), (Vector3D::new(0.0, 1.0, 0.0), GREEN), (Vector3D::new(0.0, 0.0, 1.0), BLUE)] {
+            let direction = transform.transform_vector(axis).normalize();
+            self.arrow(origin, origin + direction * size, color, duration);
+        }
+    }
+
+    // Merkezi verilen, XZ düzleminde, kenarı size olan ve divisions hücreye bölünmüş bir ızgara ekler
+    pub fn grid(&mut self, center: Vector3D, size: f32, divisions: u32, color: [f32; 4], duration: f32) {
+        let divisions = divisions.max(1);
+        let half = size / 2.0;
+        for i in 0..=divisions {
+            let offset = -half + size * i as f32 / divisions as f32;
+            self.line(center + Vector3D::new(offset, 0.0, -half), center + Vector3D::new(offset, 0.0, half), color, duration);
+            self.line(center + Vector3D::new(-half, 0.0, offset), center + Vector3D::new(half, 0.0, offset), color, duration);
+        }
+    }
+
+    // Görüş-izdüşüm matrisinin görüş hacmini (ör. başka bir kameranın veya bir ışığın) ekler
+    pub fn frustum(&mut self, view_projection: &Matrix4, color: [f32; 4], duration: f32) {
+        let inverse = view_projection.inverse().unwrap_or_else(Matrix4::identity);
+        let corner = |i: usize| {
+            let ndc = |bit: usize| if i & bit == 0 { -1.0 } else { 1.0 };
+            inverse.transform_point(Vector3D::new(ndc(1), ndc(2), ndc(4)))
+        };
+        self.box_edges(corner, color, duration);
+    }
+
+    // Dünya uzayındaki bir noktaya yazı ekler
+    pub fn text(&mut self, position: Vector3D, text: &str, color: [f32; 4], duration: f32) {
+        self.texts.push(DebugText { position, text: text.to_string(), color, remaining: duration });
+    }
+
+    // Çizgi sayısını döndürür
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    // Yazıları döndürür
+    pub fn texts(&self) -> &[DebugText] {
+        &self.texts
+    }
+
+    // Çizgileri GPU'ya yüklenecek biçimde (konum, renk) art arda dizer
+    pub fn vertices(&self) -> Vec<f32> {
+        let mut vertices = Vec::with_capacity(self.lines.len() * 2 * VERTEX_FLOATS);
+        for line in &self.lines {
+            for point in [line.from, line.to] {
+                vertices.extend_from_slice(&[point.x, point.y, point.z]);
+                vertices.extend_from_slice(&line.color);
+            }
+        }
+        vertices
+    }
+
+    // Karenin süresini düşer ve süresi dolan çizimleri siler; kare çizildikten sonra çağrılır
+    pub fn end_frame(&mut self, dt: f32) {
+        self.lines.iter_mut().for_each(|line| line.remaining -= dt);
+        self.lines.retain(|line| line.remaining > 0.0);
+        self.texts.iter_mut().for_each(|text| text.remaining -= dt);
+        self.texts.retain(|text| text.remaining > 0.0);
+    }
+
+    // Tüm çizimleri siler
+    pub fn clear(&mut self) {
+        self.lines.clear();
+        self.texts.clear();
+    }
+}
+
+// Program genelindeki çizim listesi; çizimler karenin herhangi bir yerinden eklenebilir
+static DEBUG_DRAW: Mutex<DebugDraw> = Mutex::new(DebugDraw::new());
+
+// Program genelindeki çizim listesiyle bir işlem yapar
+pub fn with<T>(f: impl FnOnce(&mut DebugDraw) -> T) -> T {
+    f(&mut DEBUG_DRAW.lock().unwrap())
+}
+
+// Program genelindeki listeye çizgi ekler
+pub fn line(from: Vector3D, to: Vector3D, color: [f32; 4], duration: f32) {
+    with(|debug| debug.line(from, to, color, duration));
+}
+
+// Program genelindeki listeye ok ekler
+pub fn arrow(from: Vector3D, to: Vector3D, color: [f32; 4], duration: f32) {
+    with(|debug| debug.arrow(from, to, color, duration));
+}
+
+// Program genelindeki listeye kutu ekler
+pub fn aabb(min: Vector3D, max: Vector3D, color: [f32; 4], duration: f32) {
+    with(|debug| debug.aabb(min, max, color, duration));
+}
+
+// Program genelindeki listeye küre ekler
+pub fn sphere(center: Vector3D, radius: f32, color: [f32; 4], duration: f32) {
+    with(|debug| debug.sphere(center, radius, color, duration));
+}
+
+// Program genelindeki listeye eksen göstergesi ekler
+pub fn axis_gizmo(transform: &Matrix4, size: f32, duration: f32) {
+    with(|debug| debug.axis_gizmo(transform, size, duration));
+}
+
+// Program genelindeki listeye ızgara ekler
+pub fn grid(center: Vector3D, size: f32, divisions: u32, color: [f32; 4], duration: f32) {
+    with(|debug| debug.grid(center, size, divisions, color, duration));
+}
+
+// Program genelindeki listeye görüş hacmi ekler
+pub fn frustum(view_projection: &Matrix4, color: [f32; 4], duration: f32) {
+    with(|debug| debug.frustum(view_projection, color, duration));
+}
+
+// Program genelindeki listeye yazı ekler
+pub fn text(position: Vector3D, text: &str, color: [f32; 4], duration: f32) {
+    with(|debug| debug.text(position, text, color, duration));
+}
+
+// Dünya uzayındaki bir noktanın piksel cinsinden ekran konumunu (sol üst köşe 0, 0) döndürür.
+// Nokta kameranın arkasındaysa None döner.
+pub fn screen_position(view_projection: &Matrix4, point: Vector3D, width: u32, height: u32) -> Option<Vector2D> {
+    let m = view_projection;
+    let w = m.get(0, 3) * point.x + m.get(1, 3) * point.y + m.get(2, 3) * point.z + m.get(3, 3);
+    if w <= 0.0 {
+        return None;
+    }
+    let ndc = m.transform_point(point);
+    Some(Vector2D::new((ndc.x + 1.0) / 2.0 * width as f32, (1.0 - ndc.y) / 2.0 * height as f32))
+}
+
+// Çizgi listesini tek dinamik tamponla, sahnenin üzerine çizen çizici
+pub struct DebugRenderer {
+    shader: Shader,
+    vao: gl::types::GLuint,
+    vbo: gl::types::GLuint,
+}
+
+impl DebugRenderer {
+    // Çizgi shader'ını yükler ve köşe tamponunu hazırlar
+    pub fn new() -> DebugRenderer {
+        let mut renderer = DebugRenderer {
+            shader: Shader::load("debug_vertex_shader.glsl", "debug_fragment_shader.glsl"),
+            vao: 0,
+            vbo: 0,
+        };
+
+        let stride = (VERTEX_FLOATS * mem::size_of::<f32>()) as gl::types::GLsizei;
+        unsafe {
+            gl::GenVertexArrays(1, &mut renderer.vao);
+            gl::GenBuffers(1, &mut renderer.vbo);
+
+            gl::BindVertexArray(renderer.vao);
+            gl::BindBuffer(gl::ARRAY_BUFFER, renderer.vbo);
+
+            gl::VertexAttribPointer(0, 3, gl::FLOAT, gl::FALSE, stride, std::ptr::null());
+            gl::EnableVertexAttribArray(0);
+            gl::VertexAttribPointer(1, 4, gl::FLOAT, gl::FALSE, stride, (3 * mem::size_of::<f32>()) as *const c_void);
+            gl::EnableVertexAttribArray(1);
+
+            gl::BindVertexArray(0);
+            gl::BindBuffer(gl::ARRAY_BUFFER, 0);
+        }
+        renderer
+    }
+
+    // Çizgileri derinlik testi olmadan, bağlı çizim hedefine çizer
+    pub fn render(&self, debug: &DebugDraw, view_projection: &Matrix4) {
+        let vertices = debug.vertices();
+        if vertices.is_empty() {
+            return;
+        }
+
+        let mut depth_test = 0;
+        unsafe {
+            gl::GetIntegerv(gl::DEPTH_TEST, &mut depth_test);
+            gl::Disable(gl::DEPTH_TEST);
+
+            self.shader.use_program();
+            self.shader.set_mat4("viewProjection", view_projection);
+
+            gl::BindVertexArray(self.vao);
+            gl::BindBuffer(gl::ARRAY_BUFFER, self.vbo);
+            gl::BufferData(
+                gl::ARRAY_BUFFER,
+                (vertices.len() * mem::size_of::<f32>()) as gl::types::GLsizeiptr,
+                vertices.as_ptr() as *const c_void,
+                gl::STREAM_DRAW,
+            );
+            gl::DrawArrays(gl::LINES, 0, (vertices.len() / VERTEX_FLOATS) as i32);
+            gl::BindVertexArray(0);
+            gl::BindBuffer(gl::ARRAY_BUFFER, 0);
+
+            if depth_test != 0 {
+                gl::Enable(gl::DEPTH_TEST);
+            }
+        }
+    }
+}
+
+impl Drop for DebugRenderer {
+    // Çizici silindiğinde OpenGL kaynaklarını temizler
+    fn drop(&mut self) {
+        unsafe {
+            gl::DeleteVertexArrays(1, &self.vao);
+            gl::DeleteBuffers(1, &self.vbo);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WHITE: [f32; 4] = [1.0; 4];
+
+    #[test]
+    fn shapes_expand_to_lines() {
+        let mut debug = DebugDraw::new();
+        debug.aabb(Vector3D::zero(), Vector3D::new(1.0, 1.0, 1.0), WHITE, 0.0);
+        assert_eq!(debug.line_count(), 12);
+
+        debug.clear();
+        debug.arrow(Vector3D::zero(), Vector3D::new(0.0, 0.0, 5.0), WHITE, 0.0);
+        assert_eq!(debug.line_count(), 5);
+
+        debug.clear();
+        debug.axis_gizmo(&Matrix4::identity(), 1.0, 0.0);
+        debug.grid(Vector3D::zero(), 10.0, 4, WHITE, 0.0);
+        debug.sphere(Vector3D::zero(), 1.0, WHITE, 0.0);
+        assert_eq!(debug.line_count(), 3 * 5 + 2 * 5 + 3 * SPHERE_SEGMENTS);
+        assert_eq!(debug.vertices().len(), debug.line_count() * 2 * VERTEX_FLOATS);
+    }
+
+    #[test]
+    fn identity_frustum_is_the_unit_cube() {
+        let mut debug = DebugDraw::new();
+        debug.frustum(&Matrix4::identity(), WHITE, 0.0);
+        let vertices = debug.vertices();
+        assert_eq!(debug.line_count(), 12);
+        assert!(vertices.chunks_exact(VERTEX_FLOATS).all(|v| v[..3].iter().all(|c| c.abs() == 1.0)));
+    }
+
+    #[test]
+    fn lines_expire_after_their_duration() {
+        let mut debug = DebugDraw::new();
+        debug.line(Vector3D::zero(), Vector3D::new(1.0, 0.0, 0.0), WHITE, 0.0);
+        debug.line(Vector3D::zero(), Vector3D::new(0.0, 1.0, 0.0), WHITE, 0.05);
+        debug.text(Vector3D::zero(), "origin", WHITE, 1.0);
+
+        debug.end_frame(1.0 / 60.0);
+        assert_eq!(debug.line_count(), 1);
+        debug.end_frame(0.04);
+        assert_eq!(debug.line_count(), 0);
+        assert_eq!(debug.texts().len(), 1);
+    }
+
+    #[test]
+    fn points_project_to_pixels() {
+        let center = screen_position(&Matrix4::identity(), Vector3D::new(0.0, 0.5, 0.0), 800, 600).unwrap();
+        assert_eq!((center.x, center.y), (400.0, 150.0));
+
+        let projection = Matrix4::perspective(1.0, 1.0, 0.1, 10.0);
+        assert!(screen_position(&projection, Vector3D::new(0.0, 0.0, 1.0), 800, 600).is_none());
+        assert!(screen_position(&projection, Vector3D::new(0.0, 0.0, -1.0), 800, 600).is_some());
+    }
+}
@@ -1,9 +1,100 @@
 extern crate gl;
 
+use crate::color::{srgb_to_linear, Color};
+use crate::light::{Lighting, MAX_SPOT_LIGHTS};
+use crate::material::Material;
+use crate::matrix4::Matrix4;
+use crate::mesh::MeshData;
+use crate::shader::Shader;
+use crate::shadow::ShadowRenderer;
+use crate::vector3d::Vector3D;
+
+// Bir karenin çizimi sırasında şekillere aktarılan kamera ve ışık bilgisi
+pub struct RenderContext<'a> {
+    pub view: Matrix4,
+    pub projection: Matrix4,
+    pub projection_2d: Matrix4,
+    pub camera_position: Vector3D,
+    pub lighting: Option<&'a Lighting>,
+    pub shadows: Option<&'a ShadowRenderer>,
+    pub linear_colors: bool,  // sRGB colors are converted to linear because a post effect encodes the output
+}
+
+// 2B şekillerin izdüşümü: y ekseni -1..1, x ekseni en-boy oranına göre genişler, böylece daireler ekranda yuvarlak kalır
+pub fn projection_2d(aspect: f32) -> Matrix4 {
+    Matrix4::orthographic(-aspect, aspect, -1.0, 1.0, -1.0, 1.0)
+}
+
+impl<'a> RenderContext<'a> {
+    // 2B izdüşümü ve şeklin model matrisini ışıklandırılmayan bir shader'a yükler
+    pub fn apply_2d(&self, shader: &Shader, model: &Matrix4) {
+        shader.set_mat4("projection", &self.projection_2d);
+        shader.set_mat4("model", model);
+        shader.set_bool("linearColors", self.linear_colors);
+    }
+
+    // Şeklin sRGB rengini shader'a yüklenecek uzaya (gerekiyorsa doğrusal) çevirir
+    pub fn color(&self, color: Color) -> [f32; 4] {
+        if self.linear_colors {
+            color.to_linear()
+        } else {
+            color.to_array()
+        }
+    }
+
+    // Malzeme ve ışık renkleri gibi sRGB RGB değerlerini de aynı şekilde çevirir
+    pub fn rgb(&self, color: Vector3D) -> Vector3D {
+        if self.linear_colors {
+            Vector3D::new(srgb_to_linear(color.x), srgb_to_linear(color.y), srgb_to_linear(color.z))
+        } else {
+            color
+        }
+    }
+
+    // Kamera, ışık ve gölge uniform'larını ışıklandırılan bir shader'a yükler
+    pub fn apply(&self, shader: &Shader) {
+        shader.set_mat4("view", &self.view);
+        shader.set_mat4("projection", &self.projection);
+        shader.set_vec3("viewPos", self.camera_position);
+        shader.set_bool("linearColors", self.linear_colors);
+
+        match self.lighting {
+            Some(lighting) => lighting.apply(shader, self),
+            None => {
+                shader.set_bool("dirLight.enabled", false);
+                shader.set_int("spotLightCount", 0);
+            }
+        }
+
+        match self.shadows {
+            Some(shadows) => shadows.apply(shader),
+            None => {
+                shader.set_bool("dirShadow.enabled", false);
+                for i in 0..MAX_SPOT_LIGHTS {
+                    shader.set_bool(&format!("spotShadows[{}].enabled", i), false);
+                }
+            }
+        }
+    }
+}
+
 pub trait Shape {
     // Şekli başlatır
     fn init(&mut self);
 
-    // Şekli çizer
-    fn draw(&self);
-}
\ No newline at end of file
+    // Şekli verilen model (dünya) matrisiyle çizer
+    fn draw(&self, ctx: &RenderContext, model: &Matrix4);
+
+    // Şekli gölge haritası için yalnızca derinlik olarak çizer
+    fn draw_depth(&self, _depth_shader: &Shader, _model: &Matrix4) {}
+
+    // Şeklin yerel koordinatlardaki üçgenlerini döndürür (dışa aktarma için); geometrisi olmayan şekiller None döndürür
+    fn mesh_data(&self) -> Option<MeshData> {
+        None
+    }
+
+    // Şeklin malzemesini (varsa) düzenlemek için döndürür
+    fn material_mut(&mut self) -> Option<&mut Material> {
+        None
+    }
+}
@@ -0,0 +1,275 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use crate::mesh::MeshData;
+use crate::scene::Scene;
+use crate::vector3d::Vector3D;
+
+// Desteklenen dışa aktarma biçimleri
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Obj,
+    Ply,
+    StlAscii,
+    StlBinary,
+}
+
+impl ExportFormat {
+    // Biçimi adından seçer: obj, ply, stl (ikili) veya stl-ascii
+    pub fn parse(name: &str) -> Result<ExportFormat, String> {
+        match name {
+            "obj" => Ok(ExportFormat::Obj),
+            "ply" => Ok(ExportFormat::Ply),
+            "stl" => Ok(ExportFormat::StlBinary),
+            "stl-ascii" => Ok(ExportFormat::StlAscii),
+            _ => Err(format!("unknown export format '{}', expected obj, ply, stl or stl-ascii", name)),
+        }
+    }
+
+    // Biçimi dosya uzantısından seçer; .stl dosyaları ikili (binary) yazılır
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<ExportFormat, String> {
+        let path = path.as_ref();
+        match path.extension().and_then(|ext| ext.to_str()).map(str::to_ascii_lowercase).as_deref() {
+            Some("obj") => Ok(ExportFormat::Obj),
+            Some("ply") => Ok(ExportFormat::Ply),
+            Some("stl") => Ok(ExportFormat::StlBinary),
+            _ => Err(format!("{}: unknown export format, expected .obj, .ply or .stl", path.display())),
+        }
+    }
+}
+
+// Sahnedeki görünür şekillerin geometrisini dünya koordinatlarında, düğüm adlarıyla döndürür.
+// Dünya matrislerinin güncel olması için önce Scene::update_world çağrılmalıdır.
+pub fn scene_objects(scene: &Scene) -> Vec<(String, MeshData)> {
+    let mut objects = Vec::new();
+    scene.visit(|_, node| {
+        if let Some(data) = node.renderable.as_ref().and_then(|shape| shape.mesh_data()) {
+            objects.push((node.name.clone(), data.transformed(node.world_matrix())));
+        }
+    });
+    objects
+}
+
+// Nesneleri tek mesh'te birleştirir
+fn merge(objects: &[(String, MeshData)]) -> MeshData {
+    let mut merged = MeshData::default();
+    for (_, data) in objects {
+        merged.append(data);
+    }
+    merged
+}
+
+// Nesneleri verilen biçimde yazar. PLY ve STL tek mesh tuttuğu için nesneler birleştirilir.
+pub fn write<W: Write>(out: &mut W, format: ExportFormat, objects: &[(String, MeshData)]) -> io::Result<()> {
+    match format {
+        ExportFormat::Obj => write_obj(out, objects),
+        ExportFormat::Ply => write_ply(out, &merge(objects)),
+        ExportFormat::StlAscii => write_stl_ascii(out, "scene", &merge(objects)),
+        ExportFormat::StlBinary => write_stl_binary(out, &merge(objects)),
+    }
+}
+
+// Nesneleri dosyaya yazar; biçim verilmezse dosya uzantısından seçilir
+pub fn export<P: AsRef<Path>>(path: P, format: Option<ExportFormat>, objects: &[(String, MeshData)]) -> Result<(), String> {
+    let path = path.as_ref();
+    let format = match format {
+        Some(format) => format,
+        None => ExportFormat::from_path(path)?,
+    };
+    let file = File::create(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let mut out = BufWriter::new(file);
+    write(&mut out, format, objects)
+        .and_then(|_| out.flush())
+        .map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+// Wavefront OBJ yazar; her nesne kendi "o" bölümünü alır
+pub fn write_obj<W: Write>(out: &mut W, objects: &[(String, MeshData)]) -> io::Result<()> {
+    writeln!(out, "# Exported by opengl_renderer")?;
+    let (mut positions, mut uvs, mut normals) = (0, 0, 0);
+
+    for (name, data) in objects {
+        writeln!(out, "o {}", name)?;
+        for p in &data.positions {
+            writeln!(out, "v {} {} {}", p[0], p[1], p[2])?;
+        }
+        for uv in &data.uvs {
+            writeln!(out, "vt {} {}", uv[0], uv[1])?;
+        }
+        for n in &data.normals {
+            writeln!(out, "vn {} {} {}", n[0], n[1], n[2])?;
+        }
+
+        // OBJ indices are 1-based and count from the start of the file
+        let vertex = |i: u32| {
+            let (p, t, n) = (positions + i + 1, uvs + i + 1, normals + i + 1);
+            match (data.uvs.is_empty(), data.normals.is_empty()) {
+                (true, true) => format!("{}", p),
+                (false, true) => format!("{}/{}", p, t),
+                (true, false) => format!("{}//{}", p, n),
+                (false, false) => format!("{}/{}/{}", p, t, n),
+            }
+        };
+        for triangle in data.indices.chunks_exact(3) {
+            writeln!(out, "f {} {} {}", vertex(triangle[0]), vertex(triangle[1]), vertex(triangle[2]))?;
+        }
+
+        positions += data.positions.len() as u32;
+        uvs += data.uvs.len() as u32;
+        normals += data.normals.len() as u32;
+    }
+    Ok(())
+}
+
+// ASCII PLY yazar; normal ve doku koordinatları varsa köşe özelliği olarak eklenir
+pub fn write_ply<W: Write>(out: &mut W, data: &MeshData) -> io::Result<()> {
+    let (has_normals, has_uvs) = (!data.normals.is_empty(), !data.uvs.is_empty());
+
+    writeln!(out, "ply")?;
+    writeln!(out, "format ascii 1.0")?;
+    writeln!(out, "comment Exported by opengl_renderer")?;
+    writeln!(out, "element vertex {}", data.positions.len())?;
+    writeln!(out, "property float x\nproperty float y\nproperty float z")?;
+    if has_normals {
+        writeln!(out, "property float nx\nproperty float ny\nproperty float nz")?;
+    }
+    if has_uvs {
+        writeln!(out, "property float s\nproperty float t")?;
+    }
+    writeln!(out, "element face {}", data.indices.len() / 3)?;
+    writeln!(out, "property list uchar uint vertex_indices")?;
+    writeln!(out, "end_header")?;
+
+    for (i, p) in data.positions.iter().enumerate() {
+        write!(out, "{} {} {}", p[0], p[1], p[2])?;
+        if has_normals {
+            let n = data.normals[i];
+            write!(out, " {} {} {}", n[0], n[1], n[2])?;
+        }
+        if has_uvs {
+            write!(out, " {} {}", data.uvs[i][0], data.uvs[i][1])?;
+        }
+        writeln!(out)?;
+    }
+    for triangle in data.indices.chunks_exact(3) {
+        writeln!(out, "3 {} {} {}", triangle[0], triangle[1], triangle[2])?;
+    }
+    Ok(())
+}
+
+// Üçgenlerin köşelerini ve yüz normallerini döndürür (STL köşe normali tutmaz)
+fn facets(data: &MeshData) -> impl Iterator<Item = (Vector3D, [Vector3D; 3])> + '_ {
+    data.indices.chunks_exact(3).map(move |triangle| {
+        let corners = [0, 1, 2].map(|i| {
+            let p = data.positions[triangle[i] as usize];
+            Vector3D::new(p[0], p[1], p[2])
+        });
+        let normal = (corners[1] - corners[0]).cross(&(corners[2] - corners[0]));
+        let normal = if normal.magnitude() > 0.0 { normal.normalize() } else { Vector3D::zero() };
+        (normal, corners)
+    })
+}
+
+// ASCII STL yazar
+pub fn write_stl_ascii<W: Write>(out: &mut W, name: &str, data: &MeshData) -> io::Result<()> {
+    writeln!(out, "solid {}", name)?;
+    for (n, corners) in facets(data) {
+        writeln!(out, "  facet normal {} {} {}", n.x, n.y, n.z)?;
+        writeln!(out, "    outer loop")?;
+        for c in corners {
+            writeln!(out, "      vertex {} {} {}", c.x, c.y, c.z)?;
+        }
+        writeln!(out, "    endloop")?;
+        writeln!(out, "  endfacet")?;
+    }
+    writeln!(out, "endsolid {}", name)
+}
+
+// İkili STL yazar: 80 baytlık başlık, üçgen sayısı ve üçgen başına 50 bayt
+pub fn write_stl_binary<W: Write>(out: &mut W, data: &MeshData) -> io::Result<()> {
+    let mut header = [0u8; 80];
+    let title = b"Exported by opengl_renderer";
+    header[..title.len()].copy_from_slice(title);
+    out.write_all(&header)?;
+    out.write_all(&((data.indices.len() / 3) as u32).to_le_bytes())?;
+
+    for (n, corners) in facets(data) {
+        for v in std::iter::once(n).chain(corners) {
+            for component in [v.x, v.y, v.z] {
+                out.write_all(&component.to_le_bytes())?;
+            }
+        }
+        // Attribute byte count, unused
+        out.write_all(&[0, 0])?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix4::Matrix4;
+
+    fn triangle() -> MeshData {
+        MeshData {
+            positions: vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+            normals: vec![[0.0, 0.0, 1.0]; 3],
+            uvs: Vec::new(),
+            indices: vec![0, 1, 2],
+        }
+    }
+
+    #[test]
+    fn obj_indices_continue_across_objects() {
+        let moved = triangle().transformed(&Matrix4::translation(Vector3D::new(2.0, 0.0, 0.0)));
+        let objects = vec![("a".to_string(), triangle()), ("b".to_string(), moved)];
+        let mut out = Vec::new();
+        write_obj(&mut out, &objects).unwrap();
+        let text = String::from_utf8(out).unwrap();
+
+        assert!(text.contains("o b\nv 2 0 0\nv 3 0 0\n"), "{}", text);
+        assert!(text.contains("f 1//1 2//2 3//3\n"));
+        assert!(text.contains("f 4//4 5//5 6//6\n"));
+    }
+
+    #[test]
+    fn ply_and_stl_merge_objects() {
+        let objects = vec![("a".to_string(), triangle()), ("b".to_string(), triangle())];
+
+        let mut ply = Vec::new();
+        write(&mut ply, ExportFormat::Ply, &objects).unwrap();
+        let ply = String::from_utf8(ply).unwrap();
+        assert!(ply.contains("element vertex 6\n") && ply.contains("element face 2\n"));
+        assert!(ply.ends_with("3 0 1 2\n3 3 4 5\n"), "{}", ply);
+
+        let mut stl = Vec::new();
+        write(&mut stl, ExportFormat::StlBinary, &objects).unwrap();
+        assert_eq!(stl.len(), 84 + 2 * 50);
+        assert_eq!(u32::from_le_bytes([stl[80], stl[81], stl[82], stl[83]]), 2);
+
+        let mut ascii = Vec::new();
+        write(&mut ascii, ExportFormat::StlAscii, &objects).unwrap();
+        let ascii = String::from_utf8(ascii).unwrap();
+        assert_eq!(ascii.matches("facet normal 0 0 1\n").count(), 2);
+    }
+
+    #[test]
+    fn transformed_normals_stay_perpendicular() {
+        // Squashing along x must tilt a diagonal normal towards x
+        let mut data = triangle();
+        let d = std::f32::consts::FRAC_1_SQRT_2;
+        data.normals = vec![[d, d, 0.0]; 3];
+        let squashed = data.transformed(&Matrix4::scale(Vector3D::new(0.5, 1.0, 1.0)));
+        let n = squashed.normals[0];
+        assert!(n[0] > n[1], "{:?}", n);
+        assert!((Vector3D::new(n[0], n[1], n[2]).magnitude() - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn format_follows_extension() {
+        assert_eq!(ExportFormat::from_path("out/scene.OBJ"), Ok(ExportFormat::Obj));
+        assert_eq!(ExportFormat::from_path("part.stl"), Ok(ExportFormat::StlBinary));
+        assert!(ExportFormat::from_path("scene.fbx").is_err());
+    }
+}
@@ -0,0 +1,306 @@
+extern crate gl;
+
+use gl::types::{GLenum, GLint, GLuint};
+
+use crate::texture::{Texture, TextureFormat};
+
+#[derive(Debug, Clone)]
+pub struct FramebufferSpec {
+    pub width: u32,
+    pub height: u32,
+    pub color_formats: Vec<TextureFormat>,
+    pub depth_format: Option<TextureFormat>,
+    pub samples: u32,  // 1 = no multisampling
+}
+
+impl FramebufferSpec {
+    // Tek renk ekli, derinlik/stencil'li bir framebuffer tanımı oluşturur
+    pub fn new(width: u32, height: u32) -> FramebufferSpec {
+        FramebufferSpec {
+            width,
+            height,
+            color_formats: vec![TextureFormat::Rgba8],
+            depth_format: Some(TextureFormat::Depth24Stencil8),
+            samples: 1,
+        }
+    }
+}
+
+// Çok örneklemeli (MSAA) çizim hedefi, sonradan dokulara çözümlenir
+struct MultisampleTarget {
+    fbo: GLuint,
+    color_renderbuffers: Vec<GLuint>,
+    depth_renderbuffer: Option<GLuint>,
+}
+
+pub struct Framebuffer {
+    fbo: GLuint,
+    spec: FramebufferSpec,
+    color_textures: Vec<Texture>,
+    depth_texture: Option<Texture>,
+    multisample: Option<MultisampleTarget>,
+}
+
+impl Framebuffer {
+    // Tanıma göre yeni bir framebuffer oluşturur; sürücü framebuffer'ı tamamlayamazsa hata döndürür
+    pub fn new(mut spec: FramebufferSpec) -> Result<Framebuffer, String> {
+        let max_samples = max_samples();
+        if spec.samples > max_samples {
+            eprintln!("{}x MSAA is not supported, using the maximum of {}x", spec.samples, max_samples);
+            spec.samples = max_samples;
+        }
+        let mut framebuffer = Framebuffer {
+            fbo: 0,
+            spec,
+            color_textures: Vec::new(),
+            depth_texture: None,
+            multisample: None,
+        };
+        framebuffer.create()?;
+        Ok(framebuffer)
+    }
+
+    // Ekleri ve framebuffer nesnelerini oluşturur
+    fn create(&mut self) -> Result<(), String> {
+        let (width, height) = (self.spec.width.max(1), self.spec.height.max(1));
+
+        self.color_textures = self
+            .spec
+            .color_formats
+            .iter()
+            .map(|format| Texture::new(width, height, *format))
+            .collect();
+        self.depth_texture = self.spec.depth_format.map(|format| Texture::new(width, height, format));
+
+        unsafe {
+            gl::GenFramebuffers(1, &mut self.fbo);
+            gl::BindFramebuffer(gl::FRAMEBUFFER, self.fbo);
+            for (i, texture) in self.color_textures.iter().enumerate() {
+                gl::FramebufferTexture2D(gl::FRAMEBUFFER, gl::COLOR_ATTACHMENT0 + i as u32, gl::TEXTURE_2D, texture.id(), 0);
+            }
+            if let Some(depth) = &self.depth_texture {
+                gl::FramebufferTexture2D(gl::FRAMEBUFFER, depth_attachment(depth.format()), gl::TEXTURE_2D, depth.id(), 0);
+            }
+            set_draw_buffers(self.color_textures.len());
+            let status = check_status("framebuffer");
+            gl::BindFramebuffer(gl::FRAMEBUFFER, 0);
+            status?;
+        }
+
+        if self.spec.samples > 1 {
+            // Stored before the status check so a failed target is still deleted by destroy()
+            let (target, status) = self.create_multisample(width, height);
+            self.multisample = Some(target);
+            unsafe {
+                gl::BindFramebuffer(gl::FRAMEBUFFER, 0);
+            }
+            status?;
+        }
+        Ok(())
+    }
+
+    // MSAA renderbuffer'larını ve bunlara bağlı framebuffer'ı oluşturur
+    fn create_multisample(&self, width: u32, height: u32) -> (MultisampleTarget, Result<(), String>) {
+        let mut target = MultisampleTarget { fbo: 0, color_renderbuffers: Vec::new(), depth_renderbuffer: None };
+        let samples = self.spec.samples as i32;
+
+        unsafe {
+            gl::GenFramebuffers(1, &mut target.fbo);
+            gl::BindFramebuffer(gl::FRAMEBUFFER, target.fbo);
+
+            for (i, format) in self.spec.color_formats.iter().enumerate() {
+                let renderbuffer = create_renderbuffer(samples, *format, width, height);
+                gl::FramebufferRenderbuffer(gl::FRAMEBUFFER, gl::COLOR_ATTACHMENT0 + i as u32, gl::RENDERBUFFER, renderbuffer);
+                target.color_renderbuffers.push(renderbuffer);
+            }
+            if let Some(format) = self.spec.depth_format {
+                let renderbuffer = create_renderbuffer(samples, format, width, height);
+                gl::FramebufferRenderbuffer(gl::FRAMEBUFFER, depth_attachment(format), gl::RENDERBUFFER, renderbuffer);
+                target.depth_renderbuffer = Some(renderbuffer);
+            }
+            set_draw_buffers(self.spec.color_formats.len());
+            let status = check_status("multisample framebuffer");
+            (target, status)
+        }
+    }
+
+    // Tüm OpenGL nesnelerini siler
+    fn destroy(&mut self) {
+        unsafe {
+            gl::DeleteFramebuffers(1, &self.fbo);
+            if let Some(target) = self.multisample.take() {
+                gl::DeleteFramebuffers(1, &target.fbo);
+                gl::DeleteRenderbuffers(target.color_renderbuffers.len() as i32, target.color_renderbuffers.as_ptr());
+                if let Some(depth) = target.depth_renderbuffer {
+                    gl::DeleteRenderbuffers(1, &depth);
+                }
+            }
+        }
+        self.color_textures.clear();
+        self.depth_texture = None;
+    }
+
+    // Framebuffer'ı çizim hedefi yapar ve viewport'u boyutuna ayarlar
+    pub fn bind(&self) {
+        let fbo = self.multisample.as_ref().map_or(self.fbo, |target| target.fbo);
+        unsafe {
+            gl::BindFramebuffer(gl::FRAMEBUFFER, fbo);
+            gl::Viewport(0, 0, self.spec.width as i32, self.spec.height as i32);
+        }
+    }
+
+    // Varsayılan (pencere) framebuffer'ına geri döner
+    pub fn unbind() {
+        unsafe {
+            gl::BindFramebuffer(gl::FRAMEBUFFER, 0);
+        }
+    }
+
+    // Boyut değiştiyse tüm ekleri yeniden oluşturur
+    pub fn resize(&mut self, width: u32, height: u32) -> Result<(), String> {
+        if width == 0 || height == 0 || (width == self.spec.width && height == self.spec.height) {
+            return Ok(());
+        }
+        self.destroy();
+        self.spec.width = width;
+        self.spec.height = height;
+        self.create()
+    }
+
+    // MSAA içeriğini örneklenebilir dokulara çözümler, MSAA yoksa bir şey yapmaz
+    pub fn resolve(&self) {
+        let target = match &self.multisample {
+            Some(target) => target,
+            None => return,
+        };
+        let (width, height) = (self.spec.width as i32, self.spec.height as i32);
+
+        unsafe {
+            gl::BindFramebuffer(gl::READ_FRAMEBUFFER, target.fbo);
+            gl::BindFramebuffer(gl::DRAW_FRAMEBUFFER, self.fbo);
+
+            // Each color attachment has to be blitted on its own
+            for i in 0..self.color_textures.len() {
+                let attachment = gl::COLOR_ATTACHMENT0 + i as u32;
+                gl::ReadBuffer(attachment);
+                gl::DrawBuffers(1, &attachment);
+                gl::BlitFramebuffer(0, 0, width, height, 0, 0, width, height, gl::COLOR_BUFFER_BIT, gl::NEAREST);
+            }
+            if let Some(depth) = &self.depth_texture {
+                let mut mask = gl::DEPTH_BUFFER_BIT;
+                if depth.format().has_stencil() {
+                    mask |= gl::STENCIL_BUFFER_BIT;
+                }
+                gl::BlitFramebuffer(0, 0, width, height, 0, 0, width, height, mask, gl::NEAREST);
+            }
+
+            set_draw_buffers(self.color_textures.len());
+            if !self.color_textures.is_empty() {
+                gl::ReadBuffer(gl::COLOR_ATTACHMENT0);
+            }
+            gl::BindFramebuffer(gl::FRAMEBUFFER, 0);
+        }
+    }
+
+    // Verilen renk ekini varsayılan framebuffer'a kopyalar
+    pub fn blit_to_screen(&self, attachment: usize, screen_width: u32, screen_height: u32) {
+        self.resolve();
+        unsafe {
+            gl::BindFramebuffer(gl::READ_FRAMEBUFFER, self.fbo);
+            gl::ReadBuffer(gl::COLOR_ATTACHMENT0 + attachment as u32);
+            gl::BindFramebuffer(gl::DRAW_FRAMEBUFFER, 0);
+            gl::BlitFramebuffer(
+                0, 0, self.spec.width as i32, self.spec.height as i32,
+                0, 0, screen_width as i32, screen_height as i32,
+                gl::COLOR_BUFFER_BIT,
+                gl::LINEAR,
+            );
+            gl::BindFramebuffer(gl::FRAMEBUFFER, 0);
+        }
+    }
+
+    // Verilen renk ekinin (çözümlenmiş) dokusunu döndürür
+    pub fn color_texture(&self, index: usize) -> &Texture {
+        &self.color_textures[index]
+    }
+
+    // Derinlik ekinin (çözümlenmiş) dokusunu döndürür
+    pub fn depth_texture(&self) -> Option<&Texture> {
+        self.depth_texture.as_ref()
+    }
+
+    // Framebuffer ID'sini döndürür (MSAA varsa çözümleme hedefi)
+    pub fn id(&self) -> GLuint {
+        self.fbo
+    }
+
+    // Framebuffer tanımını döndürür
+    pub fn spec(&self) -> &FramebufferSpec {
+        &self.spec
+    }
+
+    // Framebuffer genişliğini döndürür
+    pub fn width(&self) -> u32 {
+        self.spec.width
+    }
+
+    // Framebuffer yüksekliğini döndürür
+    pub fn height(&self) -> u32 {
+        self.spec.height
+    }
+}
+
+impl Drop for Framebuffer {
+    // Framebuffer silindiğinde OpenGL kaynaklarını temizler
+    fn drop(&mut self) {
+        self.destroy();
+    }
+}
+
+// Derinlik formatına uygun ek noktasını döndürür
+fn depth_attachment(format: TextureFormat) -> GLenum {
+    if format.has_stencil() {
+        gl::DEPTH_STENCIL_ATTACHMENT
+    } else {
+        gl::DEPTH_ATTACHMENT
+    }
+}
+
+// Çok örneklemeli bir renderbuffer oluşturur
+unsafe fn create_renderbuffer(samples: i32, format: TextureFormat, width: u32, height: u32) -> GLuint {
+    let mut renderbuffer = 0;
+    gl::GenRenderbuffers(1, &mut renderbuffer);
+    gl::BindRenderbuffer(gl::RENDERBUFFER, renderbuffer);
+    gl::RenderbufferStorageMultisample(gl::RENDERBUFFER, samples, format.internal_format(), width as i32, height as i32);
+    gl::BindRenderbuffer(gl::RENDERBUFFER, 0);
+    renderbuffer
+}
+
+// Bağlı framebuffer için çizim tamponlarını ayarlar (çoklu çizim hedefi)
+unsafe fn set_draw_buffers(count: usize) {
+    if count == 0 {
+        gl::DrawBuffer(gl::NONE);
+        gl::ReadBuffer(gl::NONE);
+        return;
+    }
+    let buffers: Vec<GLenum> = (0..count as u32).map(|i| gl::COLOR_ATTACHMENT0 + i).collect();
+    gl::DrawBuffers(count as i32, buffers.as_ptr());
+}
+
+// Bağlı framebuffer'ın tamamlanmış olduğunu doğrular
+unsafe fn check_status(name: &str) -> Result<(), String> {
+    let status = gl::CheckFramebufferStatus(gl::FRAMEBUFFER);
+    if status != gl::FRAMEBUFFER_COMPLETE {
+        return Err(format!("{} is incomplete (status {:#x})", name, status as GLint));
+    }
+    Ok(())
+}
+
+// Sürücünün desteklediği en yüksek MSAA örnek sayısını döndürür
+fn max_samples() -> u32 {
+    let mut samples = 0;
+    unsafe {
+        gl::GetIntegerv(gl::MAX_SAMPLES, &mut samples);
+    }
+    samples.max(1) as u32
+}
@@ -0,0 +1,636 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use gltf::animation::util::ReadOutputs;
+use gltf::khr_lights_punctual::Kind;
+use gltf::mesh::Mode;
+
+use crate::camera::Camera;
+use crate::capture::Image;
+use crate::light::{DirectionalLight, SpotLight};
+use crate::locator;
+use crate::material::Material;
+use crate::matrix4::Matrix4;
+use crate::mesh::MeshData;
+use crate::scene::Transform;
+use crate::vector3d::Vector3D;
+
+// Bir glTF mesh'inin tek malzemeli parçası. Eklem ve ağırlıklar yalnızca iskeletli mesh'lerde doludur.
+#[derive(Debug, Clone)]
+pub struct GltfPrimitive {
+    pub data: MeshData,
+    pub material: Option<usize>,
+    pub joints: Vec<[u16; 4]>,
+    pub weights: Vec<[f32; 4]>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GltfMesh {
+    pub name: String,
+    pub primitives: Vec<GltfPrimitive>,
+}
+
+// glTF'in metal/pürüzlülük malzemesi. Doku alanları GltfAsset::images içindeki sıralardır.
+#[derive(Debug, Clone)]
+pub struct PbrMaterial {
+    pub name: String,
+    pub base_color: [f32; 4],
+    pub base_color_texture: Option<usize>,
+    pub metallic: f32,
+    pub roughness: f32,
+    pub metallic_roughness_texture: Option<usize>,
+    pub normal_texture: Option<usize>,
+    pub occlusion_texture: Option<usize>,
+    pub emissive: [f32; 3],
+    pub emissive_texture: Option<usize>,
+    pub double_sided: bool,
+}
+
+impl PbrMaterial {
+    // Malzemeyi lit shader'ının Blinn-Phong malzemesine yaklaştırır:
+    // metaller yansımayı kendi rengiyle yapar, pürüzlülük parlaklık üssüne çevrilir
+    pub fn to_material(&self) -> Material {
+        let base = Vector3D::new(self.base_color[0], self.base_color[1], self.base_color[2]);
+        let dielectric = Vector3D::new(0.04, 0.04, 0.04);
+        let specular = dielectric + (base - dielectric) * self.metallic;
+        let diffuse = base * (1.0 - self.metallic);
+        // Common roughness to Blinn-Phong exponent mapping: 2 / r^4 - 2
+        let roughness = self.roughness.clamp(0.05, 1.0);
+        let shininess = (2.0 / roughness.powi(4) - 2.0).clamp(1.0, 256.0);
+        Material::new(diffuse, specular, shininess)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Projection {
+    Perspective { y_fov: f32, aspect: Option<f32>, near: f32, far: Option<f32> },  // y_fov in radians
+    Orthographic { x_mag: f32, y_mag: f32, near: f32, far: f32 },
+}
+
+#[derive(Debug, Clone)]
+pub struct GltfCamera {
+    pub name: String,
+    pub projection: Projection,
+}
+
+impl GltfCamera {
+    // Kameranın düğümün dünya matrisindeki konum ve yönünü (-Z yönüne bakar) verilen kameraya uygular.
+    // Ortografik kameralar desteklenmediği için yalnızca konum ve yön aktarılır.
+    pub fn apply(&self, camera: &mut Camera, world: &Matrix4) {
+        camera.position = world.transform_point(Vector3D::zero());
+        camera.target = camera.position + world.transform_vector(Vector3D::new(0.0, 0.0, -1.0)).normalize();
+        camera.up = world.transform_vector(Vector3D::new(0.0, 1.0, 0.0)).normalize();
+        if let Projection::Perspective { y_fov, near, far, .. } = self.projection {
+            camera.fov_y = y_fov.to_degrees();
+            camera.near = near;
+            camera.far = far.unwrap_or(camera.far);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LightKind {
+    Directional,
+    Point,
+    Spot { inner_angle: f32, outer_angle: f32 },  // Radians from the axis
+}
+
+// KHR_lights_punctual ışığı; ışık düğümün -Z yönüne bakar
+#[derive(Debug, Clone)]
+pub struct GltfLight {
+    pub name: String,
+    pub kind: LightKind,
+    pub color: [f32; 3],
+    pub intensity: f32,
+    pub range: Option<f32>,
+}
+
+// Sahneye aktarılabilen ışık türleri
+pub enum ImportedLight {
+    Directional(DirectionalLight),
+    Spot(SpotLight),
+}
+
+impl GltfLight {
+    // Işığı düğümün dünya matrisine göre yerleştirir. Işık sistemimizde noktasal ışık olmadığı için
+    // noktasal ışıklar için None döner. Şiddet fiziksel birimlerden çevrilmeden olduğu gibi aktarılır.
+    pub fn to_light(&self, world: &Matrix4) -> Option<ImportedLight> {
+        let position = world.transform_point(Vector3D::zero());
+        let direction = world.transform_vector(Vector3D::new(0.0, 0.0, -1.0));
+        let color = Vector3D::new(self.color[0], self.color[1], self.color[2]);
+        match self.kind {
+            LightKind::Directional => Some(ImportedLight::Directional(DirectionalLight::new(direction, color, self.intensity))),
+            LightKind::Point => None,
+            LightKind::Spot { inner_angle, outer_angle } => {
+                let mut light =
+                    SpotLight::new(position, direction, color, inner_angle.to_degrees(), outer_angle.to_degrees());
+                light.intensity = self.intensity;
+                light.range = self.range.unwrap_or(light.range);
+                Some(ImportedLight::Spot(light))
+            }
+        }
+    }
+}
+
+// glTF düğümü; alanlardaki sayılar GltfAsset içindeki dizilerin sıralarıdır
+#[derive(Debug, Clone)]
+pub struct GltfNode {
+    pub name: String,
+    pub transform: Transform,
+    pub mesh: Option<usize>,
+    pub camera: Option<usize>,
+    pub light: Option<usize>,
+    pub skin: Option<usize>,
+    pub children: Vec<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GltfSkin {
+    pub name: String,
+    pub joints: Vec<usize>,
+    pub skeleton: Option<usize>,
+    pub inverse_bind_matrices: Vec<Matrix4>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnimatedProperty {
+    Translation,
+    Rotation,  // Quaternions (x, y, z, w)
+    Scale,
+    MorphWeights,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interpolation {
+    Step,
+    Linear,
+    CubicSpline,
+}
+
+// Bir düğüm özelliğinin anahtar kareleri. `values` her anahtar için özelliğin bileşenlerini art arda tutar
+// (cubic spline'da her anahtar için giriş teğeti, değer ve çıkış teğeti).
+#[derive(Debug, Clone)]
+pub struct AnimationChannel {
+    pub node: usize,
+    pub property: AnimatedProperty,
+    pub interpolation: Interpolation,
+    pub times: Vec<f32>,
+    pub values: Vec<f32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GltfAnimation {
+    pub name: String,
+    pub channels: Vec<AnimationChannel>,
+}
+
+impl GltfAnimation {
+    // Animasyonun süresini (son anahtar karenin zamanı, saniye) döndürür
+    pub fn duration(&self) -> f32 {
+        self.channels.iter().filter_map(|channel| channel.times.last()).fold(0.0, |a, &b| a.max(b))
+    }
+}
+
+// CPU tarafına okunmuş bir glTF dosyası. `roots` varsayılan sahnenin kök düğümleridir.
+#[derive(Debug, Clone, Default)]
+pub struct GltfAsset {
+    pub meshes: Vec<GltfMesh>,
+    pub materials: Vec<PbrMaterial>,
+    pub images: Vec<Image>,
+    pub image_files: Vec<Option<PathBuf>>,  // Resolved path of each image that is a separate PNG file
+    pub cameras: Vec<GltfCamera>,
+    pub lights: Vec<GltfLight>,
+    pub nodes: Vec<GltfNode>,
+    pub roots: Vec<usize>,
+    pub skins: Vec<GltfSkin>,
+    pub animations: Vec<GltfAnimation>,
+}
+
+impl GltfAsset {
+    // Primitive'in malzemesini döndürür; malzemesi olmayanlar varsayılan malzemeyi alır
+    pub fn material_of(&self, primitive: &GltfPrimitive) -> Material {
+        primitive.material.map_or_else(Material::default, |index| self.materials[index].to_material())
+    }
+}
+
+// .gltf (harici .bin ve doku dosyalarıyla) veya .glb dosyasını varlık klasörlerinde bulup okur
+pub fn load<P: AsRef<Path>>(path: P) -> Result<GltfAsset, String> {
+    let path = path.as_ref();
+    let resolved = locator::resolve(path)
+        .ok_or_else(|| format!("{}: model not found in any asset directory", path.display()))?;
+    let (document, buffers, images) = gltf::import(&resolved).map_err(|e| format!("{}: {}", resolved.display(), e))?;
+    let mut asset = convert(&document, &buffers, &images).map_err(|e| format!("{}: {}", resolved.display(), e))?;
+    let directory = resolved.parent().unwrap_or(Path::new(""));
+    asset.image_files = document
+        .images()
+        .map(|image| match image.source() {
+            gltf::image::Source::Uri { uri, .. } => image_file(uri, directory),
+            gltf::image::Source::View { .. } => None,
+        })
+        .collect();
+    Ok(asset)
+}
+
+// Bellekteki .glb veya gömülü (data URI) tamponlu .gltf içeriğini okur
+pub fn parse(bytes: &[u8]) -> Result<GltfAsset, String> {
+    let (document, buffers, images) = gltf::import_slice(bytes).map_err(|e| e.to_string())?;
+    let mut asset = convert(&document, &buffers, &images)?;
+    asset.image_files = vec![None; asset.images.len()];
+    Ok(asset)
+}
+
+// Görüntü URI'si klasördeki bir PNG dosyasını gösteriyorsa dosyanın tam yolunu döndürür; data URI'ler ve
+// diğer biçimler None verir. URI'deki %XX kaçışları çözülür.
+fn image_file(uri: &str, directory: &Path) -> Option<PathBuf> {
+    if uri.starts_with("data:") || !uri.to_ascii_lowercase().ends_with(".png") {
+        return None;
+    }
+    let (raw, mut bytes, mut i) = (uri.as_bytes(), Vec::with_capacity(uri.len()), 0);
+    while i < raw.len() {
+        let escaped = raw.get(i + 1..i + 3).and_then(|hex| u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok());
+        match escaped {
+            Some(byte) if raw[i] == b'%' => {
+                bytes.push(byte);
+                i += 3;
+            }
+            _ => {
+                bytes.push(raw[i]);
+                i += 1;
+            }
+        }
+    }
+    fs::canonicalize(directory.join(String::from_utf8(bytes).ok()?)).ok()
+}
+
+// Adı olmayan öğelere "<tür><sıra>" biçiminde ad verir
+fn name_or(name: Option<&str>, kind: &str, index: usize) -> String {
+    name.map_or_else(|| format!("{}{}", kind, index), str::to_string)
+}
+
+fn convert(document: &gltf::Document, buffers: &[gltf::buffer::Data], images: &[gltf::image::Data]) -> Result<GltfAsset, String> {
+    let buffer = |buffer: gltf::Buffer| buffers.get(buffer.index()).map(|data| &data.0[..]);
+    let mut asset = GltfAsset::default();
+
+    for mesh in document.meshes() {
+        let mut primitives = Vec::new();
+        for primitive in mesh.primitives() {
+            let reader = primitive.reader(buffer);
+            let Some(positions) = reader.read_positions() else { continue };
+            let positions: Vec<[f32; 3]> = positions.collect();
+            let indices: Vec<u32> = match reader.read_indices() {
+                Some(indices) => indices.into_u32().collect(),
+                None => (0..positions.len() as u32).collect(),
+            };
+            let indices = match primitive.mode() {
+                Mode::Triangles => indices,
+                Mode::TriangleStrip => strip_to_triangles(&indices),
+                Mode::TriangleFan => fan_to_triangles(&indices),
+                // Points and lines cannot be drawn by the triangle pipeline
+                _ => continue,
+            };
+
+            let mut data = MeshData {
+                positions,
+                normals: reader.read_normals().map_or_else(Vec::new, Iterator::collect),
+                // glTF puts v = 0 at the top of the image; the renderer follows OBJ with v = 0 at the bottom
+                uvs: reader.read_tex_coords(0).map_or_else(Vec::new, |uvs| uvs.into_f32().map(|[u, v]| [u, 1.0 - v]).collect()),
+                indices,
+            };
+            if data.normals.is_empty() {
+                data.compute_normals();
+            }
+            primitives.push(GltfPrimitive {
+                data,
+                material: primitive.material().index(),
+                joints: reader.read_joints(0).map_or_else(Vec::new, |joints| joints.into_u16().collect()),
+                weights: reader.read_weights(0).map_or_else(Vec::new, |weights| weights.into_f32().collect()),
+            });
+        }
+        asset.meshes.push(GltfMesh { name: name_or(mesh.name(), "mesh", mesh.index()), primitives });
+    }
+
+    let image_index = |texture: gltf::Texture| texture.source().index();
+    for material in document.materials() {
+        let pbr = material.pbr_metallic_roughness();
+        asset.materials.push(PbrMaterial {
+            name: name_or(material.name(), "material", asset.materials.len()),
+            base_color: pbr.base_color_factor(),
+            base_color_texture: pbr.base_color_texture().map(|info| image_index(info.texture())),
+            metallic: pbr.metallic_factor(),
+            roughness: pbr.roughness_factor(),
+            metallic_roughness_texture: pbr.metallic_roughness_texture().map(|info| image_index(info.texture())),
+            normal_texture: material.normal_texture().map(|info| image_index(info.texture())),
+            occlusion_texture: material.occlusion_texture().map(|info| image_index(info.texture())),
+            emissive: material.emissive_factor(),
+            emissive_texture: material.emissive_texture().map(|info| image_index(info.texture())),
+            double_sided: material.double_sided(),
+        });
+    }
+
+    for (index, image) in images.iter().enumerate() {
+        let mut image = to_rgba8(image).ok_or_else(|| format!("image {}: unsupported pixel format {:?}", index, image.format))?;
+        // Bottom row first, to match the flipped texture coordinates
+        image.flip_vertical();
+        asset.images.push(image);
+    }
+
+    for camera in document.cameras() {
+        let projection = match camera.projection() {
+            gltf::camera::Projection::Perspective(p) => {
+                Projection::Perspective { y_fov: p.yfov(), aspect: p.aspect_ratio(), near: p.znear(), far: p.zfar() }
+            }
+            gltf::camera::Projection::Orthographic(o) => {
+                Projection::Orthographic { x_mag: o.xmag(), y_mag: o.ymag(), near: o.znear(), far: o.zfar() }
+            }
+        };
+        asset.cameras.push(GltfCamera { name: name_or(camera.name(), "camera", camera.index()), projection });
+    }
+
+    for light in document.lights().into_iter().flatten() {
+        let kind = match light.kind() {
+            Kind::Directional => LightKind::Directional,
+            Kind::Point => LightKind::Point,
+            Kind::Spot { inner_cone_angle, outer_cone_angle } => {
+                LightKind::Spot { inner_angle: inner_cone_angle, outer_angle: outer_cone_angle }
+            }
+        };
+        asset.lights.push(GltfLight {
+            name: name_or(light.name(), "light", light.index()),
+            kind,
+            color: light.color(),
+            intensity: light.intensity(),
+            range: light.range(),
+        });
+    }
+
+    for node in document.nodes() {
+        let (translation, rotation, scale) = node.transform().decomposed();
+        asset.nodes.push(GltfNode {
+            name: name_or(node.name(), "node", node.index()),
+            transform: Transform {
+                translation: Vector3D::new(translation[0], translation[1], translation[2]),
+                rotation: euler_from_quaternion(rotation),
+                scale: Vector3D::new(scale[0], scale[1], scale[2]),
+            },
+            mesh: node.mesh().map(|mesh| mesh.index()),
+            camera: node.camera().map(|camera| camera.index()),
+            light: node.light().map(|light| light.index()),
+            skin: node.skin().map(|skin| skin.index()),
+            children: node.children().map(|child| child.index()).collect(),
+        });
+    }
+
+    // Files without scenes still show their parentless nodes
+    asset.roots = match document.default_scene().or_else(|| document.scenes().next()) {
+        Some(scene) => scene.nodes().map(|node| node.index()).collect(),
+        None => {
+            let children: Vec<usize> = asset.nodes.iter().flat_map(|node| node.children.iter().copied()).collect();
+            (0..asset.nodes.len()).filter(|index| !children.contains(index)).collect()
+        }
+    };
+
+    for skin in document.skins() {
+        let reader = skin.reader(buffer);
+        asset.skins.push(GltfSkin {
+            name: name_or(skin.name(), "skin", skin.index()),
+            joints: skin.joints().map(|joint| joint.index()).collect(),
+            skeleton: skin.skeleton().map(|node| node.index()),
+            inverse_bind_matrices: reader.read_inverse_bind_matrices().map_or_else(Vec::new, |matrices| {
+                matrices.map(|m| Matrix4 { data: flatten_columns(m) }).collect()
+            }),
+        });
+    }
+
+    for animation in document.animations() {
+        let mut channels = Vec::new();
+        for channel in animation.channels() {
+            let reader = channel.reader(buffer);
+            let (Some(times), Some(outputs)) = (reader.read_inputs(), reader.read_outputs()) else { continue };
+            let (property, values): (AnimatedProperty, Vec<f32>) = match outputs {
+                ReadOutputs::Translations(values) => (AnimatedProperty::Translation, values.flatten().collect()),
+                ReadOutputs::Rotations(values) => (AnimatedProperty::Rotation, values.into_f32().flatten().collect()),
+                ReadOutputs::Scales(values) => (AnimatedProperty::Scale, values.flatten().collect()),
+                ReadOutputs::MorphTargetWeights(values) => (AnimatedProperty::MorphWeights, values.into_f32().collect()),
+            };
+            channels.push(AnimationChannel {
+                node: channel.target().node().index(),
+                property,
+                interpolation: match channel.sampler().interpolation() {
+                    gltf::animation::Interpolation::Step => Interpolation::Step,
+                    gltf::animation::Interpolation::Linear => Interpolation::Linear,
+                    gltf::animation::Interpolation::CubicSpline => Interpolation::CubicSpline,
+                },
+                times: times.collect(),
+                values,
+            });
+        }
+        asset.animations.push(GltfAnimation { name: name_or(animation.name(), "animation", animation.index()), channels });
+    }
+
+    Ok(asset)
+}
+
+// Sütun öncelikli 4x4 diziyi Matrix4 verisine çevirir
+fn flatten_columns(columns: [[f32; 4]; 4]) -> [f32; 16] {
+    let mut data = [0.0; 16];
+    for (i, column) in columns.iter().enumerate() {
+        data[i * 4..i * 4 + 4].copy_from_slice(column);
+    }
+    data
+}
+
+// Üçgen şeridini ayrı üçgenlere çevirir; tek sıradaki üçgenlerin sarma yönü düzeltilir
+fn strip_to_triangles(indices: &[u32]) -> Vec<u32> {
+    let mut triangles = Vec::new();
+    for i in 0..indices.len().saturating_sub(2) {
+        if i % 2 == 0 {
+            triangles.extend_from_slice(&[indices[i], indices[i + 1], indices[i + 2]]);
+        } else {
+            triangles.extend_from_slice(&[indices[i + 1], indices[i], indices[i + 2]]);
+        }
+    }
+    triangles
+}
+
+// Üçgen yelpazesini ayrı üçgenlere çevirir
+fn fan_to_triangles(indices: &[u32]) -> Vec<u32> {
+    let mut triangles = Vec::new();
+    for i in 1..indices.len().saturating_sub(1) {
+        triangles.extend_from_slice(&[indices[0], indices[i], indices[i + 1]]);
+    }
+    triangles
+}
+
+// glTF görüntüsünü RGBA8'e çevirir; 16 bitlik kanallar 8 bite indirilir, kayan noktalı biçimler desteklenmez
+fn to_rgba8(image: &gltf::image::Data) -> Option<Image> {
+    use gltf::image::Format;
+
+    let (channels, wide) = match image.format {
+        Format::R8 => (1, false),
+        Format::R8G8 => (2, false),
+        Format::R8G8B8 => (3, false),
+        Format::R8G8B8A8 => (4, false),
+        Format::R16 => (1, true),
+        Format::R16G16 => (2, true),
+        Format::R16G16B16 => (3, true),
+        Format::R16G16B16A16 => (4, true),
+        _ => return None,
+    };
+    let bytes_per_channel = if wide { 2 } else { 1 };
+
+    let mut pixels = Vec::with_capacity((image.width * image.height * 4) as usize);
+    for pixel in image.pixels.chunks_exact(channels * bytes_per_channel) {
+        // Little-endian 16 bit values keep their most significant byte second
+        let channel = |i: usize| pixel[i * bytes_per_channel + bytes_per_channel - 1];
+        let rgba = match channels {
+            1 => [channel(0), channel(0), channel(0), 255],
+            2 => [channel(0), channel(0), channel(0), channel(1)],
+            3 => [channel(0), channel(1), channel(2), 255],
+            _ => [channel(0), channel(1), channel(2), channel(3)],
+        };
+        pixels.extend_from_slice(&rgba);
+    }
+    Some(Image { width: image.width, height: image.height, pixels })
+}
+
+// Birim kuaterniyonu (x, y, z, w) Transform'un kullandığı Rx * Ry * Rz sırasındaki Euler açılarına çevirir
+fn euler_from_quaternion([x, y, z, w]: [f32; 4]) -> Vector3D {
+    // Rotation matrix entries (row, column) that the Euler angles can be read from
+    let r00 = 1.0 - 2.0 * (y * y + z * z);
+    let r01 = 2.0 * (x * y - z * w);
+    let r02 = 2.0 * (x * z + y * w);
+    let r11 = 1.0 - 2.0 * (x * x + z * z);
+    let r12 = 2.0 * (y * z - x * w);
+    let r21 = 2.0 * (y * z + x * w);
+    let r22 = 1.0 - 2.0 * (x * x + y * y);
+
+    let b = r02.clamp(-1.0, 1.0).asin();
+    if r02.abs() < 0.99999 {
+        Vector3D::new((-r12).atan2(r22), b, (-r01).atan2(r00))
+    } else {
+        // Gimbal lock: only the sum of the x and z angles is defined, put it all into x
+        Vector3D::new(r21.atan2(r11), b, 0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // One triangle with a material, a hierarchy with a camera and a spot light, and a rotation animation.
+    // Buffer: 3 positions, 3 u16 indices (padded), 2 key times and 2 rotation quaternions.
+    const TRIANGLE: &str = r#"{
+        "asset": {"version": "2.0"},
+        "extensionsUsed": ["KHR_lights_punctual"],
+        "extensions": {"KHR_lights_punctual": {"lights": [
+            {"type": "spot", "color": [1, 0.5, 0], "intensity": 3, "spot": {"innerConeAngle": 0.2, "outerConeAngle": 0.4}}
+        ]}},
+        "scene": 0,
+        "scenes": [{"nodes": [0]}],
+        "nodes": [
+            {"name": "root", "translation": [0, 1, 0], "children": [1, 2, 3]},
+            {"name": "tri", "mesh": 0, "rotation": [0, 0.7071068, 0, 0.7071068]},
+            {"name": "eye", "camera": 0, "translation": [0, 0, 5]},
+            {"name": "lamp", "extensions": {"KHR_lights_punctual": {"light": 0}}}
+        ],
+        "cameras": [{"type": "perspective", "perspective": {"yfov": 0.8, "znear": 0.1, "zfar": 100}}],
+        "meshes": [{"name": "triangle", "primitives": [{"attributes": {"POSITION": 0}, "indices": 1, "material": 0}]}],
+        "materials": [{"name": "gold", "pbrMetallicRoughness": {"baseColorFactor": [1, 0.8, 0.2, 1], "metallicFactor": 1, "roughnessFactor": 0.3}}],
+        "animations": [{"name": "turn", "channels": [{"sampler": 0, "target": {"node": 1, "path": "rotation"}}],
+                        "samplers": [{"input": 2, "output": 3}]}],
+        "accessors": [
+            {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0]},
+            {"bufferView": 1, "componentType": 5123, "count": 3, "type": "SCALAR"},
+            {"bufferView": 2, "componentType": 5126, "count": 2, "type": "SCALAR", "min": [0], "max": [1]},
+            {"bufferView": 3, "componentType": 5126, "count": 2, "type": "VEC4"}
+        ],
+        "bufferViews": [
+            {"buffer": 0, "byteOffset": 0, "byteLength": 36},
+            {"buffer": 0, "byteOffset": 36, "byteLength": 6},
+            {"buffer": 0, "byteOffset": 44, "byteLength": 8},
+            {"buffer": 0, "byteOffset": 52, "byteLength": 32}
+        ],
+        "buffers": [{"byteLength": 84, "uri": "data:application/octet-stream;base64,AAAAAAAAAAAAAAAAAACAPwAAAAAAAAAAAAAAAAAAgD8AAAAAAAABAAIAAAAAAAAAAACAPwAAAAAAAAAAAAAAAAAAgD8AAAAA9AQ1PwAAAAD0BDU/"}]
+    }"#;
+
+    fn approx(a: Vector3D, b: Vector3D) -> bool {
+        (a - b).magnitude() < 1e-4
+    }
+
+    #[test]
+    fn imports_meshes_hierarchy_and_materials() {
+        let asset = parse(TRIANGLE.as_bytes()).unwrap();
+        assert_eq!(asset.roots, [0]);
+        assert_eq!(asset.nodes[0].children, [1, 2, 3]);
+
+        let primitive = &asset.meshes[0].primitives[0];
+        assert_eq!(primitive.data.indices, [0, 1, 2]);
+        // Missing normals are computed from the faces
+        assert!(approx(Vector3D::new(primitive.data.normals[0][0], primitive.data.normals[0][1], primitive.data.normals[0][2]), Vector3D::new(0.0, 0.0, 1.0)));
+
+        let gold = asset.material_of(primitive);
+        assert!(approx(gold.specular, Vector3D::new(1.0, 0.8, 0.2)));
+        assert!(approx(gold.diffuse, Vector3D::zero()));
+        assert!(asset.materials[0].base_color_texture.is_none());
+    }
+
+    #[test]
+    fn node_rotation_matches_the_quaternion() {
+        let asset = parse(TRIANGLE.as_bytes()).unwrap();
+        let matrix = asset.nodes[1].transform.matrix();
+        // A quarter turn around +Y maps +X onto -Z
+        assert!(approx(matrix.transform_vector(Vector3D::new(1.0, 0.0, 0.0)), Vector3D::new(0.0, 0.0, -1.0)));
+
+        let q = [0.3f32, -0.5, 0.2, 0.787_4];
+        let length = q.iter().map(|c| c * c).sum::<f32>().sqrt();
+        let [x, y, z, w] = q.map(|c| c / length);
+        let rotation = Transform { rotation: euler_from_quaternion([x, y, z, w]), ..Transform::identity() }.matrix();
+        // Rotating a vector by q: v' = v + 2w(u x v) + 2u x (u x v)
+        let u = Vector3D::new(x, y, z);
+        let v = Vector3D::new(0.2, 1.0, -0.4);
+        let t = u.cross(&v) * 2.0;
+        assert!(approx(rotation.transform_vector(v), v + t * w + u.cross(&t)));
+    }
+
+    #[test]
+    fn imports_cameras_lights_and_animations() {
+        let asset = parse(TRIANGLE.as_bytes()).unwrap();
+        assert_eq!(asset.nodes[2].camera, Some(0));
+        assert_eq!(asset.cameras[0].projection, Projection::Perspective { y_fov: 0.8, aspect: None, near: 0.1, far: Some(100.0) });
+
+        let lamp = &asset.nodes[3];
+        assert_eq!(lamp.light, Some(0));
+        match asset.lights[0].to_light(&lamp.transform.matrix()) {
+            Some(ImportedLight::Spot(light)) => {
+                assert!(approx(light.direction, Vector3D::new(0.0, 0.0, -1.0)));
+                assert!((light.outer_angle - 0.4f32.to_degrees()).abs() < 1e-4);
+                assert_eq!(light.intensity, 3.0);
+            }
+            _ => panic!("expected a spot light"),
+        }
+
+        let turn = &asset.animations[0];
+        assert_eq!(turn.duration(), 1.0);
+        let channel = &turn.channels[0];
+        assert_eq!((channel.node, channel.property, channel.interpolation), (1, AnimatedProperty::Rotation, Interpolation::Linear));
+        assert_eq!(channel.values.len(), 8);
+    }
+
+    #[test]
+    fn image_files_are_found_next_to_the_model() {
+        let directory = std::env::temp_dir().join(format!("gltf_import_test_{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        fs::write(directory.join("base color.png"), []).unwrap();
+
+        assert_eq!(image_file("base%20color.png", &directory), Some(fs::canonicalize(directory.join("base color.png")).unwrap()));
+        assert_eq!(image_file("missing.png", &directory), None);
+        assert_eq!(image_file("data:image/png;base64,AAAA", &directory), None);
+        assert_eq!(image_file("photo.jpg", &directory), None);
+    }
+
+    #[test]
+    fn converts_strips_and_fans() {
+        assert_eq!(strip_to_triangles(&[0, 1, 2, 3]), [0, 1, 2, 2, 1, 3]);
+        assert_eq!(fan_to_triangles(&[0, 1, 2, 3]), [0, 1, 2, 0, 2, 3]);
+    }
+}
@@ -0,0 +1,256 @@
+use std::env;
+use std::path::{Path, PathBuf};
+
+use crate::app::{AppSettings, Application};
+use crate::capture::Image;
+use crate::cli;
+
+// Referans görüntüleri yeniden oluşturmak için ayarlanan ortam değişkeni
+pub const UPDATE_ENV: &str = "UPDATE_GOLDEN";
+
+// Ekransız bağlam tipini seçen ortam değişkeni (native, egl, osmesa)
+pub const CONTEXT_API_ENV: &str = "GOLDEN_CONTEXT_API";
+
+#[derive(Debug, Clone, Copy)]
+pub struct Tolerance {
+    pub per_channel: u8,          // Largest allowed difference of a single channel
+    pub max_mismatch_ratio: f32,  // Fraction of pixels that may exceed `per_channel`
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Tolerance { per_channel: 2, max_mismatch_ratio: 0.001 }
+    }
+}
+
+#[derive(Debug)]
+pub struct Comparison {
+    pub mismatched: usize,
+    pub total: usize,
+    pub max_difference: u8,
+    pub diff: Image,
+}
+
+impl Comparison {
+    // Karşılaştırmanın tolerans içinde kalıp kalmadığını döndürür
+    pub fn passes(&self, tolerance: &Tolerance) -> bool {
+        self.mismatched as f32 <= self.total as f32 * tolerance.max_mismatch_ratio
+    }
+}
+
+// İki görüntüyü piksel piksel karşılaştırır ve fark görüntüsünü üretir.
+// Farkı tolerans içindeki pikseller soluk gri, dışındakiler kırmızı çizilir.
+pub fn compare(actual: &Image, expected: &Image, tolerance: &Tolerance) -> Result<Comparison, String> {
+    if actual.width != expected.width || actual.height != expected.height {
+        return Err(format!(
+            "image size {}x{} does not match reference size {}x{}",
+            actual.width, actual.height, expected.width, expected.height
+        ));
+    }
+
+    let mut diff = Image::new(actual.width, actual.height, [0, 0, 0, 255]);
+    let mut mismatched = 0;
+    let mut max_difference = 0;
+
+    for y in 0..actual.height {
+        for x in 0..actual.width {
+            let a = actual.pixel(x, y);
+            let e = expected.pixel(x, y);
+            let difference = (0..4).map(|c| a[c].abs_diff(e[c])).max().unwrap();
+            max_difference = max_difference.max(difference);
+
+            if difference > tolerance.per_channel {
+                mismatched += 1;
+                diff.set_pixel(x, y, [255, 0, 0, 255]);
+            } else {
+                let luma = ((e[0] as u32 * 54 + e[1] as u32 * 183 + e[2] as u32 * 19) >> 8) as u8;
+                let faded = luma / 3;
+                diff.set_pixel(x, y, [faded, faded, faded, 255]);
+            }
+        }
+    }
+
+    Ok(Comparison {
+        mismatched,
+        total: (actual.width * actual.height) as usize,
+        max_difference,
+        diff,
+    })
+}
+
+// Referans görüntünün yolunu döndürür (tests/golden/<ad>.png)
+pub fn reference_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests").join("golden").join(format!("{}.png", name))
+}
+
+// Başarısız karşılaştırmaların çıktılarının yazıldığı klasörü döndürür (target/golden)
+pub fn output_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("target").join("golden")
+}
+
+// Görüntüyü tests/golden altındaki referansla karşılaştırır. UPDATE_GOLDEN ayarlıysa referansı yeniden yazar;
+// eksik referans bir hatadır, böylece referansı olmayan bir test hiçbir zaman kendiliğinden geçmez.
+pub fn check(name: &str, actual: &Image, tolerance: &Tolerance) -> Result<(), String> {
+    let update = env::var(UPDATE_ENV).map(|v| !v.is_empty() && v != "0").unwrap_or(false);
+    check_reference(name, &reference_path(name), actual, tolerance, update)
+}
+
+// Görüntüyü verilen referans dosyasıyla karşılaştırır; `update` ise referansı yeniden yazar
+fn check_reference(name: &str, reference: &Path, actual: &Image, tolerance: &Tolerance, update: bool) -> Result<(), String> {
+    if update {
+        actual
+            .save_png(reference)
+            .map_err(|e| format!("failed to write reference {}: {}", reference.display(), e))?;
+        println!("golden '{}': wrote reference {}", name, reference.display());
+        return Ok(());
+    }
+    if !reference.exists() {
+        return Err(format!(
+            "golden '{}': reference {} does not exist (run with {}=1 to create it)",
+            name,
+            reference.display(),
+            UPDATE_ENV
+        ));
+    }
+
+    let expected = Image::load_png(reference).map_err(|e| {
+        format!(
+            "failed to load reference {}: {} (run with {}=1 to create it)",
+            reference.display(),
+            e,
+            UPDATE_ENV
+        )
+    })?;
+
+    let comparison = compare(actual, &expected, tolerance).map_err(|e| format!("golden '{}': {}", name, e))?;
+    if comparison.passes(tolerance) {
+        return Ok(());
+    }
+
+    let dir = output_dir();
+    let actual_path = dir.join(format!("{}.actual.png", name));
+    let diff_path = dir.join(format!("{}.diff.png", name));
+    actual.save_png(&actual_path).map_err(|e| e.to_string())?;
+    comparison.diff.save_png(&diff_path).map_err(|e| e.to_string())?;
+
+    Err(format!(
+        "golden '{}': {} of {} pixels differ by more than {} (max difference {}); see {} and {}",
+        name,
+        comparison.mismatched,
+        comparison.total,
+        tolerance.per_channel,
+        comparison.max_difference,
+        actual_path.display(),
+        diff_path.display()
+    ))
+}
+
+// Ortam değişkeninden ekransız bağlam tipini okur; değişken yoksa Native kullanılır
+pub fn context_api() -> Result<glfw::ContextCreationApi, String> {
+    match env::var(CONTEXT_API_ENV) {
+        Ok(value) => cli::parse_context_api(&value).map_err(|e| format!("{}: {}", CONTEXT_API_ENV, e)),
+        Err(_) => Ok(glfw::ContextCreationApi::Native),
+    }
+}
+
+// scenes/<ad>.ron sahnesini sabit çözünürlükte ekransız çizer ve verilen karedeki görüntüyü döndürür
+pub fn render_scene(name: &str, width: u32, height: u32, frame: u32) -> Result<Image, String> {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("scenes").join(format!("{}.ron", name));
+    let settings = AppSettings { width, height, ..AppSettings::default() };
+    let mut app = Application::new_headless(&settings, context_api()?)?;
+    app.load_scene(path)?;
+    app.init_gl()?;
+    let image = app.render_frames(frame);
+    app.cleanup();
+    Ok(image)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checkerboard(width: u32, height: u32) -> Image {
+        let mut image = Image::new(width, height, [0, 0, 0, 255]);
+        for y in 0..height {
+            for x in 0..width {
+                if (x + y) % 2 == 0 {
+                    image.set_pixel(x, y, [255, 255, 255, 255]);
+                }
+            }
+        }
+        image
+    }
+
+    #[test]
+    fn identical_images_match() {
+        let image = checkerboard(8, 8);
+        let comparison = compare(&image, &image, &Tolerance::default()).unwrap();
+        assert_eq!(comparison.mismatched, 0);
+        assert_eq!(comparison.max_difference, 0);
+        assert!(comparison.passes(&Tolerance::default()));
+    }
+
+    #[test]
+    fn differences_within_tolerance_are_ignored() {
+        let expected = Image::new(4, 4, [100, 100, 100, 255]);
+        let actual = Image::new(4, 4, [102, 99, 100, 255]);
+        let comparison = compare(&actual, &expected, &Tolerance::default()).unwrap();
+        assert_eq!(comparison.mismatched, 0);
+        assert_eq!(comparison.max_difference, 2);
+    }
+
+    #[test]
+    fn mismatched_pixels_are_marked_in_diff() {
+        let expected = Image::new(4, 4, [0, 0, 0, 255]);
+        let mut actual = expected.clone();
+        actual.set_pixel(1, 2, [200, 0, 0, 255]);
+
+        let tolerance = Tolerance { per_channel: 2, max_mismatch_ratio: 0.0 };
+        let comparison = compare(&actual, &expected, &tolerance).unwrap();
+        assert_eq!(comparison.mismatched, 1);
+        assert_eq!(comparison.max_difference, 200);
+        assert_eq!(comparison.diff.pixel(1, 2), [255, 0, 0, 255]);
+        assert_eq!(comparison.diff.pixel(0, 0), [0, 0, 0, 255]);
+        assert!(!comparison.passes(&tolerance));
+    }
+
+    #[test]
+    fn size_mismatch_is_an_error() {
+        let result = compare(&Image::new(4, 4, [0; 4]), &Image::new(4, 5, [0; 4]), &Tolerance::default());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn png_round_trip_preserves_pixels() {
+        let image = checkerboard(5, 3);
+        let path = output_dir().join("round_trip.png");
+        image.save_png(&path).unwrap();
+        assert_eq!(Image::load_png(&path).unwrap(), image);
+    }
+
+    #[test]
+    fn missing_reference_is_an_error() {
+        let reference = output_dir().join("missing.png");
+        let _ = std::fs::remove_file(&reference);
+        let image = checkerboard(4, 4);
+
+        let error = check_reference("missing", &reference, &image, &Tolerance::default(), false).unwrap_err();
+        assert!(error.contains("does not exist"), "{}", error);
+        assert!(!reference.exists());
+
+        check_reference("missing", &reference, &image, &Tolerance::default(), true).unwrap();
+        check_reference("missing", &reference, &image, &Tolerance::default(), false).unwrap();
+    }
+
+    // Needs an OpenGL context, so it only runs when GOLDEN_CONTEXT_API picks one. A software context is
+    // enough, e.g. in CI: GOLDEN_CONTEXT_API=egl cargo test
+    #[test]
+    fn demo_scene_matches_reference() {
+        if env::var_os(CONTEXT_API_ENV).is_none() {
+            eprintln!("Skipping golden test 'demo': set {} to native, egl or osmesa to run it", CONTEXT_API_ENV);
+            return;
+        }
+        let image = render_scene("demo", 320, 240, 30).unwrap_or_else(|e| panic!("cannot render the demo scene: {}", e));
+        check("demo", &image, &Tolerance::default()).unwrap();
+    }
+}
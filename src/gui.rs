@@ -0,0 +1,602 @@
+use std::collections::HashMap;
+use std::ffi::c_void;
+use std::mem;
+use std::time::Instant;
+
+use egui::epaint::textures::{TextureFilter, TextureWrapMode};
+use egui::epaint::{ImageDelta, Primitive};
+use egui::{ClippedPrimitive, Pos2, RawInput, TextureId, TexturesDelta, ViewportId};
+use glfw::{Action, WindowEvent};
+
+use crate::camera::Camera;
+use crate::light::Lighting;
+use crate::matrix4::Matrix4;
+use crate::scene::{NodeId, Scene};
+use crate::shader::Shader;
+use crate::sprite_batch::SpriteBatch;
+use crate::vector3d::Vector3D;
+
+// Bir köşenin kayan nokta sayısı: konum (2), doku koordinatı (2), renk (4); sprite shader'ıyla aynı düzen
+const VERTEX_FLOATS: usize = 8;
+
+// Arayüzde gösterilen kare istatistikleri
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FrameStats {
+    pub fps: f32,
+    pub nodes: usize,  // Visible nodes only
+    pub shapes: usize,
+    pub debug_lines: usize,
+}
+
+// GLFW tuş değiştiricilerini egui'ninkilere çevirir
+fn modifiers(mods: glfw::Modifiers) -> egui::Modifiers {
+    let ctrl = mods.contains(glfw::Modifiers::Control);
+    egui::Modifiers {
+        alt: mods.contains(glfw::Modifiers::Alt),
+        ctrl,
+        shift: mods.contains(glfw::Modifiers::Shift),
+        mac_cmd: false,
+        command: ctrl,
+    }
+}
+
+// Metin düzenleme ve gezinme için gereken GLFW tuşlarını egui tuşlarına çevirir
+fn key(key: glfw::Key) -> Option<egui::Key> {
+    use egui::Key as K;
+    use glfw::Key as G;
+    Some(match key {
+        G::Left => K::ArrowLeft,
+        G::Right => K::ArrowRight,
+        G::Up => K::ArrowUp,
+        G::Down => K::ArrowDown,
+        G::Home => K::Home,
+        G::End => K::End,
+        G::PageUp => K::PageUp,
+        G::PageDown => K::PageDown,
+        G::Backspace => K::Backspace,
+        G::Delete => K::Delete,
+        G::Enter | G::KpEnter => K::Enter,
+        G::Tab => K::Tab,
+        G::Escape => K::Escape,
+        G::Space => K::Space,
+        G::A => K::A,
+        G::Z => K::Z,
+        G::Y => K::Y,
+        _ => return None,
+    })
+}
+
+// GLFW olaylarını egui girdisine çeviren durum; imleç konumu fare tuşu olayları için saklanır.
+// Konumlar pencere koordinatındadır ve egui noktalarına birebir karşılık gelir.
+#[derive(Debug, Default)]
+pub struct GuiInput {
+    pointer: Pos2,
+    modifiers: egui::Modifiers,
+    events: Vec<egui::Event>,
+}
+
+impl GuiInput {
+    // Olayı egui girdisine ekler; egui'yi ilgilendirmeyen olaylar yok sayılır
+    pub fn handle_event(&mut self, event: &WindowEvent) {
+        let event = match *event {
+            WindowEvent::CursorPos(x, y) => {
+                self.pointer = Pos2::new(x as f32, y as f32);
+                egui::Event::PointerMoved(self.pointer)
+            }
+            WindowEvent::CursorEnter(false) => egui::Event::PointerGone,
+            WindowEvent::MouseButton(button, action, mods) => {
+                self.modifiers = modifiers(mods);
+                let button = match button {
+                    glfw::MouseButtonLeft => egui::PointerButton::Primary,
+                    glfw::MouseButtonRight => egui::PointerButton::Secondary,
+                    glfw::MouseButtonMiddle => egui::PointerButton::Middle,
+                    _ => return,
+                };
+                egui::Event::PointerButton {
+                    pos: self.pointer,
+                    button,
+                    pressed: action != Action::Release,
+                    modifiers: self.modifiers,
+                }
+            }
+            WindowEvent::Scroll(x, y) => egui::Event::MouseWheel {
+                unit: egui::MouseWheelUnit::Line,
+                delta: egui::vec2(x as f32, y as f32),
+                modifiers: self.modifiers,
+            },
+            WindowEvent::Char(c) if !c.is_control() => egui::Event::Text(c.to_string()),
+            WindowEvent::Key(glfw_key, _, action, mods) => {
+                self.modifiers = modifiers(mods);
+                let Some(key) = key(glfw_key) else {
+                    return;
+                };
+                egui::Event::Key {
+                    key,
+                    physical_key: None,
+                    pressed: action != Action::Release,
+                    repeat: action == Action::Repeat,
+                    modifiers: self.modifiers,
+                }
+            }
+            WindowEvent::Focus(focused) => egui::Event::WindowFocused(focused),
+            _ => return,
+        };
+        self.events.push(event);
+    }
+
+    // Biriken olaylardan bir karelik egui girdisi oluşturur; `size` pencere boyutudur (nokta)
+    pub fn take(&mut self, size: (f32, f32), pixels_per_point: f32, time: f64) -> RawInput {
+        let mut input = RawInput {
+            screen_rect: Some(egui::Rect::from_min_size(Pos2::ZERO, egui::vec2(size.0, size.1))),
+            time: Some(time),
+            modifiers: self.modifiers,
+            events: mem::take(&mut self.events),
+            focused: true,
+            ..RawInput::default()
+        };
+        input.viewports.entry(ViewportId::ROOT).or_default().native_pixels_per_point = Some(pixels_per_point);
+        input
+    }
+}
+
+// egui üçgenlerini sprite köşe düzenine (konum, doku koordinatı, renk) çevirir
+fn mesh_vertices(mesh: &egui::Mesh) -> Vec<f32> {
+    let mut vertices = Vec::with_capacity(mesh.vertices.len() * VERTEX_FLOATS);
+    for vertex in &mesh.vertices {
+        let color = vertex.color.to_array().map(|c| c as f32 / 255.0);
+        vertices.extend_from_slice(&[vertex.pos.x, vertex.pos.y, vertex.uv.x, vertex.uv.y]);
+        vertices.extend_from_slice(&color);
+    }
+    vertices
+}
+
+// Nokta cinsinden kırpma dikdörtgenini çizim hedefinin piksellerinde (sol alt köşe 0, 0) bir makas
+// dikdörtgenine (x, y, genişlik, yükseklik) çevirir; görünür alan kalmazsa None döner
+fn scissor_rect(clip: egui::Rect, pixels_per_point: f32, width: u32, height: u32) -> Option<[i32; 4]> {
+    let min_x = (clip.min.x * pixels_per_point).round().clamp(0.0, width as f32) as i32;
+    let min_y = (clip.min.y * pixels_per_point).round().clamp(0.0, height as f32) as i32;
+    let max_x = (clip.max.x * pixels_per_point).round().clamp(0.0, width as f32) as i32;
+    let max_y = (clip.max.y * pixels_per_point).round().clamp(0.0, height as f32) as i32;
+    if max_x <= min_x || max_y <= min_y {
+        return None;
+    }
+    Some([min_x, height as i32 - max_y, max_x - min_x, max_y - min_y])
+}
+
+// egui çizim çıktısını OpenGL ile çizen ressam; dokuları egui'nin kimlikleriyle tutar
+pub struct GuiPainter {
+    shader: Shader,
+    vao: gl::types::GLuint,
+    vbo: gl::types::GLuint,
+    ebo: gl::types::GLuint,
+    textures: HashMap<TextureId, gl::types::GLuint>,
+}
+
+impl GuiPainter {
+    // Sprite shader'ını yükler ve köşe tamponlarını hazırlar
+    pub fn new() -> GuiPainter {
+        let mut painter = GuiPainter {
+            shader: Shader::load("sprite_vertex_shader.glsl", "sprite_fragment_shader.glsl"),
+            vao: 0,
+            vbo: 0,
+            ebo: 0,
+            textures: HashMap::new(),
+        };
+
+        let stride = (VERTEX_FLOATS * mem::size_of::<f32>()) as gl::types::GLsizei;
+        unsafe {
+            gl::GenVertexArrays(1, &mut painter.vao);
+            gl::GenBuffers(1, &mut painter.vbo);
+            gl::GenBuffers(1, &mut painter.ebo);
+
+            gl::BindVertexArray(painter.vao);
+            gl::BindBuffer(gl::ARRAY_BUFFER, painter.vbo);
+            gl::BindBuffer(gl::ELEMENT_ARRAY_BUFFER, painter.ebo);
+
+            gl::VertexAttribPointer(0, 2, gl::FLOAT, gl::FALSE, stride, std::ptr::null());
+            gl::EnableVertexAttribArray(0);
+            gl::VertexAttribPointer(1, 2, gl::FLOAT, gl::FALSE, stride, (2 * mem::size_of::<f32>()) as *const c_void);
+            gl::EnableVertexAttribArray(1);
+            gl::VertexAttribPointer(2, 4, gl::FLOAT, gl::FALSE, stride, (4 * mem::size_of::<f32>()) as *const c_void);
+            gl::EnableVertexAttribArray(2);
+
+            gl::BindVertexArray(0);
+            gl::BindBuffer(gl::ARRAY_BUFFER, 0);
+        }
+        painter
+    }
+
+    // Yeni veya değişen dokuları yükler
+    fn set_textures(&mut self, delta: &TexturesDelta) {
+        for (id, image) in &delta.set {
+            self.set_texture(*id, image);
+        }
+    }
+
+    // Bir dokunun tamamını ya da (konum verildiyse) bir bölümünü yükler
+    fn set_texture(&mut self, id: TextureId, delta: &ImageDelta) {
+        let egui::ImageData::Color(image) = &delta.image;
+        let pixels: Vec<u8> = image.pixels.iter().flat_map(|pixel| pixel.to_array()).collect();
+        let [width, height] = image.size;
+
+        let filter = |filter: TextureFilter| match filter {
+            TextureFilter::Nearest => gl::NEAREST,
+            TextureFilter::Linear => gl::LINEAR,
+        };
+        let wrap = match delta.options.wrap_mode {
+            TextureWrapMode::ClampToEdge => gl::CLAMP_TO_EDGE,
+            TextureWrapMode::Repeat => gl::REPEAT,
+            TextureWrapMode::MirroredRepeat => gl::MIRRORED_REPEAT,
+        };
+
+        unsafe {
+            let texture = *self.textures.entry(id).or_insert_with(|| {
+                let mut texture = 0;
+                gl::GenTextures(1, &mut texture);
+                texture
+            });
+            gl::BindTexture(gl::TEXTURE_2D, texture);
+            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MAG_FILTER, filter(delta.options.magnification) as i32);
+            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MIN_FILTER, filter(delta.options.minification) as i32);
+            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_WRAP_S, wrap as i32);
+            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_WRAP_T, wrap as i32);
+            // Rows of egui images are tightly packed RGBA, so the default 4-byte alignment always holds
+            match delta.pos {
+                Some([x, y]) => gl::TexSubImage2D(
+                    gl::TEXTURE_2D,
+                    0,
+                    x as i32,
+                    y as i32,
+                    width as i32,
+                    height as i32,
+                    gl::RGBA,
+                    gl::UNSIGNED_BYTE,
+                    pixels.as_ptr() as *const c_void,
+                ),
+                None => gl::TexImage2D(
+                    gl::TEXTURE_2D,
+                    0,
+                    gl::RGBA8 as i32,
+                    width as i32,
+                    height as i32,
+                    0,
+                    gl::RGBA,
+                    gl::UNSIGNED_BYTE,
+                    pixels.as_ptr() as *const c_void,
+                ),
+            }
+            gl::BindTexture(gl::TEXTURE_2D, 0);
+        }
+    }
+
+    // Artık kullanılmayan dokuları siler
+    fn free_textures(&mut self, delta: &TexturesDelta) {
+        for id in &delta.free {
+            if let Some(texture) = self.textures.remove(id) {
+                unsafe {
+                    gl::DeleteTextures(1, &texture);
+                }
+            }
+        }
+    }
+
+    // Üçgenleri kırpma dikdörtgenleriyle, derinlik testi olmadan bağlı çizim hedefine çizer.
+    // `width` ve `height` çizim hedefinin piksel boyutudur.
+    pub fn paint(&mut self, primitives: &[ClippedPrimitive], textures: &TexturesDelta, pixels_per_point: f32, width: u32, height: u32) {
+        self.set_textures(textures);
+
+        let (mut depth_test, mut blend, mut cull_face) = (0, 0, 0);
+        unsafe {
+            gl::GetIntegerv(gl::DEPTH_TEST, &mut depth_test);
+            gl::GetIntegerv(gl::BLEND, &mut blend);
+            gl::GetIntegerv(gl::CULL_FACE, &mut cull_face);
+            gl::Disable(gl::DEPTH_TEST);
+            gl::Disable(gl::CULL_FACE);
+            gl::Enable(gl::SCISSOR_TEST);
+            gl::Enable(gl::BLEND);
+            // egui colors and textures use premultiplied alpha
+            gl::BlendFunc(gl::ONE, gl::ONE_MINUS_SRC_ALPHA);
+
+            gl::BindVertexArray(self.vao);
+            gl::BindBuffer(gl::ARRAY_BUFFER, self.vbo);
+        }
+
+        let projection: Matrix4 = SpriteBatch::screen_projection(width, height) * Matrix4::scale(Vector3D::new(pixels_per_point, pixels_per_point, 1.0));
+        self.shader.use_program();
+        self.shader.set_mat4("projection", &projection);
+        self.shader.set_int("spriteTexture", 0);
+
+        for primitive in primitives {
+            // Paint callbacks are only produced by custom widgets, which this overlay does not use
+            let Primitive::Mesh(mesh) = &primitive.primitive else {
+                continue;
+            };
+            let (Some(scissor), Some(&texture)) = (scissor_rect(primitive.clip_rect, pixels_per_point, width, height), self.textures.get(&mesh.texture_id)) else {
+                continue;
+            };
+
+            let vertices = mesh_vertices(mesh);
+            unsafe {
+                gl::Scissor(scissor[0], scissor[1], scissor[2], scissor[3]);
+                gl::ActiveTexture(gl::TEXTURE0);
+                gl::BindTexture(gl::TEXTURE_2D, texture);
+                gl::BufferData(
+                    gl::ARRAY_BUFFER,
+                    (vertices.len() * mem::size_of::<f32>()) as gl::types::GLsizeiptr,
+                    vertices.as_ptr() as *const c_void,
+                    gl::STREAM_DRAW,
+                );
+                gl::BufferData(
+                    gl::ELEMENT_ARRAY_BUFFER,
+                    (mesh.indices.len() * mem::size_of::<u32>()) as gl::types::GLsizeiptr,
+                    mesh.indices.as_ptr() as *const c_void,
+                    gl::STREAM_DRAW,
+                );
+                gl::DrawElements(gl::TRIANGLES, mesh.indices.len() as i32, gl::UNSIGNED_INT, std::ptr::null());
+            }
+        }
+
+        unsafe {
+            gl::BindVertexArray(0);
+            gl::BindBuffer(gl::ARRAY_BUFFER, 0);
+            gl::BindTexture(gl::TEXTURE_2D, 0);
+            gl::Disable(gl::SCISSOR_TEST);
+            gl::BlendFunc(gl::SRC_ALPHA, gl::ONE_MINUS_SRC_ALPHA);
+            if blend == 0 {
+                gl::Disable(gl::BLEND);
+            }
+            if depth_test != 0 {
+                gl::Enable(gl::DEPTH_TEST);
+            }
+            if cull_face != 0 {
+                gl::Enable(gl::CULL_FACE);
+            }
+        }
+
+        self.free_textures(textures);
+    }
+}
+
+impl Drop for GuiPainter {
+    // Ressam silindiğinde OpenGL kaynaklarını temizler
+    fn drop(&mut self) {
+        unsafe {
+            for texture in self.textures.values() {
+                gl::DeleteTextures(1, texture);
+            }
+            gl::DeleteVertexArrays(1, &self.vao);
+            gl::DeleteBuffers(1, &self.vbo);
+            gl::DeleteBuffers(1, &self.ebo);
+        }
+    }
+}
+
+// Çalışırken sahneyi incelemek ve ayarlamak için pencere içi arayüz (F1 ile açılıp kapanır)
+pub struct Gui {
+    context: egui::Context,
+    input: GuiInput,
+    painter: GuiPainter,
+    start: Instant,
+    visible: bool,
+}
+
+impl Gui {
+    // Gizli bir arayüz oluşturur; OpenGL bağlamı hazır olmalıdır
+    pub fn new() -> Gui {
+        Gui { context: egui::Context::default(), input: GuiInput::default(), painter: GuiPainter::new(), start: Instant::now(), visible: false }
+    }
+
+    // Arayüzü gösterir veya gizler
+    pub fn toggle(&mut self) {
+        self.visible = !self.visible;
+    }
+
+    // Pencere olayını arayüze iletir
+    pub fn handle_event(&mut self, event: &WindowEvent) {
+        if self.visible {
+            self.input.handle_event(event);
+        }
+    }
+
+    // Arayüz fareyi kullanıyorsa (imleç bir panelin üzerindeyse veya bir panel sürükleniyorsa) true döndürür
+    pub fn wants_pointer(&self) -> bool {
+        self.visible && self.context.wants_pointer_input()
+    }
+
+    // Arayüz klavyeyi kullanıyorsa (ör. bir metin kutusu odaktaysa) true döndürür
+    pub fn wants_keyboard(&self) -> bool {
+        self.visible && self.context.wants_keyboard_input()
+    }
+
+    // Paneli çalıştırır ve sonucu bağlı çizim hedefine çizer.
+    // `window_size` pencere koordinatlarında, `framebuffer_size` piksel cinsindendir.
+    pub fn render(&mut self, window_size: (i32, i32), framebuffer_size: (i32, i32), ui: impl FnMut(&egui::Context)) {
+        if !self.visible || window_size.0 <= 0 || framebuffer_size.0 <= 0 {
+            return;
+        }
+        let pixels_per_point = framebuffer_size.0 as f32 / window_size.0 as f32;
+        let input = self.input.take(
+            (window_size.0 as f32, window_size.1 as f32),
+            pixels_per_point,
+            self.start.elapsed().as_secs_f64(),
+        );
+        let output = self.context.run(input, ui);
+        let primitives = self.context.tessellate(output.shapes, output.pixels_per_point);
+        self.painter.paint(
+            &primitives,
+            &output.textures_delta,
+            output.pixels_per_point,
+            framebuffer_size.0 as u32,
+            framebuffer_size.1 as u32,
+        );
+    }
+}
+
+// Vector3D için üç sürüklenebilir sayı alanı gösterir; değer değiştiyse true döndürür
+fn vector_row(ui: &mut egui::Ui, label: &str, value: &mut Vector3D, speed: f32) -> bool {
+    ui.horizontal(|ui| {
+        ui.label(label);
+        let x = ui.add(egui::DragValue::new(&mut value.x).speed(speed).prefix("x "));
+        let y = ui.add(egui::DragValue::new(&mut value.y).speed(speed).prefix("y "));
+        let z = ui.add(egui::DragValue::new(&mut value.z).speed(speed).prefix("z "));
+        x.changed() || y.changed() || z.changed()
+    })
+    .inner
+}
+
+// Vector3D olarak tutulan bir rengi renk seçiciyle gösterir
+fn color_row(ui: &mut egui::Ui, label: &str, value: &mut Vector3D) {
+    ui.horizontal(|ui| {
+        ui.label(label);
+        let mut rgb = [value.x, value.y, value.z];
+        if ui.color_edit_button_rgb(&mut rgb).changed() {
+            *value = Vector3D::new(rgb[0], rgb[1], rgb[2]);
+        }
+    });
+}
+
+// Düğümü ve alt düğümlerini iç içe başlıklar olarak gösterir
+fn node_tree(ui: &mut egui::Ui, scene: &mut Scene, id: NodeId) {
+    let name = scene.node(id).name.clone();
+    egui::CollapsingHeader::new(name).id_salt(id).show(ui, |ui| {
+        let node = scene.node_mut(id);
+        ui.checkbox(&mut node.visible, "Visible");
+        // Edits are applied without interpolation so the node does not slide back from its previous pose
+        let mut transform = node.transform;
+        let moved = vector_row(ui, "Position", &mut transform.translation, 0.05);
+        let turned = vector_row(ui, "Rotation", &mut transform.rotation, 0.01);
+        let scaled = vector_row(ui, "Scale", &mut transform.scale, 0.01);
+        if moved || turned || scaled {
+            node.set_transform(transform);
+        }
+        vector_row(ui, "Spin", &mut node.angular_velocity, 0.01);
+
+        if let Some(material) = node.renderable.as_mut().and_then(|shape| shape.material_mut()) {
+            ui.separator();
+            color_row(ui, "Diffuse", &mut material.diffuse);
+            color_row(ui, "Specular", &mut material.specular);
+            ui.add(egui::Slider::new(&mut material.shininess, 1.0..=256.0).text("Shininess").logarithmic(true));
+        }
+
+        for child in scene.node(id).children().to_vec() {
+            node_tree(ui, scene, child);
+        }
+    });
+}
+
+// Sahne düğümleri, kamera, ışıklar ve kare istatistikleri için panelleri oluşturur
+pub fn inspector(context: &egui::Context, scene: &mut Scene, camera: &mut Camera, lighting: &mut Lighting, stats: &FrameStats) {
+    egui::Window::new("Scene").default_pos([8.0, 32.0]).show(context, |ui| {
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            for root in scene.roots().to_vec() {
+                node_tree(ui, scene, root);
+            }
+        });
+    });
+
+    egui::Window::new("Camera").default_open(false).show(context, |ui| {
+        vector_row(ui, "Position", &mut camera.position, 0.05);
+        vector_row(ui, "Target", &mut camera.target, 0.05);
+        ui.add(egui::Slider::new(&mut camera.fov_y, 10.0..=120.0).text("Field of view"));
+        ui.add(egui::DragValue::new(&mut camera.near).speed(0.01).range(0.001..=camera.far).prefix("near "));
+        ui.add(egui::DragValue::new(&mut camera.far).speed(1.0).range(camera.near..=f32::MAX).prefix("far "));
+    });
+
+    egui::Window::new("Lights").default_open(false).show(context, |ui| {
+        color_row(ui, "Ambient", &mut lighting.ambient);
+        if let Some(light) = &mut lighting.directional {
+            ui.separator();
+            ui.label("Directional");
+            vector_row(ui, "Direction", &mut light.direction, 0.01);
+            color_row(ui, "Color", &mut light.color);
+            ui.add(egui::Slider::new(&mut light.intensity, 0.0..=10.0).text("Intensity"));
+        }
+        for (i, light) in lighting.spots.iter_mut().enumerate() {
+            ui.separator();
+            ui.label(format!("Spot {}", i));
+            vector_row(ui, "Position", &mut light.position, 0.05);
+            vector_row(ui, "Direction", &mut light.direction, 0.01);
+            color_row(ui, "Color", &mut light.color);
+            ui.add(egui::Slider::new(&mut light.intensity, 0.0..=10.0).text("Intensity"));
+            ui.add(egui::Slider::new(&mut light.outer_angle, 1.0..=89.0).text("Outer angle"));
+            ui.add(egui::Slider::new(&mut light.inner_angle, 0.0..=light.outer_angle).text("Inner angle"));
+            ui.add(egui::DragValue::new(&mut light.range).speed(0.1).range(0.0..=f32::MAX).prefix("range "));
+        }
+    });
+
+    egui::Window::new("Stats").default_open(false).show(context, |ui| {
+        ui.label(format!("{:.0} FPS ({:.2} ms)", stats.fps, 1000.0 / stats.fps.max(f32::EPSILON)));
+        ui.label(format!("{} visible nodes, {} shapes", stats.nodes, stats.shapes));
+        ui.label(format!("{} debug lines", stats.debug_lines));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn window_events_become_egui_events() {
+        let mut input = GuiInput::default();
+        input.handle_event(&WindowEvent::CursorPos(10.0, 20.0));
+        input.handle_event(&WindowEvent::MouseButton(glfw::MouseButtonLeft, Action::Press, glfw::Modifiers::Shift));
+        input.handle_event(&WindowEvent::Char('a'));
+        input.handle_event(&WindowEvent::Char('\u{8}'));
+        input.handle_event(&WindowEvent::Key(glfw::Key::F5, 0, Action::Press, glfw::Modifiers::empty()));
+        input.handle_event(&WindowEvent::Key(glfw::Key::Backspace, 0, Action::Repeat, glfw::Modifiers::empty()));
+
+        let raw = input.take((800.0, 600.0), 2.0, 1.0);
+        assert_eq!(raw.events.len(), 4);
+        assert_eq!(raw.events[0], egui::Event::PointerMoved(Pos2::new(10.0, 20.0)));
+        match &raw.events[1] {
+            egui::Event::PointerButton { pos, pressed, modifiers, .. } => {
+                assert_eq!(*pos, Pos2::new(10.0, 20.0));
+                assert!(*pressed && modifiers.shift);
+            }
+            event => panic!("unexpected event {:?}", event),
+        }
+        assert_eq!(raw.events[2], egui::Event::Text("a".to_string()));
+        assert!(matches!(raw.events[3], egui::Event::Key { key: egui::Key::Backspace, pressed: true, repeat: true, .. }));
+        assert_eq!(raw.viewports[&ViewportId::ROOT].native_pixels_per_point, Some(2.0));
+        assert!(input.take((800.0, 600.0), 2.0, 2.0).events.is_empty());
+    }
+
+    #[test]
+    fn clip_rects_become_scissor_rects() {
+        let clip = egui::Rect::from_min_max(Pos2::new(10.0, 20.0), Pos2::new(110.0, 70.0));
+        assert_eq!(scissor_rect(clip, 2.0, 800, 600), Some([20, 460, 200, 100]));
+
+        let outside = egui::Rect::from_min_max(Pos2::new(500.0, 0.0), Pos2::new(600.0, 10.0));
+        assert_eq!(scissor_rect(outside, 2.0, 800, 600), None);
+    }
+
+    #[test]
+    fn inspector_lays_out_without_gl() {
+        let mut scene = Scene::new();
+        let parent = scene.add_node("parent", None);
+        scene.add_node("child", Some(parent));
+        let mut camera = Camera::new(Vector3D::new(0.0, 0.0, 5.0), Vector3D::zero(), 1.0);
+        let mut lighting = Lighting::new(Vector3D::new(0.1, 0.1, 0.1));
+        let stats = FrameStats { fps: 60.0, nodes: 2, shapes: 0, debug_lines: 0 };
+
+        // Windows are measured invisibly on their first frame, so only the second one paints them
+        let context = egui::Context::default();
+        let mut input = GuiInput::default();
+        let first = context.run(input.take((800.0, 600.0), 1.0, 0.0), |ctx| {
+            inspector(ctx, &mut scene, &mut camera, &mut lighting, &stats)
+        });
+        assert!(!first.textures_delta.set.is_empty());
+        let output = context.run(input.take((800.0, 600.0), 1.0, 0.1), |ctx| {
+            inspector(ctx, &mut scene, &mut camera, &mut lighting, &stats)
+        });
+        let primitives = context.tessellate(output.shapes, output.pixels_per_point);
+        let vertices: usize = primitives
+            .iter()
+            .map(|primitive| match &primitive.primitive {
+                Primitive::Mesh(mesh) => mesh_vertices(mesh).len(),
+                Primitive::Callback(_) => 0,
+            })
+            .sum();
+        assert!(vertices > 0 && vertices.is_multiple_of(VERTEX_FLOATS));
+    }
+}
@@ -0,0 +1,369 @@
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::ffi::c_void;
+use std::mem;
+use std::rc::Rc;
+
+use crate::draw::{RenderContext, Shape};
+use crate::material::Material;
+use crate::matrix4::Matrix4;
+use crate::mesh::{Mesh, MeshData};
+use crate::shader::Shader;
+
+// Bir örneğin GPU'daki kayan nokta sayısı: dönüşüm (16), renk (4), özel veri (4)
+const INSTANCE_FLOATS: usize = 24;
+
+// Örnek özelliklerini okuyan vertex shader dosyası
+pub const VERTEX_SHADER: &str = "instanced_vertex_shader.glsl";
+
+// İlk örnek özelliğinin konumu; 0-2 mesh köşe özellikleri için ayrılmıştır
+const FIRST_INSTANCE_ATTRIBUTE: u32 = 3;
+
+// Tek bir örneğin verisi. Renk malzemenin diffuse rengiyle çarpılır,
+// özel veri ise shader'a yorumlanmadan iletilir.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Instance {
+    pub transform: Matrix4,
+    pub color: [f32; 4],
+    pub data: [f32; 4],
+}
+
+impl Instance {
+    // Beyaz renkli ve özel verisi sıfır olan bir örnek oluşturur
+    pub fn new(transform: Matrix4) -> Instance {
+        Instance { transform, color: [1.0; 4], data: [0.0; 4] }
+    }
+
+    // Rengi değiştirilmiş bir kopya döndürür
+    pub fn with_color(mut self, color: [f32; 4]) -> Instance {
+        self.color = color;
+        self
+    }
+
+    // Özel verisi değiştirilmiş bir kopya döndürür
+    pub fn with_data(mut self, data: [f32; 4]) -> Instance {
+        self.data = data;
+        self
+    }
+}
+
+// Bir örneği kümede tanımlayan kimlik. Silinen örneklerin kimlikleri yeniden kullanılmaz.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InstanceId(u64);
+
+// Örneklerin bitişik tutulduğu küme. Silme son örneği boşluğa taşır,
+// böylece veri tek parça halinde GPU'ya yüklenebilir.
+#[derive(Debug, Default)]
+pub struct InstanceSet {
+    instances: Vec<Instance>,
+    ids: Vec<InstanceId>,
+    slots: HashMap<InstanceId, usize>,
+    next_id: u64,
+}
+
+impl InstanceSet {
+    // Boş bir küme oluşturur
+    pub fn new() -> InstanceSet {
+        InstanceSet::default()
+    }
+
+    // Örneği ekler ve kimliğini döndürür
+    pub fn add(&mut self, instance: Instance) -> InstanceId {
+        let id = InstanceId(self.next_id);
+        self.next_id += 1;
+        self.slots.insert(id, self.instances.len());
+        self.instances.push(instance);
+        self.ids.push(id);
+        id
+    }
+
+    // Örneği döndürür
+    pub fn get(&self, id: InstanceId) -> Option<&Instance> {
+        self.slots.get(&id).map(|&slot| &self.instances[slot])
+    }
+
+    // Örneği değiştirir; kimlik kümede yoksa false döndürür
+    pub fn update(&mut self, id: InstanceId, instance: Instance) -> bool {
+        match self.slots.get(&id) {
+            Some(&slot) => {
+                self.instances[slot] = instance;
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Örneği siler ve döndürür
+    pub fn remove(&mut self, id: InstanceId) -> Option<Instance> {
+        let slot = self.slots.remove(&id)?;
+        self.ids.swap_remove(slot);
+        if let Some(&moved) = self.ids.get(slot) {
+            self.slots.insert(moved, slot);
+        }
+        Some(self.instances.swap_remove(slot))
+    }
+
+    // Örnek sayısını döndürür
+    pub fn len(&self) -> usize {
+        self.instances.len()
+    }
+
+    // Küme boşsa true döndürür
+    pub fn is_empty(&self) -> bool {
+        self.instances.is_empty()
+    }
+
+    // Tüm örnekleri siler
+    pub fn clear(&mut self) {
+        self.instances.clear();
+        self.ids.clear();
+        self.slots.clear();
+    }
+
+    // Örnekleri kimlikleriyle birlikte dolaşır
+    pub fn iter(&self) -> impl Iterator<Item = (InstanceId, &Instance)> {
+        self.ids.iter().copied().zip(self.instances.iter())
+    }
+
+    // Örnekleri GPU'ya yüklenecek biçimde (dönüşüm, renk, özel veri) art arda dizer
+    pub fn as_floats(&self) -> Vec<f32> {
+        let mut floats = Vec::with_capacity(self.instances.len() * INSTANCE_FLOATS);
+        for instance in &self.instances {
+            floats.extend_from_slice(&instance.transform.data);
+            floats.extend_from_slice(&instance.color);
+            floats.extend_from_slice(&instance.data);
+        }
+        floats
+    }
+}
+
+// Paylaşılan bir mesh'in tüm örneklerini tek çizim çağrısıyla çizen şekil.
+// Örnek tamponu yalnızca örnekler değiştiğinde, bir sonraki çizimde yeniden yüklenir.
+pub struct InstancedMesh {
+    mesh: Rc<Mesh>,
+    shader: Rc<Shader>,
+    pub material: Material,
+    instances: InstanceSet,
+    vao: gl::types::GLuint,
+    instance_vbo: gl::types::GLuint,
+    capacity: Cell<usize>,
+    dirty: Cell<bool>,
+}
+
+impl InstancedMesh {
+    // Yeni bir örneklenmiş mesh oluşturur; shader instanced_vertex_shader.glsl ile kurulmalıdır
+    pub fn new(mesh: Rc<Mesh>, shader: Rc<Shader>, material: Material) -> InstancedMesh {
+        InstancedMesh {
+            mesh,
+            shader,
+            material,
+            instances: InstanceSet::new(),
+            vao: 0,
+            instance_vbo: 0,
+            capacity: Cell::new(0),
+            dirty: Cell::new(true),
+        }
+    }
+
+    // Bir şeklin geometrisinden (ör. Circle) örneklenmiş mesh oluşturur
+    pub fn from_shape(shape: &dyn Shape, shader: Rc<Shader>, material: Material) -> Option<InstancedMesh> {
+        let data = shape.mesh_data()?;
+        Some(InstancedMesh::new(Rc::new(Mesh::new(&data)), shader, material))
+    }
+
+    // Örnekleri döndürür
+    pub fn instances(&self) -> &InstanceSet {
+        &self.instances
+    }
+
+    // Örnekleri değiştirmek için döndürür; tampon bir sonraki çizimde yeniden yüklenir
+    pub fn instances_mut(&mut self) -> &mut InstanceSet {
+        self.dirty.set(true);
+        &mut self.instances
+    }
+
+    // Örnek ekler
+    pub fn add(&mut self, instance: Instance) -> InstanceId {
+        self.instances_mut().add(instance)
+    }
+
+    // Örneği değiştirir
+    pub fn update(&mut self, id: InstanceId, instance: Instance) -> bool {
+        self.instances_mut().update(id, instance)
+    }
+
+    // Örneği siler
+    pub fn remove(&mut self, id: InstanceId) -> Option<Instance> {
+        self.instances_mut().remove(id)
+    }
+
+    // Örnek verisini GPU'ya yükler; kapasite yetmezse tampon büyütülür
+    fn upload(&self) {
+        let floats = self.instances.as_floats();
+        let bytes = (floats.len() * mem::size_of::<f32>()) as gl::types::GLsizeiptr;
+        unsafe {
+            gl::BindBuffer(gl::ARRAY_BUFFER, self.instance_vbo);
+            if self.instances.len() > self.capacity.get() {
+                // Grow geometrically so that adding one instance per frame does not reallocate every frame
+                let capacity = self.instances.len().max(self.capacity.get() * 2);
+                let size = (capacity * INSTANCE_FLOATS * mem::size_of::<f32>()) as gl::types::GLsizeiptr;
+                gl::BufferData(gl::ARRAY_BUFFER, size, std::ptr::null(), gl::DYNAMIC_DRAW);
+                self.capacity.set(capacity);
+            }
+            if !floats.is_empty() {
+                gl::BufferSubData(gl::ARRAY_BUFFER, 0, bytes, floats.as_ptr() as *const c_void);
+            }
+            gl::BindBuffer(gl::ARRAY_BUFFER, 0);
+        }
+        self.dirty.set(false);
+    }
+
+    // Bağlı shader ile bütün örnekleri tek çizim çağrısıyla çizer
+    fn draw_instances(&self) {
+        unsafe {
+            gl::BindVertexArray(self.vao);
+            gl::DrawElementsInstanced(
+                gl::TRIANGLES,
+                self.mesh.index_count(),
+                gl::UNSIGNED_INT,
+                std::ptr::null(),
+                self.instances.len() as gl::types::GLsizei,
+            );
+            gl::BindVertexArray(0);
+        }
+    }
+}
+
+impl Shape for InstancedMesh {
+    // Mesh'in köşe tamponlarını ve örnek tamponunu kendi VAO'sunda birleştirir
+    fn init(&mut self) {
+        let stride = (INSTANCE_FLOATS * mem::size_of::<f32>()) as gl::types::GLsizei;
+        let float = mem::size_of::<f32>();
+
+        unsafe {
+            gl::GenVertexArrays(1, &mut self.vao);
+            gl::GenBuffers(1, &mut self.instance_vbo);
+
+            gl::BindVertexArray(self.vao);
+            self.mesh.bind_vertex_layout();
+
+            gl::BindBuffer(gl::ARRAY_BUFFER, self.instance_vbo);
+            // A mat4 attribute takes four consecutive locations, one per column; color and data follow
+            for i in 0..6 {
+                let location = FIRST_INSTANCE_ATTRIBUTE + i;
+                gl::VertexAttribPointer(location, 4, gl::FLOAT, gl::FALSE, stride, (i as usize * 4 * float) as *const c_void);
+                gl::EnableVertexAttribArray(location);
+                gl::VertexAttribDivisor(location, 1);
+            }
+
+            gl::BindVertexArray(0);
+            gl::BindBuffer(gl::ARRAY_BUFFER, 0);
+            gl::BindBuffer(gl::ELEMENT_ARRAY_BUFFER, 0);
+        }
+        self.dirty.set(true);
+    }
+
+    // Tüm örnekleri tek çağrıda çizer; düğüm matrisi her örneğin dönüşümünden önce uygulanır
+    fn draw(&self, ctx: &RenderContext, model: &Matrix4) {
+        if self.dirty.get() {
+            self.upload();
+        }
+        if self.instances.is_empty() {
+            return;
+        }
+
+        self.shader.use_program();
+        ctx.apply(&self.shader);
+        self.shader.set_mat4("model", model);
+        self.material.apply(&self.shader, ctx);
+        self.draw_instances();
+    }
+
+    // Tüm örnekleri gölge haritasına tek çağrıda çizer; derinlik shader'ı örnek dönüşümlerini instanced ile okur
+    fn draw_depth(&self, depth_shader: &Shader, model: &Matrix4) {
+        if self.dirty.get() {
+            self.upload();
+        }
+        if self.instances.is_empty() {
+            return;
+        }
+
+        depth_shader.set_mat4("model", model);
+        depth_shader.set_bool("instanced", true);
+        self.draw_instances();
+        depth_shader.set_bool("instanced", false);
+    }
+
+    // Tüm örnekleri tek mesh olarak döndürür
+    fn mesh_data(&self) -> Option<MeshData> {
+        let mut merged = MeshData::default();
+        for (_, instance) in self.instances.iter() {
+            merged.append(&self.mesh.data().transformed(&instance.transform));
+        }
+        Some(merged)
+    }
+
+    // Şeklin malzemesini döndürür
+    fn material_mut(&mut self) -> Option<&mut Material> {
+        Some(&mut self.material)
+    }
+}
+
+impl Drop for InstancedMesh {
+    // Örneklenmiş mesh silindiğinde OpenGL kaynaklarını temizler
+    fn drop(&mut self) {
+        unsafe {
+            gl::DeleteVertexArrays(1, &self.vao);
+            gl::DeleteBuffers(1, &self.instance_vbo);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector3d::Vector3D;
+
+    fn at(x: f32) -> Instance {
+        Instance::new(Matrix4::translation(Vector3D::new(x, 0.0, 0.0)))
+    }
+
+    #[test]
+    fn removal_keeps_other_ids_valid() {
+        let mut set = InstanceSet::new();
+        let a = set.add(at(1.0));
+        let b = set.add(at(2.0));
+        let c = set.add(at(3.0));
+
+        assert_eq!(set.remove(a), Some(at(1.0)));
+        assert_eq!(set.remove(a), None);
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.get(b), Some(&at(2.0)));
+        assert_eq!(set.get(c), Some(&at(3.0)));
+
+        assert!(set.update(c, at(4.0)));
+        assert!(!set.update(a, at(5.0)));
+        assert_eq!(set.get(c), Some(&at(4.0)));
+
+        // Ids are never reused, even after the set is emptied
+        set.clear();
+        let d = set.add(at(6.0));
+        assert!(d != a && d != b && d != c);
+        assert_eq!(set.iter().map(|(id, _)| id).collect::<Vec<_>>(), vec![d]);
+    }
+
+    #[test]
+    fn floats_are_packed_per_instance() {
+        let mut set = InstanceSet::new();
+        set.add(at(1.0).with_color([0.5, 0.5, 0.5, 1.0]));
+        set.add(at(2.0).with_data([7.0, 0.0, 0.0, 0.0]));
+
+        let floats = set.as_floats();
+        assert_eq!(floats.len(), 2 * INSTANCE_FLOATS);
+        // Translation lives in the fourth column of the column-major matrix
+        assert_eq!(floats[12], 1.0);
+        assert_eq!(&floats[16..20], &[0.5, 0.5, 0.5, 1.0]);
+        assert_eq!(floats[INSTANCE_FLOATS + 12], 2.0);
+        assert_eq!(floats[INSTANCE_FLOATS + 20], 7.0);
+    }
+}
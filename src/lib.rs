@@ -0,0 +1,48 @@
+// Most types own OpenGL objects and are only created explicitly with new() once a context exists
+#![allow(clippy::new_without_default)]
+
+extern crate glfw;  
+extern crate gl;    
+
+pub mod window;
+pub mod app;
+pub mod shader;
+pub mod draw;
+pub mod circle;
+pub mod triangle;
+pub mod rectangle;
+pub mod vector2d;
+pub mod vector3d;  // Add this line
+pub mod cube;  // Add this line
+pub mod plane;
+pub mod matrix4;
+pub mod camera;
+pub mod material;
+pub mod light;
+pub mod shadow;
+pub mod texture;
+pub mod framebuffer;
+pub mod postprocess;
+pub mod capture;
+pub mod timing;
+pub mod scene;
+pub mod scene_file;
+pub mod cli;
+pub mod locator;
+pub mod mesh;
+pub mod obj;
+pub mod gltf_import;
+pub mod export;
+pub mod assets;
+pub mod instancing;
+pub mod sprite_batch;
+pub mod atlas;
+pub mod sprite_sheet;
+pub mod text;
+pub mod debug_draw;
+pub mod gui;
+pub mod path2d;
+pub mod polygon;
+pub mod color;
+#[cfg(test)]
+mod golden;
@@ -0,0 +1,112 @@
+use crate::draw::RenderContext;
+use crate::matrix4::Matrix4;
+use crate::shader::Shader;
+use crate::shadow::ShadowSettings;
+use crate::vector3d::Vector3D;
+
+// Lit shader'ındaki spot ışık dizisinin boyutu
+pub const MAX_SPOT_LIGHTS: usize = 4;
+
+pub struct DirectionalLight {
+    pub direction: Vector3D,
+    pub color: Vector3D,
+    pub intensity: f32,
+    pub shadow: Option<ShadowSettings>,
+}
+
+impl DirectionalLight {
+    // Yeni bir yönlü ışık oluşturur
+    pub fn new(direction: Vector3D, color: Vector3D, intensity: f32) -> DirectionalLight {
+        DirectionalLight { direction: direction.normalize(), color, intensity, shadow: None }
+    }
+
+    // Işığın gölge düşürmesini sağlar
+    pub fn with_shadow(mut self, settings: ShadowSettings) -> DirectionalLight {
+        self.shadow = Some(settings);
+        self
+    }
+}
+
+pub struct SpotLight {
+    pub position: Vector3D,
+    pub direction: Vector3D,
+    pub color: Vector3D,
+    pub intensity: f32,
+    pub inner_angle: f32,  // Degrees
+    pub outer_angle: f32,  // Degrees
+    pub range: f32,
+    pub shadow: Option<ShadowSettings>,
+}
+
+impl SpotLight {
+    // Yeni bir spot ışık oluşturur
+    pub fn new(position: Vector3D, direction: Vector3D, color: Vector3D, inner_angle: f32, outer_angle: f32) -> SpotLight {
+        SpotLight {
+            position,
+            direction: direction.normalize(),
+            color,
+            intensity: 1.0,
+            inner_angle,
+            outer_angle,
+            range: 20.0,
+            shadow: None,
+        }
+    }
+
+    // Işığın gölge düşürmesini sağlar
+    pub fn with_shadow(mut self, settings: ShadowSettings) -> SpotLight {
+        self.shadow = Some(settings);
+        self
+    }
+
+    // Işığın bakış açısından görünüm-izdüşüm matrisini döndürür
+    pub fn light_space_matrix(&self) -> Matrix4 {
+        let up = if self.direction.y.abs() > 0.99 {
+            Vector3D::new(1.0, 0.0, 0.0)
+        } else {
+            Vector3D::new(0.0, 1.0, 0.0)
+        };
+        let view = Matrix4::look_at(self.position, self.position + self.direction, up);
+        let projection = Matrix4::perspective((self.outer_angle * 2.0).to_radians(), 1.0, 0.05, self.range);
+        projection * view
+    }
+}
+
+pub struct Lighting {
+    pub ambient: Vector3D,
+    pub directional: Option<DirectionalLight>,
+    pub spots: Vec<SpotLight>,
+}
+
+impl Lighting {
+    // Boş bir ışık kümesi oluşturur
+    pub fn new(ambient: Vector3D) -> Lighting {
+        Lighting { ambient, directional: None, spots: Vec::new() }
+    }
+
+    // Işık parametrelerini shader'a yükler; renkler ctx.color gibi gerekirse doğrusal renge çevrilir
+    pub fn apply(&self, shader: &Shader, ctx: &RenderContext) {
+        shader.set_vec3("ambient", ctx.rgb(self.ambient));
+
+        match &self.directional {
+            Some(light) => {
+                shader.set_bool("dirLight.enabled", true);
+                shader.set_vec3("dirLight.direction", light.direction);
+                shader.set_vec3("dirLight.color", ctx.rgb(light.color) * light.intensity);
+            }
+            None => shader.set_bool("dirLight.enabled", false),
+        }
+
+        let count = self.spots.len().min(MAX_SPOT_LIGHTS);
+        shader.set_int("spotLightCount", count as i32);
+        for (i, light) in self.spots.iter().take(count).enumerate() {
+            let prefix = format!("spotLights[{}]", i);
+            shader.set_vec3(&format!("{}.position", prefix), light.position);
+            shader.set_vec3(&format!("{}.direction", prefix), light.direction);
+            shader.set_vec3(&format!("{}.color", prefix), ctx.rgb(light.color) * light.intensity);
+            shader.set_float(&format!("{}.innerCos", prefix), light.inner_angle.to_radians().cos());
+            shader.set_float(&format!("{}.outerCos", prefix), light.outer_angle.to_radians().cos());
+            shader.set_float(&format!("{}.range", prefix), light.range);
+        }
+    }
+}
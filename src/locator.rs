@@ -0,0 +1,219 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+// Ek varlık (asset) klasörlerini listeleyen ortam değişkeni (PATH gibi ayraçla ayrılır)
+pub const ASSET_PATH_ENV: &str = "RENDERER_ASSET_PATH";
+
+// `embedded-assets` özelliğiyle derlendiğinde varsayılan shader'lar ve demo sahnesi programın içine gömülür.
+// Shader'lar yalnızca dosya adlarıyla bulunur, böylece eksik dosyaları olan bir shader klasörünü de tamamlarlar.
+macro_rules! embedded_shaders {
+    ($($name:literal),* $(,)?) => {
+        #[cfg(feature = "embedded-assets")]
+        const EMBEDDED_SHADERS: &[(&str, &str)] = &[$(($name, include_str!(concat!("Shaders/", $name))),)*];
+
+        #[cfg(not(feature = "embedded-assets"))]
+        const EMBEDDED_SHADERS: &[(&str, &str)] = &[];
+
+        // Compared with the contents of src/Shaders by the tests
+        #[cfg(test)]
+        const SHADER_NAMES: &[&str] = &[$($name,)*];
+    };
+}
+
+embedded_shaders!(
+    "bloom_combine_fragment_shader.glsl",
+    "bloom_extract_fragment_shader.glsl",
+    "blur_fragment_shader.glsl",
+    "color_filter_fragment_shader.glsl",
+    "color_fragment_shader.glsl",
+    "color_grading_fragment_shader.glsl",
+    "color_vertex_shader.glsl",
+    "debug_fragment_shader.glsl",
+    "debug_vertex_shader.glsl",
+    "depth_fragment_shader.glsl",
+    "depth_vertex_shader.glsl",
+    "fullscreen_vertex_shader.glsl",
+    "fxaa_fragment_shader.glsl",
+    "gamma_fragment_shader.glsl",
+    "gradient_fragment_shader.glsl",
+    "instanced_vertex_shader.glsl",
+    "lit_fragment_shader.glsl",
+    "lit_vertex_shader.glsl",
+    "normal_fragment_shader.glsl",
+    "orange_fragment_shader.glsl",
+    "red_fragment_shader.glsl",
+    "sprite_fragment_shader.glsl",
+    "sprite_vertex_shader.glsl",
+    "text_sdf_fragment_shader.glsl",
+    "texture_fragment_shader.glsl",
+    "tonemap_fragment_shader.glsl",
+    "vertex_shader.glsl",
+    "vignette_fragment_shader.glsl",
+);
+
+#[cfg(feature = "embedded-assets")]
+const EMBEDDED_FILES: &[(&str, &str)] = &[("scenes/demo.ron", include_str!("../scenes/demo.ron"))];
+
+#[cfg(not(feature = "embedded-assets"))]
+const EMBEDDED_FILES: &[(&str, &str)] = &[];
+
+// Göreli varlık yollarını sırasıyla denenen kök klasörlere göre çözen bulucu
+#[derive(Debug, Clone)]
+pub struct AssetLocator {
+    roots: Vec<PathBuf>,
+}
+
+impl AssetLocator {
+    // Verilen kök klasörlerle bir bulucu oluşturur
+    pub fn new(roots: Vec<PathBuf>) -> AssetLocator {
+        AssetLocator { roots }
+    }
+
+    // Kökleri öncelik sırasıyla oluşturur: komut satırı klasörleri, RENDERER_ASSET_PATH,
+    // çalışma klasörü ve çalıştırılabilir dosyanın klasörü
+    pub fn from_environment(extra_roots: &[PathBuf]) -> AssetLocator {
+        let mut roots = extra_roots.to_vec();
+        if let Some(paths) = env::var_os(ASSET_PATH_ENV) {
+            roots.extend(env::split_paths(&paths).filter(|path| !path.as_os_str().is_empty()));
+        }
+        roots.push(PathBuf::from("."));
+        if let Some(directory) = env::current_exe().ok().and_then(|exe| exe.parent().map(Path::to_path_buf)) {
+            roots.push(directory);
+        }
+        AssetLocator { roots }
+    }
+
+    // Yolu diskte bulur; mutlak yollar olduğu gibi, göreli yollar ilk eşleşen köke göre döndürülür
+    pub fn resolve<P: AsRef<Path>>(&self, path: P) -> Option<PathBuf> {
+        let path = path.as_ref();
+        if path.is_absolute() {
+            return path.exists().then(|| path.to_path_buf());
+        }
+        self.roots.iter().map(|root| root.join(path)).find(|candidate| candidate.exists())
+    }
+
+    // Varlığın diskte veya gömülü varlıklar arasında bulunup bulunmadığını döndürür
+    pub fn exists<P: AsRef<Path>>(&self, path: P) -> bool {
+        self.resolve(&path).is_some() || embedded(path.as_ref()).is_some()
+    }
+
+    // Metin varlığını okur. Diskteki dosyalar gömülü kopyalardan önceliklidir,
+    // böylece shader'lar yeniden derlemeden düzenlenebilir.
+    pub fn read_to_string<P: AsRef<Path>>(&self, path: P) -> Result<String, String> {
+        let path = path.as_ref();
+        match self.resolve(path) {
+            Some(resolved) => fs::read_to_string(&resolved).map_err(|e| format!("{}: {}", resolved.display(), e)),
+            None => embedded(path).map(str::to_string).ok_or_else(|| {
+                let roots: Vec<String> = self.roots.iter().map(|root| root.display().to_string()).collect();
+                format!("{}: not found in any asset directory (searched {})", path.display(), roots.join(", "))
+            }),
+        }
+    }
+}
+
+// Gömülü varlığın içeriğini döndürür; shader'lar hangi klasörde aranırsa aransın dosya adlarıyla eşleşir
+fn embedded(path: &Path) -> Option<&'static str> {
+    let key = path.to_str()?.replace('\\', "/");
+    let key = key.strip_prefix("./").unwrap_or(&key);
+    let name = path.file_name()?.to_str()?;
+    EMBEDDED_FILES
+        .iter()
+        .find(|(file, _)| *file == key)
+        .or_else(|| EMBEDDED_SHADERS.iter().find(|(file, _)| *file == name))
+        .map(|(_, source)| *source)
+}
+
+// Program genelinde kullanılan bulucu; ilk kullanımda ortamdan oluşturulur
+static LOCATOR: Mutex<Option<AssetLocator>> = Mutex::new(None);
+
+// Program genelindeki bulucuyu verilen ek kök klasörlerle yeniden oluşturur
+pub fn configure(extra_roots: &[PathBuf]) {
+    *LOCATOR.lock().unwrap() = Some(AssetLocator::from_environment(extra_roots));
+}
+
+// Program genelindeki bulucuyla bir işlem yapar
+fn with_locator<T>(f: impl FnOnce(&AssetLocator) -> T) -> T {
+    let mut locator = LOCATOR.lock().unwrap();
+    f(locator.get_or_insert_with(|| AssetLocator::from_environment(&[])))
+}
+
+// Göreli varlık yolunu program genelindeki kök klasörlere göre çözer
+pub fn resolve<P: AsRef<Path>>(path: P) -> Option<PathBuf> {
+    with_locator(|locator| locator.resolve(path))
+}
+
+// Varlığın diskte veya gömülü olarak bulunup bulunmadığını döndürür
+pub fn exists<P: AsRef<Path>>(path: P) -> bool {
+    with_locator(|locator| locator.exists(path))
+}
+
+// Metin varlığını program genelindeki bulucuyla okur
+pub fn read_to_string<P: AsRef<Path>>(path: P) -> Result<String, String> {
+    with_locator(|locator| locator.read_to_string(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_root(name: &str) -> PathBuf {
+        let root = env::temp_dir().join(format!("locator_test_{}_{}", std::process::id(), name));
+        fs::create_dir_all(root.join("shaders")).unwrap();
+        root
+    }
+
+    #[test]
+    fn earlier_roots_take_priority() {
+        let first = temp_root("first");
+        let second = temp_root("second");
+        fs::write(first.join("shaders/a.glsl"), "first").unwrap();
+        fs::write(second.join("shaders/a.glsl"), "second").unwrap();
+        fs::write(second.join("shaders/b.glsl"), "only second").unwrap();
+
+        let locator = AssetLocator::new(vec![first.clone(), second.clone()]);
+        assert_eq!(locator.read_to_string("shaders/a.glsl").unwrap(), "first");
+        assert_eq!(locator.read_to_string("shaders/b.glsl").unwrap(), "only second");
+        assert_eq!(locator.resolve("shaders/b.glsl"), Some(second.join("shaders/b.glsl")));
+    }
+
+    #[test]
+    fn missing_assets_report_searched_roots() {
+        let root = temp_root("missing");
+        let locator = AssetLocator::new(vec![root.clone()]);
+        assert!(!locator.exists("shaders/none.glsl"));
+        let error = locator.read_to_string("shaders/none.glsl").unwrap_err();
+        assert!(error.contains(&root.display().to_string()), "{}", error);
+    }
+
+    #[test]
+    fn every_shader_is_embedded() {
+        let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("src").join("Shaders");
+        let mut files: Vec<String> = fs::read_dir(directory)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        files.sort();
+        assert_eq!(files, SHADER_NAMES);
+    }
+
+    #[cfg(feature = "embedded-assets")]
+    #[test]
+    fn embedded_shaders_fill_in_any_shader_directory() {
+        let locator = AssetLocator::new(vec![temp_root("embedded")]);
+        assert!(locator.exists("shaders/vertex_shader.glsl"));
+        assert!(locator.read_to_string("src/Shaders/vertex_shader.glsl").unwrap().contains("gl_Position"));
+        // Other embedded files keep their full path
+        assert!(!locator.exists("other/demo.ron"));
+    }
+
+    #[test]
+    fn absolute_paths_ignore_roots() {
+        let root = temp_root("absolute");
+        let file = root.join("shaders/abs.glsl");
+        fs::write(&file, "absolute").unwrap();
+        let locator = AssetLocator::new(Vec::new());
+        assert_eq!(locator.read_to_string(&file).unwrap(), "absolute");
+    }
+}
@@ -1,26 +1,87 @@
-extern crate glfw;  
-extern crate gl;    
-
-mod window;
-mod app;
-mod shader;
-mod draw;
-mod circle;
-mod triangle;
-mod rectangle;
-mod vector2d;
-mod vector3d;  // Add this line
-mod cube;  // Add this line
-
-use app::Application;
+use std::path::Path;
+
+use opengl_renderer::app::Application;
+use opengl_renderer::cli::{self, Command};
+use opengl_renderer::{locator, shader};
 
 fn main() {
+    let options = match cli::parse(std::env::args().skip(1)) {
+        Ok(Command::Run(options)) => options,
+        Ok(Command::Help) => {
+            print!("{}", cli::USAGE);
+            return;
+        }
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    locator::configure(&options.asset_dirs);
+    if let Some(directory) = &options.shader_dir {
+        shader::set_directory(directory);
+    }
+
+    if let Some(export) = &options.export {
+        let mut app = new_headless(&options);
+        load_scene(&mut app, &options.scene);
+        init_gl(&mut app);
+        match app.export_scene(&export.path, export.format) {
+            Ok(count) => println!("Exported {} objects to {}", count, export.path.display()),
+            Err(e) => {
+                eprintln!("Failed to export scene: {}", e);
+                std::process::exit(1);
+            }
+        }
+        app.cleanup();
+        return;
+    }
+
+    if let Some(headless) = &options.headless {
+        let mut app = new_headless(&options);
+        load_scene(&mut app, &options.scene);
+        init_gl(&mut app);
+        if let Err(e) = app.run_headless(headless.frames, &headless.output) {
+            eprintln!("Failed to save the captured frame: {}", e);
+            std::process::exit(1);
+        }
+        println!("Saved frame to {}", headless.output.display());
+        return;
+    }
+
     // Create a new instance of the Application
-    let mut app = Application::new();
+    let mut app = Application::new(&options.settings);
+
+    // Read the scene file before creating any OpenGL resources so errors are reported early
+    load_scene(&mut app, &options.scene);
 
     // Initialize OpenGL settings, such as clear color, context setup
-    app.init_gl();
+    init_gl(&mut app);
 
     // Start the main loop that handles rendering and input events
     app.run();
 }
+
+// Ekransız bir uygulama oluşturur, OpenGL bağlamı oluşturulamazsa hatayı yazdırıp programdan çıkar
+fn new_headless(options: &cli::CliOptions) -> Application {
+    Application::new_headless(&options.settings, options.context_api).unwrap_or_else(|e| {
+        eprintln!("Failed to create a headless OpenGL context: {}", e);
+        std::process::exit(1);
+    })
+}
+
+// Sahne dosyasını yükler, hatalıysa hatayı yazdırıp programdan çıkar
+fn load_scene(app: &mut Application, path: &Path) {
+    if let Err(e) = app.load_scene(path) {
+        eprintln!("Failed to load scene: {}", e);
+        std::process::exit(1);
+    }
+}
+
+// OpenGL'i başlatır, çizim hedefleri veya sahnenin varlıkları oluşturulamazsa hatayı yazdırıp programdan çıkar
+fn init_gl(app: &mut Application) {
+    if let Err(e) = app.init_gl() {
+        eprintln!("Failed to initialize the scene: {}", e);
+        std::process::exit(1);
+    }
+}
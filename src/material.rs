@@ -0,0 +1,37 @@
+use crate::draw::RenderContext;
+use crate::shader::Shader;
+use crate::vector3d::Vector3D;
+
+// Diffuse dokusunun bağlandığı doku birimi; gölge haritaları 8 ve 9'u kullanır
+pub const DIFFUSE_MAP_UNIT: u32 = 0;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Material {
+    pub diffuse: Vector3D,
+    pub specular: Vector3D,
+    pub shininess: f32,
+}
+
+impl Material {
+    // Yeni bir malzeme oluşturur
+    pub fn new(diffuse: Vector3D, specular: Vector3D, shininess: f32) -> Material {
+        Material { diffuse, specular, shininess }
+    }
+
+    // Malzeme değerlerini shader'a yükler; renkler ctx.color gibi gerekirse doğrusal renge çevrilir
+    pub fn apply(&self, shader: &Shader, ctx: &RenderContext) {
+        shader.set_vec3("material.diffuse", ctx.rgb(self.diffuse));
+        shader.set_vec3("material.specular", ctx.rgb(self.specular));
+        shader.set_float("material.shininess", self.shininess);
+        // Shapes with their own colors (see Cube) or textures (see Model) set these again after applying the material
+        shader.set_vec4("tint", [1.0; 4]);
+        shader.set_bool("vertexColors", false);
+        shader.set_bool("useDiffuseMap", false);
+    }
+}
+
+impl Default for Material {
+    fn default() -> Self {
+        Material::new(Vector3D::new(0.8, 0.8, 0.8), Vector3D::new(0.2, 0.2, 0.2), 32.0)
+    }
+}
@@ -0,0 +1,237 @@
+use std::ops::Mul;
+
+use crate::vector3d::Vector3D;
+
+// Sütun öncelikli (column-major) 4x4 matris, OpenGL'e doğrudan gönderilebilir
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Matrix4 {
+    pub data: [f32; 16],
+}
+
+impl Matrix4 {
+    // Birim matris oluşturur
+    pub fn identity() -> Self {
+        Matrix4 {
+            data: [
+                1.0, 0.0, 0.0, 0.0,
+                0.0, 1.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ],
+        }
+    }
+
+    // (sütun, satır) konumundaki elemanı döndürür
+    pub fn get(&self, col: usize, row: usize) -> f32 {
+        self.data[col * 4 + row]
+    }
+
+    // (sütun, satır) konumundaki elemanı değiştirir
+    pub fn set(&mut self, col: usize, row: usize, value: f32) {
+        self.data[col * 4 + row] = value;
+    }
+
+    // Öteleme matrisi oluşturur
+    pub fn translation(offset: Vector3D) -> Self {
+        let mut m = Matrix4::identity();
+        m.set(3, 0, offset.x);
+        m.set(3, 1, offset.y);
+        m.set(3, 2, offset.z);
+        m
+    }
+
+    // Ölçekleme matrisi oluşturur
+    pub fn scale(factor: Vector3D) -> Self {
+        let mut m = Matrix4::identity();
+        m.set(0, 0, factor.x);
+        m.set(1, 1, factor.y);
+        m.set(2, 2, factor.z);
+        m
+    }
+
+    // X ekseni etrafında döndürme matrisi oluşturur
+    pub fn rotation_x(angle: f32) -> Self {
+        let (s, c) = angle.sin_cos();
+        let mut m = Matrix4::identity();
+        m.set(1, 1, c);
+        m.set(1, 2, s);
+        m.set(2, 1, -s);
+        m.set(2, 2, c);
+        m
+    }
+
+    // Y ekseni etrafında döndürme matrisi oluşturur
+    pub fn rotation_y(angle: f32) -> Self {
+        let (s, c) = angle.sin_cos();
+        let mut m = Matrix4::identity();
+        m.set(0, 0, c);
+        m.set(0, 2, -s);
+        m.set(2, 0, s);
+        m.set(2, 2, c);
+        m
+    }
+
+    // Z ekseni etrafında döndürme matrisi oluşturur
+    pub fn rotation_z(angle: f32) -> Self {
+        let (s, c) = angle.sin_cos();
+        let mut m = Matrix4::identity();
+        m.set(0, 0, c);
+        m.set(0, 1, s);
+        m.set(1, 0, -s);
+        m.set(1, 1, c);
+        m
+    }
+
+    // Ortografik izdüşüm matrisi oluşturur
+    pub fn orthographic(left: f32, right: f32, bottom: f32, top: f32, near: f32, far: f32) -> Self {
+        let mut m = Matrix4::identity();
+        m.set(0, 0, 2.0 / (right - left));
+        m.set(1, 1, 2.0 / (top - bottom));
+        m.set(2, 2, -2.0 / (far - near));
+        m.set(3, 0, -(right + left) / (right - left));
+        m.set(3, 1, -(top + bottom) / (top - bottom));
+        m.set(3, 2, -(far + near) / (far - near));
+        m
+    }
+
+    // Perspektif izdüşüm matrisi oluşturur (fov_y radyan cinsinden)
+    pub fn perspective(fov_y: f32, aspect: f32, near: f32, far: f32) -> Self {
+        let f = 1.0 / (fov_y / 2.0).tan();
+        let mut m = Matrix4 { data: [0.0; 16] };
+        m.set(0, 0, f / aspect);
+        m.set(1, 1, f);
+        m.set(2, 2, (far + near) / (near - far));
+        m.set(2, 3, -1.0);
+        m.set(3, 2, (2.0 * far * near) / (near - far));
+        m
+    }
+
+    // Göz konumundan hedefe bakan görünüm matrisi oluşturur
+    pub fn look_at(eye: Vector3D, target: Vector3D, up: Vector3D) -> Self {
+        let forward = (target - eye).normalize();
+        let side = forward.cross(&up).normalize();
+        let up = side.cross(&forward);
+
+        let mut m = Matrix4::identity();
+        m.set(0, 0, side.x);
+        m.set(1, 0, side.y);
+        m.set(2, 0, side.z);
+        m.set(0, 1, up.x);
+        m.set(1, 1, up.y);
+        m.set(2, 1, up.z);
+        m.set(0, 2, -forward.x);
+        m.set(1, 2, -forward.y);
+        m.set(2, 2, -forward.z);
+        m.set(3, 0, -side.dot(&eye));
+        m.set(3, 1, -up.dot(&eye));
+        m.set(3, 2, forward.dot(&eye));
+        m
+    }
+
+    // Matrisin devriğini döndürür
+    pub fn transpose(&self) -> Self {
+        let mut m = Matrix4 { data: [0.0; 16] };
+        for col in 0..4 {
+            for row in 0..4 {
+                m.set(col, row, self.get(row, col));
+            }
+        }
+        m
+    }
+
+    // Matrisin tersini hesaplar, tekil matrislerde None döner
+    pub fn inverse(&self) -> Option<Self> {
+        let m = &self.data;
+        let mut inv = [0.0f32; 16];
+
+        inv[0] = m[5] * m[10] * m[15] - m[5] * m[11] * m[14] - m[9] * m[6] * m[15]
+            + m[9] * m[7] * m[14] + m[13] * m[6] * m[11] - m[13] * m[7] * m[10];
+        inv[4] = -m[4] * m[10] * m[15] + m[4] * m[11] * m[14] + m[8] * m[6] * m[15]
+            - m[8] * m[7] * m[14] - m[12] * m[6] * m[11] + m[12] * m[7] * m[10];
+        inv[8] = m[4] * m[9] * m[15] - m[4] * m[11] * m[13] - m[8] * m[5] * m[15]
+            + m[8] * m[7] * m[13] + m[12] * m[5] * m[11] - m[12] * m[7] * m[9];
+        inv[12] = -m[4] * m[9] * m[14] + m[4] * m[10] * m[13] + m[8] * m[5] * m[14]
+            - m[8] * m[6] * m[13] - m[12] * m[5] * m[10] + m[12] * m[6] * m[9];
+        inv[1] = -m[1] * m[10] * m[15] + m[1] * m[11] * m[14] + m[9] * m[2] * m[15]
+            - m[9] * m[3] * m[14] - m[13] * m[2] * m[11] + m[13] * m[3] * m[10];
+        inv[5] = m[0] * m[10] * m[15] - m[0] * m[11] * m[14] - m[8] * m[2] * m[15]
+            + m[8] * m[3] * m[14] + m[12] * m[2] * m[11] - m[12] * m[3] * m[10];
+        inv[9] = -m[0] * m[9] * m[15] + m[0] * m[11] * m[13] + m[8] * m[1] * m[15]
+            - m[8] * m[3] * m[13] - m[12] * m[1] * m[11] + m[12] * m[3] * m[9];
+        inv[13] = m[0] * m[9] * m[14] - m[0] * m[10] * m[13] - m[8] * m[1] * m[14]
+            + m[8] * m[2] * m[13] + m[12] * m[1] * m[10] - m[12] * m[2] * m[9];
+        inv[2] = m[1] * m[6] * m[15] - m[1] * m[7] * m[14] - m[5] * m[2] * m[15]
+            + m[5] * m[3] * m[14] + m[13] * m[2] * m[7] - m[13] * m[3] * m[6];
+        inv[6] = -m[0] * m[6] * m[15] + m[0] * m[7] * m[14] + m[4] * m[2] * m[15]
+            - m[4] * m[3] * m[14] - m[12] * m[2] * m[7] + m[12] * m[3] * m[6];
+        inv[10] = m[0] * m[5] * m[15] - m[0] * m[7] * m[13] - m[4] * m[1] * m[15]
+            + m[4] * m[3] * m[13] + m[12] * m[1] * m[7] - m[12] * m[3] * m[5];
+        inv[14] = -m[0] * m[5] * m[14] + m[0] * m[6] * m[13] + m[4] * m[1] * m[14]
+            - m[4] * m[2] * m[13] - m[12] * m[1] * m[6] + m[12] * m[2] * m[5];
+        inv[3] = -m[1] * m[6] * m[11] + m[1] * m[7] * m[10] + m[5] * m[2] * m[11]
+            - m[5] * m[3] * m[10] - m[9] * m[2] * m[7] + m[9] * m[3] * m[6];
+        inv[7] = m[0] * m[6] * m[11] - m[0] * m[7] * m[10] - m[4] * m[2] * m[11]
+            + m[4] * m[3] * m[10] + m[8] * m[2] * m[7] - m[8] * m[3] * m[6];
+        inv[11] = -m[0] * m[5] * m[11] + m[0] * m[7] * m[9] + m[4] * m[1] * m[11]
+            - m[4] * m[3] * m[9] - m[8] * m[1] * m[7] + m[8] * m[3] * m[5];
+        inv[15] = m[0] * m[5] * m[10] - m[0] * m[6] * m[9] - m[4] * m[1] * m[10]
+            + m[4] * m[2] * m[9] + m[8] * m[1] * m[6] - m[8] * m[2] * m[5];
+
+        let det = m[0] * inv[0] + m[1] * inv[4] + m[2] * inv[8] + m[3] * inv[12];
+        if det == 0.0 {
+            return None;
+        }
+
+        let inv_det = 1.0 / det;
+        for value in inv.iter_mut() {
+            *value *= inv_det;
+        }
+        Some(Matrix4 { data: inv })
+    }
+
+    // Bir noktayı dönüştürür (perspektif bölmesi dahil)
+    pub fn transform_point(&self, p: Vector3D) -> Vector3D {
+        let x = self.get(0, 0) * p.x + self.get(1, 0) * p.y + self.get(2, 0) * p.z + self.get(3, 0);
+        let y = self.get(0, 1) * p.x + self.get(1, 1) * p.y + self.get(2, 1) * p.z + self.get(3, 1);
+        let z = self.get(0, 2) * p.x + self.get(1, 2) * p.y + self.get(2, 2) * p.z + self.get(3, 2);
+        let w = self.get(0, 3) * p.x + self.get(1, 3) * p.y + self.get(2, 3) * p.z + self.get(3, 3);
+        if w != 0.0 && w != 1.0 {
+            Vector3D::new(x / w, y / w, z / w)
+        } else {
+            Vector3D::new(x, y, z)
+        }
+    }
+
+    // Bir yön vektörünü dönüştürür (öteleme uygulanmaz)
+    pub fn transform_vector(&self, v: Vector3D) -> Vector3D {
+        Vector3D::new(
+            self.get(0, 0) * v.x + self.get(1, 0) * v.y + self.get(2, 0) * v.z,
+            self.get(0, 1) * v.x + self.get(1, 1) * v.y + self.get(2, 1) * v.z,
+            self.get(0, 2) * v.x + self.get(1, 2) * v.y + self.get(2, 2) * v.z,
+        )
+    }
+
+    // OpenGL'e gönderilecek ham veri işaretçisini döndürür
+    pub fn as_ptr(&self) -> *const f32 {
+        self.data.as_ptr()
+    }
+}
+
+// Matris çarpımı
+impl Mul for Matrix4 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let mut result = Matrix4 { data: [0.0; 16] };
+        for col in 0..4 {
+            for row in 0..4 {
+                let mut sum = 0.0;
+                for k in 0..4 {
+                    sum += self.get(k, row) * other.get(col, k);
+                }
+                result.set(col, row, sum);
+            }
+        }
+        result
+    }
+}
@@ -0,0 +1,262 @@
+use crate::shader::Shader;
+use crate::draw::{RenderContext, Shape};
+use crate::material::{Material, DIFFUSE_MAP_UNIT};
+use crate::matrix4::Matrix4;
+use crate::texture::Texture;
+use crate::vector3d::Vector3D;
+use std::rc::Rc;
+use std::ffi::c_void;
+use std::mem;
+
+// Bir köşenin kayan nokta sayısı: konum (3), normal (3), doku koordinatı (2)
+const VERTEX_FLOATS: usize = 8;
+
+// CPU tarafında tutulan, indeksli üçgenlerden oluşan mesh verisi.
+// Normal ve doku koordinatı dizileri ya boştur ya da konumlarla aynı uzunluktadır.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MeshData {
+    pub positions: Vec<[f32; 3]>,
+    pub normals: Vec<[f32; 3]>,
+    pub uvs: Vec<[f32; 2]>,
+    pub indices: Vec<u32>,
+}
+
+impl MeshData {
+    // Köşe sayısını döndürür
+    pub fn vertex_count(&self) -> usize {
+        self.positions.len()
+    }
+
+    // Üçgen sayısını döndürür
+    pub fn triangle_count(&self) -> usize {
+        self.indices.len() / 3
+    }
+
+    // Üçgen normallerinin alanla ağırlıklı ortalamasından köşe normallerini hesaplar
+    pub fn compute_normals(&mut self) {
+        let mut normals = vec![Vector3D::zero(); self.positions.len()];
+        for triangle in self.indices.chunks_exact(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| {
+                let p = self.positions[triangle[i] as usize];
+                Vector3D::new(p[0], p[1], p[2])
+            });
+            // The cross product's length is twice the triangle area, which weights larger faces more
+            let face = (b - a).cross(&(c - a));
+            for &index in triangle {
+                normals[index as usize] = normals[index as usize] + face;
+            }
+        }
+
+        self.normals = normals
+            .into_iter()
+            .map(|n| if n.magnitude() > 0.0 { n.normalize() } else { Vector3D::new(0.0, 1.0, 0.0) })
+            .map(|n| [n.x, n.y, n.z])
+            .collect();
+    }
+
+    // Konumları ve normalleri verilen matrisle dönüştürülmüş bir kopya döndürür.
+    // Normaller ters devrik matrisle dönüştürülür, böylece eşit olmayan ölçekte de yüzeye dik kalır.
+    pub fn transformed(&self, matrix: &Matrix4) -> MeshData {
+        let normal_matrix = matrix.inverse().unwrap_or_else(Matrix4::identity).transpose();
+        let point = |p: &[f32; 3]| {
+            let p = matrix.transform_point(Vector3D::new(p[0], p[1], p[2]));
+            [p.x, p.y, p.z]
+        };
+        let normal = |n: &[f32; 3]| {
+            let n = normal_matrix.transform_vector(Vector3D::new(n[0], n[1], n[2]));
+            let n = if n.magnitude() > 0.0 { n.normalize() } else { n };
+            [n.x, n.y, n.z]
+        };
+
+        MeshData {
+            positions: self.positions.iter().map(point).collect(),
+            normals: self.normals.iter().map(normal).collect(),
+            uvs: self.uvs.clone(),
+            indices: self.indices.clone(),
+        }
+    }
+
+    // Başka bir mesh'in üçgenlerini bu mesh'in sonuna ekler.
+    // Yalnızca bir tarafta bulunan normal veya doku koordinatları sıfırla tamamlanır.
+    pub fn append(&mut self, other: &MeshData) {
+        let offset = self.positions.len();
+        let count = offset + other.positions.len();
+        if !self.normals.is_empty() || !other.normals.is_empty() {
+            self.normals.resize(offset, [0.0, 0.0, 0.0]);
+            self.normals.extend_from_slice(&other.normals);
+            self.normals.resize(count, [0.0, 0.0, 0.0]);
+        }
+        if !self.uvs.is_empty() || !other.uvs.is_empty() {
+            self.uvs.resize(offset, [0.0, 0.0]);
+            self.uvs.extend_from_slice(&other.uvs);
+            self.uvs.resize(count, [0.0, 0.0]);
+        }
+        self.positions.extend_from_slice(&other.positions);
+        self.indices.extend(other.indices.iter().map(|index| index + offset as u32));
+    }
+
+    // Köşeleri OpenGL'e yüklenecek biçimde (konum, normal, uv) art arda dizer
+    pub fn interleaved(&self) -> Vec<f32> {
+        let mut vertices = Vec::with_capacity(self.positions.len() * VERTEX_FLOATS);
+        for (i, position) in self.positions.iter().enumerate() {
+            vertices.extend_from_slice(position);
+            vertices.extend_from_slice(self.normals.get(i).unwrap_or(&[0.0, 0.0, 0.0]));
+            vertices.extend_from_slice(self.uvs.get(i).unwrap_or(&[0.0, 0.0]));
+        }
+        vertices
+    }
+}
+
+// GPU'ya yüklenmiş bir mesh. Dışa aktarma için verinin CPU tarafındaki kopyası da tutulur.
+pub struct Mesh {
+    vao: gl::types::GLuint,
+    vbo: gl::types::GLuint,
+    ebo: gl::types::GLuint,
+    index_count: i32,
+    data: MeshData,
+}
+
+impl Mesh {
+    // Mesh verisini GPU'ya yükler
+    pub fn new(data: &MeshData) -> Mesh {
+        let vertices = data.interleaved();
+        let mut mesh = Mesh { vao: 0, vbo: 0, ebo: 0, index_count: data.indices.len() as i32, data: data.clone() };
+
+        unsafe {
+            gl::GenVertexArrays(1, &mut mesh.vao);
+            gl::GenBuffers(1, &mut mesh.vbo);
+            gl::GenBuffers(1, &mut mesh.ebo);
+
+            gl::BindVertexArray(mesh.vao);
+
+            gl::BindBuffer(gl::ARRAY_BUFFER, mesh.vbo);
+            gl::BufferData(
+                gl::ARRAY_BUFFER,
+                (vertices.len() * mem::size_of::<f32>()) as gl::types::GLsizeiptr,
+                vertices.as_ptr() as *const c_void,
+                gl::STATIC_DRAW,
+            );
+
+            gl::BindBuffer(gl::ELEMENT_ARRAY_BUFFER, mesh.ebo);
+            gl::BufferData(
+                gl::ELEMENT_ARRAY_BUFFER,
+                (data.indices.len() * mem::size_of::<u32>()) as gl::types::GLsizeiptr,
+                data.indices.as_ptr() as *const c_void,
+                gl::STATIC_DRAW,
+            );
+
+            mesh.bind_vertex_layout();
+
+            gl::BindVertexArray(0);
+            gl::BindBuffer(gl::ARRAY_BUFFER, 0);
+            gl::BindBuffer(gl::ELEMENT_ARRAY_BUFFER, 0);
+        }
+
+        mesh
+    }
+
+    // Mesh'in köşe ve indeks tamponlarını bağlı VAO'ya bağlar ve köşe özelliklerini
+    // (0: konum, 1: normal, 2: uv) tanımlar. Aynı mesh'i kendi VAO'larında kullanan çiziciler için de geçerlidir.
+    pub fn bind_vertex_layout(&self) {
+        let stride = (VERTEX_FLOATS * mem::size_of::<f32>()) as gl::types::GLsizei;
+        unsafe {
+            gl::BindBuffer(gl::ARRAY_BUFFER, self.vbo);
+            gl::BindBuffer(gl::ELEMENT_ARRAY_BUFFER, self.ebo);
+
+            gl::VertexAttribPointer(0, 3, gl::FLOAT, gl::FALSE, stride, std::ptr::null());
+            gl::EnableVertexAttribArray(0);
+
+            gl::VertexAttribPointer(1, 3, gl::FLOAT, gl::FALSE, stride, (3 * mem::size_of::<f32>()) as *const c_void);
+            gl::EnableVertexAttribArray(1);
+
+            gl::VertexAttribPointer(2, 2, gl::FLOAT, gl::FALSE, stride, (6 * mem::size_of::<f32>()) as *const c_void);
+            gl::EnableVertexAttribArray(2);
+        }
+    }
+
+    // Çizilecek indeks sayısını döndürür
+    pub fn index_count(&self) -> i32 {
+        self.index_count
+    }
+
+    // Yüklenen mesh verisini döndürür
+    pub fn data(&self) -> &MeshData {
+        &self.data
+    }
+
+    // Mesh'i bağlı shader ile çizer
+    pub fn draw(&self) {
+        unsafe {
+            gl::BindVertexArray(self.vao);
+            gl::DrawElements(gl::TRIANGLES, self.index_count, gl::UNSIGNED_INT, std::ptr::null());
+            gl::BindVertexArray(0);
+        }
+    }
+}
+
+impl Drop for Mesh {
+    // Mesh silindiğinde OpenGL kaynaklarını temizler
+    fn drop(&mut self) {
+        unsafe {
+            gl::DeleteVertexArrays(1, &self.vao);
+            gl::DeleteBuffers(1, &self.vbo);
+            gl::DeleteBuffers(1, &self.ebo);
+        }
+    }
+}
+
+// Paylaşılan bir mesh'i kendi shader'ı ve malzemesiyle çizen şekil
+pub struct Model {
+    mesh: Rc<Mesh>,
+    shader: Rc<Shader>,
+    pub material: Material,
+    diffuse_map: Option<Rc<Texture>>,  // Multiplies the diffuse color, sampled with the mesh's uvs
+}
+
+impl Model {
+    // Yeni bir model oluşturur
+    pub fn new(mesh: Rc<Mesh>, shader: Rc<Shader>, material: Material) -> Model {
+        Model { mesh, shader, material, diffuse_map: None }
+    }
+
+    // Malzemenin diffuse rengini verilen dokuyla çarpar
+    pub fn with_diffuse_map(mut self, texture: Option<Rc<Texture>>) -> Model {
+        self.diffuse_map = texture;
+        self
+    }
+}
+
+impl Shape for Model {
+    // Mesh zaten yüklü olduğu için yapılacak bir şey yoktur
+    fn init(&mut self) {}
+
+    // Modeli çizer
+    fn draw(&self, ctx: &RenderContext, model: &Matrix4) {
+        self.shader.use_program();
+        ctx.apply(&self.shader);
+        self.shader.set_mat4("model", model);
+        self.material.apply(&self.shader, ctx);
+        if let Some(texture) = &self.diffuse_map {
+            texture.bind(DIFFUSE_MAP_UNIT);
+            self.shader.set_int("diffuseMap", DIFFUSE_MAP_UNIT as i32);
+            self.shader.set_bool("useDiffuseMap", true);
+        }
+        self.mesh.draw();
+    }
+
+    // Modeli gölge haritasına çizer
+    fn draw_depth(&self, depth_shader: &Shader, model: &Matrix4) {
+        depth_shader.set_mat4("model", model);
+        self.mesh.draw();
+    }
+
+    // Yüklenen mesh verisini döndürür
+    fn mesh_data(&self) -> Option<MeshData> {
+        Some(self.mesh.data().clone())
+    }
+
+    // Şeklin malzemesini döndürür
+    fn material_mut(&mut self) -> Option<&mut Material> {
+        Some(&mut self.material)
+    }
+}
@@ -0,0 +1,375 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+use crate::locator;
+use crate::material::Material;
+use crate::mesh::MeshData;
+use crate::vector3d::Vector3D;
+
+// OBJ dosyasındaki tek malzemeli bir nesne/grup parçası
+#[derive(Debug, Clone)]
+pub struct ObjMesh {
+    pub name: String,
+    pub material: Option<String>,
+    pub data: MeshData,
+}
+
+// MTL dosyasındaki bir malzeme ve (varsa) OBJ dosyasının klasörüne göre diffuse dokusunun yolu
+#[derive(Debug, Clone, Default)]
+pub struct ObjMaterial {
+    pub material: Material,
+    pub diffuse_map: Option<PathBuf>,
+}
+
+// Ayrıştırılmış bir OBJ dosyası: parçaları ve MTL dosyalarından okunan malzemeler
+#[derive(Debug, Clone, Default)]
+pub struct ObjModel {
+    pub meshes: Vec<ObjMesh>,
+    pub materials: BTreeMap<String, ObjMaterial>,
+}
+
+impl ObjModel {
+    // Parçanın malzemesini döndürür; malzeme yoksa veya MTL'de tanımlı değilse None döner
+    pub fn material_of(&self, mesh: &ObjMesh) -> Option<Material> {
+        self.obj_material_of(mesh).map(|material| material.material)
+    }
+
+    // Parçanın diffuse dokusunun yolunu döndürür
+    pub fn diffuse_map_of(&self, mesh: &ObjMesh) -> Option<&Path> {
+        self.obj_material_of(mesh).and_then(|material| material.diffuse_map.as_deref())
+    }
+
+    fn obj_material_of(&self, mesh: &ObjMesh) -> Option<&ObjMaterial> {
+        mesh.material.as_ref().and_then(|name| self.materials.get(name))
+    }
+}
+
+// Bir yüz köşesinin konum, doku koordinatı ve normal indeksleri (0 tabanlı)
+type VertexKey = (usize, Option<usize>, Option<usize>);
+
+// Yapım aşamasındaki bir parça; aynı indeks üçlüsü tek köşe olarak paylaşılır
+struct MeshBuilder {
+    name: String,
+    material: Option<String>,
+    data: MeshData,
+    vertices: HashMap<VertexKey, u32>,
+    has_uvs: bool,
+    missing_normals: bool,
+}
+
+impl MeshBuilder {
+    fn new(name: &str, material: Option<String>) -> MeshBuilder {
+        MeshBuilder {
+            name: name.to_string(),
+            material,
+            data: MeshData::default(),
+            vertices: HashMap::new(),
+            has_uvs: false,
+            missing_normals: false,
+        }
+    }
+
+    // Köşeyi ekler (veya var olanı bulur) ve indeksini döndürür
+    fn vertex(&mut self, key: VertexKey, positions: &[[f32; 3]], uvs: &[[f32; 2]], normals: &[[f32; 3]]) -> u32 {
+        if let Some(&index) = self.vertices.get(&key) {
+            return index;
+        }
+
+        let (position, uv, normal) = key;
+        let index = self.data.positions.len() as u32;
+        self.data.positions.push(positions[position]);
+        self.data.uvs.push(uv.map_or([0.0, 0.0], |uv| uvs[uv]));
+        self.data.normals.push(normal.map_or([0.0, 0.0, 0.0], |normal| normals[normal]));
+        self.has_uvs |= uv.is_some();
+        self.missing_normals |= normal.is_none();
+        self.vertices.insert(key, index);
+        index
+    }
+
+    // Parçayı tamamlar; normali eksik köşeler varsa normaller yüzlerden hesaplanır
+    fn finish(mut self) -> Option<ObjMesh> {
+        if self.data.indices.is_empty() {
+            return None;
+        }
+        if !self.has_uvs {
+            self.data.uvs.clear();
+        }
+        if self.missing_normals {
+            self.data.compute_normals();
+        }
+        Some(ObjMesh { name: self.name, material: self.material, data: self.data })
+    }
+}
+
+// Satırdaki sayıları okur; eksik değerler `default` ile tamamlanır
+fn parse_floats<const N: usize>(fields: &[&str], required: usize, default: f32) -> Result<[f32; N], String> {
+    if fields.len() < required {
+        return Err(format!("expected at least {} numbers, got {}", required, fields.len()));
+    }
+    let mut values = [default; N];
+    for (value, field) in values.iter_mut().zip(fields) {
+        *value = field.parse().map_err(|_| format!("invalid number '{}'", field))?;
+    }
+    Ok(values)
+}
+
+// 1 tabanlı (veya sondan sayan negatif) OBJ indeksini 0 tabanlı indekse çevirir
+fn resolve_index(field: &str, count: usize, kind: &str) -> Result<usize, String> {
+    let index: i64 = field.parse().map_err(|_| format!("invalid {} index '{}'", kind, field))?;
+    let resolved = if index < 0 { count as i64 + index } else { index - 1 };
+    if index == 0 || resolved < 0 || resolved >= count as i64 {
+        return Err(format!("{} index {} out of range (1..={})", kind, index, count));
+    }
+    Ok(resolved as usize)
+}
+
+// "v", "v/vt", "v//vn" veya "v/vt/vn" biçimindeki yüz köşesini ayrıştırır
+fn parse_face_vertex(field: &str, positions: usize, uvs: usize, normals: usize) -> Result<VertexKey, String> {
+    let mut parts = field.split('/');
+    let position = resolve_index(parts.next().unwrap_or(""), positions, "position")?;
+    let uv = match parts.next() {
+        Some("") | None => None,
+        Some(uv) => Some(resolve_index(uv, uvs, "texture coordinate")?),
+    };
+    let normal = match parts.next() {
+        Some("") | None => None,
+        Some(normal) => Some(resolve_index(normal, normals, "normal")?),
+    };
+    Ok((position, uv, normal))
+}
+
+// OBJ metnini ayrıştırır. "mtllib" ile verilen dosyalar `read_mtl` ile okunur,
+// böylece ayrıştırıcı diske erişmeden de kullanılabilir; okunamayan MTL dosyaları uyarıyla atlanır.
+// Çokgen yüzler yelpaze biçiminde üçgenlenir. Hatalar "satır N: mesaj" biçimindedir.
+pub fn parse_obj<F>(source: &str, mut read_mtl: F) -> Result<ObjModel, String>
+where
+    F: FnMut(&str) -> Result<String, String>,
+{
+    let mut model = ObjModel::default();
+    let (mut positions, mut uvs, mut normals) = (Vec::new(), Vec::new(), Vec::new());
+    let mut group = "default".to_string();
+    let mut builder = MeshBuilder::new(&group, None);
+
+    for (number, line) in source.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or("").trim();
+        let mut fields = line.split_whitespace();
+        let Some(keyword) = fields.next() else { continue };
+        let fields: Vec<&str> = fields.collect();
+        let error = |message: String| format!("line {}: {}", number + 1, message);
+
+        match keyword {
+            "v" => positions.push(parse_floats::<3>(&fields, 3, 0.0).map_err(error)?),
+            "vt" => uvs.push(parse_floats::<2>(&fields, 1, 0.0).map_err(error)?),
+            "vn" => normals.push(parse_floats::<3>(&fields, 3, 0.0).map_err(error)?),
+            "f" => {
+                if fields.len() < 3 {
+                    return Err(error(format!("a face needs at least 3 vertices, got {}", fields.len())));
+                }
+                let mut face = Vec::with_capacity(fields.len());
+                for field in &fields {
+                    let key = parse_face_vertex(field, positions.len(), uvs.len(), normals.len()).map_err(error)?;
+                    face.push(builder.vertex(key, &positions, &uvs, &normals));
+                }
+                for i in 1..face.len() - 1 {
+                    builder.data.indices.extend_from_slice(&[face[0], face[i], face[i + 1]]);
+                }
+            }
+            "o" | "g" => {
+                group = if fields.is_empty() { "default".to_string() } else { fields.join(" ") };
+                let material = builder.material.clone();
+                let previous = std::mem::replace(&mut builder, MeshBuilder::new(&group, material));
+                model.meshes.extend(previous.finish());
+            }
+            "usemtl" => {
+                let material = Some(fields.join(" ")).filter(|name| !name.is_empty());
+                if material != builder.material {
+                    let previous = std::mem::replace(&mut builder, MeshBuilder::new(&group, material));
+                    model.meshes.extend(previous.finish());
+                }
+            }
+            "mtllib" => {
+                // Parts that use a material from a missing library fall back to the default material
+                let file = fields.join(" ");
+                let source = match read_mtl(&file) {
+                    Ok(source) => source,
+                    Err(e) => {
+                        eprintln!("Skipping material library '{}': {}", file, e);
+                        continue;
+                    }
+                };
+                let materials = parse_mtl(&source).map_err(|e| error(format!("{}: {}", file, e)))?;
+                // Texture paths in the MTL file are relative to the MTL file itself
+                let directory = Path::new(&file).parent().unwrap_or(Path::new(""));
+                model.materials.extend(materials.into_iter().map(|(name, mut material)| {
+                    material.diffuse_map = material.diffuse_map.map(|map| directory.join(map));
+                    (name, material)
+                }));
+            }
+            // Smoothing groups, lines, points and free-form geometry are not supported
+            _ => {}
+        }
+    }
+
+    model.meshes.extend(builder.finish());
+    Ok(model)
+}
+
+// MTL metnini ayrıştırır. Kd, Ks ve Ns değerleri malzemeye aktarılır, map_Kd dokusunun yolu olduğu gibi saklanır;
+// diğer alanlar yok sayılır.
+pub fn parse_mtl(source: &str) -> Result<BTreeMap<String, ObjMaterial>, String> {
+    let mut materials = BTreeMap::new();
+    let mut current: Option<(String, ObjMaterial)> = None;
+
+    for (number, line) in source.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or("").trim();
+        let mut fields = line.split_whitespace();
+        let Some(keyword) = fields.next() else { continue };
+        let fields: Vec<&str> = fields.collect();
+        let error = |message: String| format!("line {}: {}", number + 1, message);
+
+        if keyword == "newmtl" {
+            materials.extend(current.take());
+            current = Some((fields.join(" "), ObjMaterial::default()));
+            continue;
+        }
+
+        let Some((_, current)) = current.as_mut() else {
+            return Err(error(format!("'{}' before any newmtl", keyword)));
+        };
+        let material = &mut current.material;
+        match keyword {
+            "Kd" => material.diffuse = color(&fields).map_err(error)?,
+            "Ks" => material.specular = color(&fields).map_err(error)?,
+            "Ns" => material.shininess = parse_floats::<1>(&fields, 1, 0.0).map_err(error)?[0].max(1.0),
+            "map_Kd" => current.diffuse_map = Some(texture_path(&fields).map_err(error)?),
+            _ => {}
+        }
+    }
+
+    materials.extend(current);
+    Ok(materials)
+}
+
+// Doku satırındaki dosya yolunu döndürür. Seçenekler (-s 1 1 1 gibi) desteklenmez, bu durumda son alan
+// dosya adı kabul edilir; seçenek yoksa boşluk içeren adlar için tüm alanlar birleştirilir.
+fn texture_path(fields: &[&str]) -> Result<PathBuf, String> {
+    let file = match fields.first() {
+        None => return Err("missing texture file name".to_string()),
+        Some(first) if first.starts_with('-') => fields[fields.len() - 1].to_string(),
+        Some(_) => fields.join(" "),
+    };
+    Ok(PathBuf::from(file))
+}
+
+// "Kd 0.5" gibi tek değerli renkleri gri kabul eder
+fn color(fields: &[&str]) -> Result<Vector3D, String> {
+    let [r, g, b] = parse_floats::<3>(fields, 1, f32::NAN)?;
+    if g.is_nan() || b.is_nan() {
+        return Ok(Vector3D::new(r, r, r));
+    }
+    Ok(Vector3D::new(r, g, b))
+}
+
+// OBJ dosyasını varlık klasörlerinde bulup okur; MTL dosyaları ve dokular OBJ dosyasının klasörüne göre aranır
+pub fn load<P: AsRef<Path>>(path: P) -> Result<ObjModel, String> {
+    let path = path.as_ref();
+    let source = locator::read_to_string(path)?;
+    let directory = path.parent().unwrap_or(Path::new(""));
+    let mut model = parse_obj(&source, |file| locator::read_to_string(directory.join(file)))
+        .map_err(|e| format!("{}: {}", path.display(), e))?;
+    for material in model.materials.values_mut() {
+        material.diffuse_map = material.diffuse_map.take().map(|map| directory.join(map));
+    }
+    Ok(model)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn no_mtl(file: &str) -> Result<String, String> {
+        Err(format!("unexpected mtllib '{}'", file))
+    }
+
+    #[test]
+    fn shares_vertices_and_triangulates_polygons() {
+        let model = parse_obj(
+            "# quad\nv 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\nvt 0 0\nvt 1 0\nvt 1 1\nvt 0 1\nvn 0 0 1\n\
+             f 1/1/1 2/2/1 3/3/1 4/4/1\nf -4/-4/-1 -2/-2/-1 -1/-1/-1\n",
+            no_mtl,
+        )
+        .unwrap();
+
+        assert_eq!(model.meshes.len(), 1);
+        let data = &model.meshes[0].data;
+        assert_eq!(data.vertex_count(), 4);
+        assert_eq!(data.indices, [0, 1, 2, 0, 2, 3, 0, 2, 3]);
+        assert_eq!(data.uvs[2], [1.0, 1.0]);
+        assert_eq!(data.normals[3], [0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn computes_missing_normals_and_drops_missing_uvs() {
+        let model = parse_obj("v 0 0 0\nv 1 0 0\nv 0 0 -1\nf 1 2 3\n", no_mtl).unwrap();
+        let data = &model.meshes[0].data;
+        assert!(data.uvs.is_empty());
+        for normal in &data.normals {
+            assert!((normal[1] - 1.0).abs() < 1e-6, "{:?}", normal);
+        }
+    }
+
+    #[test]
+    fn splits_objects_groups_and_materials() {
+        let mtl = "newmtl red\nKd 1 0 0\nKs 0.5\nNs 64\n\nnewmtl green\nKd 0 1 0\n";
+        let model = parse_obj(
+            "mtllib parts.mtl\nv 0 0 0\nv 1 0 0\nv 0 1 0\n\
+             o first\nusemtl red\nf 1 2 3\nusemtl green\nf 1 3 2\n\
+             g second\nf 3 2 1\ng empty\n",
+            |file| {
+                assert_eq!(file, "parts.mtl");
+                Ok(mtl.to_string())
+            },
+        )
+        .unwrap();
+
+        let parts: Vec<(&str, Option<&str>)> =
+            model.meshes.iter().map(|mesh| (mesh.name.as_str(), mesh.material.as_deref())).collect();
+        assert_eq!(parts, [("first", Some("red")), ("first", Some("green")), ("second", Some("green"))]);
+
+        let red = model.material_of(&model.meshes[0]).unwrap();
+        assert_eq!((red.diffuse.x, red.diffuse.y), (1.0, 0.0));
+        assert_eq!((red.specular.z, red.shininess), (0.5, 64.0));
+    }
+
+    #[test]
+    fn resolves_diffuse_maps_relative_to_the_material_library() {
+        let model = parse_obj(
+            "mtllib materials/my parts.mtl\nmtllib missing.mtl\nv 0 0 0\nv 1 0 0\nv 0 1 0\n\
+             usemtl wood\nf 1 2 3\nusemtl scaled\nf 1 3 2\nusemtl lost\nf 3 2 1\n",
+            |file| match file {
+                "materials/my parts.mtl" => {
+                    Ok("newmtl wood\nmap_Kd textures/oak planks.png\nnewmtl scaled\nmap_Kd -s 2 2 1 tile.png\n".to_string())
+                }
+                _ => Err(format!("{}: not found", file)),
+            },
+        )
+        .unwrap();
+
+        let maps: Vec<Option<&Path>> = model.meshes.iter().map(|mesh| model.diffuse_map_of(mesh)).collect();
+        assert_eq!(
+            maps,
+            [Some(Path::new("materials/textures/oak planks.png")), Some(Path::new("materials/tile.png")), None]
+        );
+        assert!(model.material_of(&model.meshes[2]).is_none());
+    }
+
+    #[test]
+    fn reports_line_numbers() {
+        let error = parse_obj("v 0 0 0\nv 1 0 0\nf 1 2 3\n", no_mtl).unwrap_err();
+        assert_eq!(error, "line 3: position index 3 out of range (1..=2)");
+        let error = parse_obj("v 0 zero 0\n", no_mtl).unwrap_err();
+        assert_eq!(error, "line 1: invalid number 'zero'");
+        let error = parse_mtl("Kd 1 1 1\n").unwrap_err();
+        assert_eq!(error, "line 1: 'Kd' before any newmtl");
+    }
+}
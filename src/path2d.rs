@@ -0,0 +1,477 @@
+extern crate gl;
+
+use std::f32::consts::FRAC_PI_2;
+use std::ffi::c_void;
+use std::mem;
+use std::rc::Rc;
+
+use serde::Deserialize;
+use lyon_tessellation::math::{point, Point};
+use lyon_tessellation::path::Path;
+use lyon_tessellation::{
+    BuffersBuilder, FillOptions, FillTessellator, FillVertex, StrokeOptions, StrokeTessellator, StrokeVertex, VertexBuffers,
+};
+
+use crate::draw::{RenderContext, Shape};
+use crate::matrix4::Matrix4;
+use crate::mesh::MeshData;
+use crate::shader::Shader;
+use crate::vector2d::Vector2D;
+
+// Eğrileri düzleştirirken izin verilen en büyük sapma; 2B koordinatlarda yarım pikselden azdır
+pub const DEFAULT_TOLERANCE: f32 = 0.001;
+
+// Bir yol komutu. Eğriler kontrol noktalarıyla, yaylar kübik eğrilerle saklanır.
+#[derive(Debug, Clone, Copy)]
+pub enum PathCommand {
+    MoveTo(Vector2D),
+    LineTo(Vector2D),
+    QuadraticTo { control: Vector2D, to: Vector2D },
+    CubicTo { control1: Vector2D, control2: Vector2D, to: Vector2D },
+    Close,
+}
+
+// Çizgi birleşim biçimi
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum LineJoin {
+    Miter,
+    Round,
+    Bevel,
+}
+
+// Açık alt yolların uç biçimi
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum LineCap {
+    Butt,
+    Square,
+    Round,
+}
+
+// Kesişen veya iç içe alt yolların hangi bölgelerinin doldurulacağı
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum FillRule {
+    EvenOdd,
+    NonZero,
+}
+
+// Kontur çizim ayarları
+#[derive(Debug, Clone, Copy)]
+pub struct StrokeStyle {
+    pub width: f32,
+    pub join: LineJoin,
+    pub cap: LineCap,
+    pub miter_limit: f32,  // Miter joins longer than this many half widths fall back to bevels
+}
+
+impl StrokeStyle {
+    // Verilen kalınlıkta, sivri birleşimli ve düz uçlu bir kontur oluşturur
+    pub fn new(width: f32) -> StrokeStyle {
+        StrokeStyle { width, join: LineJoin::Miter, cap: LineCap::Butt, miter_limit: 4.0 }
+    }
+
+    // Birleşim biçimini değiştirir
+    pub fn with_join(mut self, join: LineJoin) -> StrokeStyle {
+        self.join = join;
+        self
+    }
+
+    // Uç biçimini değiştirir
+    pub fn with_cap(mut self, cap: LineCap) -> StrokeStyle {
+        self.cap = cap;
+        self
+    }
+
+    // Sivri birleşim sınırını değiştirir
+    pub fn with_miter_limit(mut self, miter_limit: f32) -> StrokeStyle {
+        self.miter_limit = miter_limit;
+        self
+    }
+}
+
+// Üçgenlenmiş yol: köşeler ve üçlü indeksler
+#[derive(Debug, Clone, Default)]
+pub struct Tessellation {
+    pub vertices: Vec<Vector2D>,
+    pub indices: Vec<u32>,
+}
+
+impl Tessellation {
+    // Üçgenlerin toplam alanını döndürür
+    pub fn area(&self) -> f32 {
+        self.indices
+            .chunks_exact(3)
+            .map(|t| {
+                let (a, b, c) = (self.vertices[t[0] as usize], self.vertices[t[1] as usize], self.vertices[t[2] as usize]);
+                ((b.x - a.x) * (c.y - a.y) - (c.x - a.x) * (b.y - a.y)).abs() / 2.0
+            })
+            .sum()
+    }
+
+    // Başka bir üçgenlemeyi bunun üzerine çizilecek şekilde sona ekler
+    pub fn append(&mut self, other: &Tessellation) {
+        let offset = self.vertices.len() as u32;
+        self.vertices.extend_from_slice(&other.vertices);
+        self.indices.extend(other.indices.iter().map(|i| i + offset));
+    }
+
+    // Köşelerin sınır kutusunu (en küçük, en büyük) döndürür; boşsa None döner
+    pub fn bounds(&self) -> Option<(Vector2D, Vector2D)> {
+        let first = *self.vertices.first()?;
+        Some(self.vertices.iter().fold((first, first), |(min, max), v| {
+            (Vector2D::new(min.x.min(v.x), min.y.min(v.y)), Vector2D::new(max.x.max(v.x), max.y.max(v.y)))
+        }))
+    }
+}
+
+// Doğru, eğri ve yaylardan oluşan, bir veya daha fazla alt yollu 2D yol
+#[derive(Debug, Clone)]
+pub struct Path2D {
+    commands: Vec<PathCommand>,
+    start: Option<Vector2D>,  // Start of the open subpath, if any
+    current: Vector2D,
+}
+
+impl Path2D {
+    // Boş bir yol oluşturur
+    pub fn new() -> Path2D {
+        Path2D { commands: Vec::new(), start: None, current: Vector2D::zero() }
+    }
+
+    // Noktaları doğrularla birleştiren bir yol oluşturur; `closed` ise son nokta ilkine bağlanır
+    pub fn polyline(points: &[Vector2D], closed: bool) -> Path2D {
+        let mut path = Path2D::new();
+        if let Some((first, rest)) = points.split_first() {
+            path = path.move_to(*first);
+            for point in rest {
+                path = path.line_to(*point);
+            }
+            if closed {
+                path = path.close();
+            }
+        }
+        path
+    }
+
+    // Verilen merkez ve yarıçapta kapalı bir çember yolu oluşturur
+    pub fn circle(center: Vector2D, radius: f32) -> Path2D {
+        Path2D::new().arc(center, radius, 0.0, std::f32::consts::TAU).close()
+    }
+
+    // Yeni bir alt yol başlatır
+    pub fn move_to(mut self, to: Vector2D) -> Path2D {
+        self.commands.push(PathCommand::MoveTo(to));
+        self.start = Some(to);
+        self.current = to;
+        self
+    }
+
+    // Alt yol yoksa son noktada (ilk komutta başlangıç noktasında) yeni bir alt yol başlatır
+    fn ensure_started(&mut self) {
+        if self.start.is_none() {
+            self.commands.push(PathCommand::MoveTo(self.current));
+            self.start = Some(self.current);
+        }
+    }
+
+    // Son noktadan verilen noktaya doğru ekler
+    pub fn line_to(mut self, to: Vector2D) -> Path2D {
+        self.ensure_started();
+        self.commands.push(PathCommand::LineTo(to));
+        self.current = to;
+        self
+    }
+
+    // İkinci dereceden Bezier eğrisi ekler
+    pub fn quadratic_to(mut self, control: Vector2D, to: Vector2D) -> Path2D {
+        self.ensure_started();
+        self.commands.push(PathCommand::QuadraticTo { control, to });
+        self.current = to;
+        self
+    }
+
+    // Kübik Bezier eğrisi ekler
+    pub fn cubic_to(mut self, control1: Vector2D, control2: Vector2D, to: Vector2D) -> Path2D {
+        self.ensure_started();
+        self.commands.push(PathCommand::CubicTo { control1, control2, to });
+        self.current = to;
+        self
+    }
+
+    // Çember yayı ekler. Açılar radyan cinsindendir; pozitif `sweep` saat yönünün tersidir.
+    // Açık bir alt yol varsa son noktadan yayın başına doğru çizilir, yoksa yayın başında yeni alt yol başlar.
+    pub fn arc(mut self, center: Vector2D, radius: f32, start_angle: f32, sweep: f32) -> Path2D {
+        let at = |angle: f32| center + Vector2D::new(angle.cos(), angle.sin()) * radius;
+        self = match self.start {
+            Some(_) => self.line_to(at(start_angle)),
+            None => self.move_to(at(start_angle)),
+        };
+
+        // Each piece spans at most a quarter turn, where a cubic stays within 0.03% of the circle
+        let pieces = (sweep.abs() / FRAC_PI_2).ceil().max(1.0) as usize;
+        let step = sweep / pieces as f32;
+        let handle = 4.0 / 3.0 * (step / 4.0).tan() * radius;
+        for i in 0..pieces {
+            let (a0, a1) = (start_angle + step * i as f32, start_angle + step * (i + 1) as f32);
+            let tangent = |angle: f32| Vector2D::new(-angle.sin(), angle.cos()) * handle;
+            self = self.cubic_to(at(a0) + tangent(a0), at(a1) - tangent(a1), at(a1));
+        }
+        self
+    }
+
+    // Alt yolu başlangıç noktasına bağlayarak kapatır
+    pub fn close(mut self) -> Path2D {
+        if let Some(start) = self.start.take() {
+            self.commands.push(PathCommand::Close);
+            self.current = start;
+        }
+        self
+    }
+
+    // Yol komutlarını döndürür
+    pub fn commands(&self) -> &[PathCommand] {
+        &self.commands
+    }
+
+    // Yolu lyon yoluna çevirir
+    fn to_lyon(&self) -> Path {
+        let p = |v: Vector2D| -> Point { point(v.x, v.y) };
+        let mut builder = Path::builder();
+        let mut open = false;
+        for command in &self.commands {
+            match *command {
+                PathCommand::MoveTo(to) => {
+                    if open {
+                        builder.end(false);
+                    }
+                    builder.begin(p(to));
+                    open = true;
+                }
+                PathCommand::LineTo(to) => {
+                    builder.line_to(p(to));
+                }
+                PathCommand::QuadraticTo { control, to } => {
+                    builder.quadratic_bezier_to(p(control), p(to));
+                }
+                PathCommand::CubicTo { control1, control2, to } => {
+                    builder.cubic_bezier_to(p(control1), p(control2), p(to));
+                }
+                PathCommand::Close => {
+                    builder.end(true);
+                    open = false;
+                }
+            }
+        }
+        if open {
+            builder.end(false);
+        }
+        builder.build()
+    }
+
+    // Yolun içini verilen kurala göre üçgenler; açık alt yollar kapalı sayılır
+    pub fn fill(&self, rule: FillRule, tolerance: f32) -> Result<Tessellation, String> {
+        let rule = match rule {
+            FillRule::EvenOdd => lyon_tessellation::FillRule::EvenOdd,
+            FillRule::NonZero => lyon_tessellation::FillRule::NonZero,
+        };
+        let options = FillOptions::tolerance(tolerance).with_fill_rule(rule);
+        let mut buffers: VertexBuffers<Vector2D, u32> = VertexBuffers::new();
+        FillTessellator::new()
+            .tessellate_path(&self.to_lyon(), &options, &mut BuffersBuilder::new(&mut buffers, |v: FillVertex| {
+                Vector2D::new(v.position().x, v.position().y)
+            }))
+            .map_err(|e| format!("failed to fill path: {}", e))?;
+        Ok(Tessellation { vertices: buffers.vertices, indices: buffers.indices })
+    }
+
+    // Yolun konturunu verilen kalınlık, birleşim ve uç biçimiyle üçgenler
+    pub fn stroke(&self, style: &StrokeStyle, tolerance: f32) -> Result<Tessellation, String> {
+        let join = match style.join {
+            LineJoin::Miter => lyon_tessellation::LineJoin::Miter,
+            LineJoin::Round => lyon_tessellation::LineJoin::Round,
+            LineJoin::Bevel => lyon_tessellation::LineJoin::Bevel,
+        };
+        let cap = match style.cap {
+            LineCap::Butt => lyon_tessellation::LineCap::Butt,
+            LineCap::Square => lyon_tessellation::LineCap::Square,
+            LineCap::Round => lyon_tessellation::LineCap::Round,
+        };
+        // lyon rejects miter limits below 1, which would make every join a bevel anyway
+        let options = StrokeOptions::tolerance(tolerance)
+            .with_line_width(style.width)
+            .with_line_join(join)
+            .with_line_cap(cap)
+            .with_miter_limit(style.miter_limit.max(StrokeOptions::MINIMUM_MITER_LIMIT));
+        let mut buffers: VertexBuffers<Vector2D, u32> = VertexBuffers::new();
+        StrokeTessellator::new()
+            .tessellate_path(&self.to_lyon(), &options, &mut BuffersBuilder::new(&mut buffers, |v: StrokeVertex| {
+                Vector2D::new(v.position().x, v.position().y)
+            }))
+            .map_err(|e| format!("failed to stroke path: {}", e))?;
+        Ok(Tessellation { vertices: buffers.vertices, indices: buffers.indices })
+    }
+}
+
+// Üçgenlenmiş bir yolu diğer 2D şekiller gibi verilen shader'la çizen şekil
+pub struct PathShape {
+    vao: gl::types::GLuint,
+    vbo: gl::types::GLuint,
+    ebo: gl::types::GLuint,
+    shader: Rc<Shader>,
+    tessellation: Tessellation,
+}
+
+impl PathShape {
+    // Üçgenlenmiş yoldan bir şekil oluşturur
+    pub fn new(shader: Rc<Shader>, tessellation: Tessellation) -> PathShape {
+        let mut shape = PathShape { vao: 0, vbo: 0, ebo: 0, shader, tessellation };
+        shape.init();
+        shape
+    }
+
+    // Köşelerin sınır kutusuna göre (0-1 aralığında) doku koordinatlarını döndürür
+    fn uvs(&self) -> Vec<[f32; 2]> {
+        let Some((min, max)) = self.tessellation.bounds() else {
+            return Vec::new();
+        };
+        let size = Vector2D::new((max.x - min.x).max(f32::EPSILON), (max.y - min.y).max(f32::EPSILON));
+        self.tessellation.vertices.iter().map(|v| [(v.x - min.x) / size.x, (v.y - min.y) / size.y]).collect()
+    }
+}
+
+impl Shape for PathShape {
+    // Köşeleri (konum, doku koordinatı) ve indeksleri OpenGL'e yükler
+    fn init(&mut self) {
+        let mut vertices: Vec<f32> = Vec::with_capacity(self.tessellation.vertices.len() * 6);
+        for (v, uv) in self.tessellation.vertices.iter().zip(self.uvs()) {
+            vertices.extend_from_slice(&[v.x, v.y, 0.0, uv[0], uv[1], 0.0]);
+        }
+        let stride = (6 * mem::size_of::<f32>()) as gl::types::GLsizei;
+
+        unsafe {
+            gl::GenVertexArrays(1, &mut self.vao);
+            gl::GenBuffers(1, &mut self.vbo);
+            gl::GenBuffers(1, &mut self.ebo);
+
+            gl::BindVertexArray(self.vao);
+
+            gl::BindBuffer(gl::ARRAY_BUFFER, self.vbo);
+            gl::BufferData(
+                gl::ARRAY_BUFFER,
+                (vertices.len() * mem::size_of::<f32>()) as gl::types::GLsizeiptr,
+                vertices.as_ptr() as *const c_void,
+                gl::STATIC_DRAW,
+            );
+            gl::BindBuffer(gl::ELEMENT_ARRAY_BUFFER, self.ebo);
+            gl::BufferData(
+                gl::ELEMENT_ARRAY_BUFFER,
+                (self.tessellation.indices.len() * mem::size_of::<u32>()) as gl::types::GLsizeiptr,
+                self.tessellation.indices.as_ptr() as *const c_void,
+                gl::STATIC_DRAW,
+            );
+
+            gl::VertexAttribPointer(0, 3, gl::FLOAT, gl::FALSE, stride, std::ptr::null());
+            gl::EnableVertexAttribArray(0);
+            gl::VertexAttribPointer(1, 3, gl::FLOAT, gl::FALSE, stride, (3 * mem::size_of::<f32>()) as *const c_void);
+            gl::EnableVertexAttribArray(1);
+
+            gl::BindVertexArray(0);
+        }
+    }
+
+    // Yolu çizer
+    fn draw(&self, ctx: &RenderContext, model: &Matrix4) {
+        self.shader.use_program();
+        ctx.apply_2d(&self.shader, model);
+        unsafe {
+            gl::BindVertexArray(self.vao);
+            gl::DrawElements(gl::TRIANGLES, self.tessellation.indices.len() as i32, gl::UNSIGNED_INT, std::ptr::null());
+            gl::BindVertexArray(0);
+        }
+    }
+
+    // Yolun üçgenlerini z = 0 düzleminde döndürür
+    fn mesh_data(&self) -> Option<MeshData> {
+        Some(MeshData {
+            positions: self.tessellation.vertices.iter().map(|v| [v.x, v.y, 0.0]).collect(),
+            normals: vec![[0.0, 0.0, 1.0]; self.tessellation.vertices.len()],
+            uvs: self.uvs(),
+            indices: self.tessellation.indices.clone(),
+        })
+    }
+}
+
+impl Drop for PathShape {
+    // Şekil silindiğinde OpenGL kaynaklarını temizler
+    fn drop(&mut self) {
+        unsafe {
+            gl::DeleteVertexArrays(1, &self.vao);
+            gl::DeleteBuffers(1, &self.vbo);
+            gl::DeleteBuffers(1, &self.ebo);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(min: f32, max: f32) -> Path2D {
+        let points = [Vector2D::new(min, min), Vector2D::new(max, min), Vector2D::new(max, max), Vector2D::new(min, max)];
+        Path2D::polyline(&points, true)
+    }
+
+    fn approx(a: f32, b: f32, epsilon: f32) -> bool {
+        (a - b).abs() <= epsilon
+    }
+
+    #[test]
+    fn fill_rules_differ_on_overlaps() {
+        let mut path = square(0.0, 2.0);
+        for command in square(1.0, 3.0).commands() {
+            path.commands.push(*command);
+        }
+        let non_zero = path.fill(FillRule::NonZero, DEFAULT_TOLERANCE).unwrap();
+        let even_odd = path.fill(FillRule::EvenOdd, DEFAULT_TOLERANCE).unwrap();
+        assert!(approx(non_zero.area(), 7.0, 1e-4));
+        assert!(approx(even_odd.area(), 6.0, 1e-4));
+        assert_eq!(non_zero.indices.len() % 3, 0);
+
+        // A self-intersecting bow tie covers its two triangles under either rule
+        let bow_tie = Path2D::polyline(
+            &[Vector2D::new(0.0, 0.0), Vector2D::new(2.0, 2.0), Vector2D::new(2.0, 0.0), Vector2D::new(0.0, 2.0)],
+            true,
+        );
+        assert!(approx(bow_tie.fill(FillRule::EvenOdd, DEFAULT_TOLERANCE).unwrap().area(), 2.0, 1e-4));
+    }
+
+    #[test]
+    fn curves_and_arcs_are_flattened() {
+        let circle = Path2D::circle(Vector2D::new(1.0, -1.0), 2.0).fill(FillRule::NonZero, 0.001).unwrap();
+        assert!(approx(circle.area(), std::f32::consts::PI * 4.0, 0.02));
+        let (min, max) = circle.bounds().unwrap();
+        assert!(approx(min.x, -1.0, 1e-3) && approx(max.y, 1.0, 1e-3));
+
+        // The filled area under a quadratic from (0,0) through control (1,2) to (2,0) is 4/3
+        let hump = Path2D::new().quadratic_to(Vector2D::new(1.0, 2.0), Vector2D::new(2.0, 0.0)).close();
+        assert!(approx(hump.fill(FillRule::NonZero, 0.0001).unwrap().area(), 4.0 / 3.0, 1e-3));
+    }
+
+    #[test]
+    fn stroke_caps_extend_open_ends() {
+        let line = Path2D::polyline(&[Vector2D::new(0.0, 0.0), Vector2D::new(10.0, 0.0)], false);
+        let area = |cap| line.stroke(&StrokeStyle::new(2.0).with_cap(cap), 0.001).unwrap().area();
+        assert!(approx(area(LineCap::Butt), 20.0, 1e-3));
+        assert!(approx(area(LineCap::Square), 24.0, 1e-3));
+        assert!(approx(area(LineCap::Round), 20.0 + std::f32::consts::PI, 0.01));
+    }
+
+    #[test]
+    fn stroke_joins_fill_corners_differently() {
+        let corner = Path2D::polyline(&[Vector2D::new(0.0, 0.0), Vector2D::new(10.0, 0.0), Vector2D::new(10.0, 10.0)], false);
+        let area = |join| corner.stroke(&StrokeStyle::new(2.0).with_join(join), 0.001).unwrap().area();
+        let (miter, round, bevel) = (area(LineJoin::Miter), area(LineJoin::Round), area(LineJoin::Bevel));
+        // The outer corner adds a unit square for miter, a quarter disc for round and half a square for bevel
+        assert!(approx(miter - bevel, 0.5, 1e-3));
+        assert!(approx(round - bevel, std::f32::consts::FRAC_PI_4 - 0.5, 0.01));
+    }
+}
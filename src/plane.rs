@@ -0,0 +1,135 @@
+use crate::shader::Shader;
+use crate::draw::{RenderContext, Shape};
+use crate::material::Material;
+use crate::matrix4::Matrix4;
+use crate::mesh::MeshData;
+use crate::vector3d::Vector3D;
+use std::rc::Rc;
+use std::ffi::c_void;
+use std::mem;
+
+// Yukarı (+Y) bakan yatay bir düzlem, örneğin gölgelerin düştüğü zemin
+pub struct Plane {
+    vao: gl::types::GLuint,
+    vbo: gl::types::GLuint,
+    shader: Rc<Shader>,
+    pub material: Material,
+    center: Vector3D,
+    half_size: f32,
+}
+
+impl Plane {
+    // Yeni bir düzlem oluşturur
+    pub fn new(shader: Rc<Shader>, center: Vector3D, half_size: f32) -> Self {
+        let mut plane = Plane {
+            vao: 0,
+            vbo: 0,
+            shader,
+            material: Material::default(),
+            center,
+            half_size,
+        };
+        plane.init();
+        plane
+    }
+
+    // Düzlem köşe noktalarını ve normallerini oluşturur
+    fn generate_vertices(&self) -> [f32; 36] {
+        let c = self.center;
+        let h = self.half_size;
+
+        [
+            c.x - h, c.y, c.z + h, 0.0, 1.0, 0.0,
+            c.x + h, c.y, c.z + h, 0.0, 1.0, 0.0,
+            c.x + h, c.y, c.z - h, 0.0, 1.0, 0.0,
+            c.x + h, c.y, c.z - h, 0.0, 1.0, 0.0,
+            c.x - h, c.y, c.z - h, 0.0, 1.0, 0.0,
+            c.x - h, c.y, c.z + h, 0.0, 1.0, 0.0,
+        ]
+    }
+}
+
+impl Shape for Plane {
+    // Düzlemi başlatır
+    fn init(&mut self) {
+        let vertices = self.generate_vertices();
+
+        unsafe {
+            gl::GenVertexArrays(1, &mut self.vao);
+            gl::GenBuffers(1, &mut self.vbo);
+
+            gl::BindVertexArray(self.vao);
+
+            gl::BindBuffer(gl::ARRAY_BUFFER, self.vbo);
+            gl::BufferData(
+                gl::ARRAY_BUFFER,
+                (vertices.len() * mem::size_of::<f32>()) as gl::types::GLsizeiptr,
+                vertices.as_ptr() as *const c_void,
+                gl::STATIC_DRAW,
+            );
+
+            gl::VertexAttribPointer(
+                0,
+                3,
+                gl::FLOAT,
+                gl::FALSE,
+                6 * mem::size_of::<f32>() as gl::types::GLsizei,
+                std::ptr::null(),
+            );
+            gl::EnableVertexAttribArray(0);
+
+            gl::VertexAttribPointer(
+                1,
+                3,
+                gl::FLOAT,
+                gl::FALSE,
+                6 * mem::size_of::<f32>() as gl::types::GLsizei,
+                (3 * mem::size_of::<f32>()) as *const c_void,
+            );
+            gl::EnableVertexAttribArray(1);
+
+            gl::BindBuffer(gl::ARRAY_BUFFER, 0);
+            gl::BindVertexArray(0);
+        }
+    }
+
+    // Düzlemi çizer
+    fn draw(&self, ctx: &RenderContext, model: &Matrix4) {
+        self.shader.use_program();
+        ctx.apply(&self.shader);
+        self.shader.set_mat4("model", model);
+        self.material.apply(&self.shader, ctx);
+
+        unsafe {
+            gl::BindVertexArray(self.vao);
+            gl::DrawArrays(gl::TRIANGLES, 0, 6);
+            gl::BindVertexArray(0);
+        }
+    }
+
+    // Düzlemin iki üçgenini döndürür
+    fn mesh_data(&self) -> Option<MeshData> {
+        let vertices = self.generate_vertices();
+        Some(MeshData {
+            positions: vertices.chunks_exact(6).map(|v| [v[0], v[1], v[2]]).collect(),
+            normals: vertices.chunks_exact(6).map(|v| [v[3], v[4], v[5]]).collect(),
+            uvs: Vec::new(),
+            indices: (0..6).collect(),
+        })
+    }
+
+    // Şeklin malzemesini döndürür
+    fn material_mut(&mut self) -> Option<&mut Material> {
+        Some(&mut self.material)
+    }
+}
+
+impl Drop for Plane {
+    // Düzlem silindiğinde OpenGL kaynaklarını temizler
+    fn drop(&mut self) {
+        unsafe {
+            gl::DeleteVertexArrays(1, &self.vao);
+            gl::DeleteBuffers(1, &self.vbo);
+        }
+    }
+}
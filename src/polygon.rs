@@ -0,0 +1,381 @@
+extern crate gl;
+
+use crate::shader::Shader;
+use std::ptr;
+use gl::types::{GLuint, GLfloat};
+use crate::draw::{RenderContext, Shape};
+use crate::matrix4::Matrix4;
+use crate::mesh::MeshData;
+use std::rc::Rc;
+use crate::vector2d::Vector2D;
+
+// İki kenar vektörünün çapraz çarpımı; pozitifse a -> b -> c saat yönünün tersine döner
+fn cross(a: Vector2D, b: Vector2D, c: Vector2D) -> f32 {
+    (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)
+}
+
+// Halkanın işaretli alanı; saat yönünün tersindeki halkalar için pozitiftir
+fn signed_area(ring: &[Vector2D]) -> f32 {
+    let mut area = 0.0;
+    for i in 0..ring.len() {
+        let (a, b) = (ring[i], ring[(i + 1) % ring.len()]);
+        area += a.x * b.y - b.x * a.y;
+    }
+    area / 2.0
+}
+
+// p noktası abc üçgeninin içinde veya kenarındaysa true döndürür (abc saat yönünün tersinde olmalıdır)
+fn in_triangle(p: Vector2D, a: Vector2D, b: Vector2D, c: Vector2D) -> bool {
+    cross(a, b, p) >= 0.0 && cross(b, c, p) >= 0.0 && cross(c, a, p) >= 0.0
+}
+
+fn same(a: Vector2D, b: Vector2D) -> bool {
+    a.x == b.x && a.y == b.y
+}
+
+// p noktası, ab ile aynı doğrudaysa ab parçasının üzerinde mi
+fn on_segment(p: Vector2D, a: Vector2D, b: Vector2D) -> bool {
+    p.x >= a.x.min(b.x) && p.x <= a.x.max(b.x) && p.y >= a.y.min(b.y) && p.y <= a.y.max(b.y)
+}
+
+// ab ve cd parçaları kesişiyor veya birbirine değiyorsa true döndürür
+fn segments_touch(a: Vector2D, b: Vector2D, c: Vector2D, d: Vector2D) -> bool {
+    let (d1, d2, d3, d4) = (cross(c, d, a), cross(c, d, b), cross(a, b, c), cross(a, b, d));
+    if d1 * d2 < 0.0 && d3 * d4 < 0.0 {
+        return true;
+    }
+    (d1 == 0.0 && on_segment(a, c, d))
+        || (d2 == 0.0 && on_segment(b, c, d))
+        || (d3 == 0.0 && on_segment(c, a, b))
+        || (d4 == 0.0 && on_segment(d, a, b))
+}
+
+// Dış halka ve deliklerin kenarlarından komşu olmayan herhangi ikisi kesişiyor veya değiyorsa hata döndürür.
+// Kulak kırpma yalnızca basit çokgenlerde doğru sonuç verir.
+fn check_simple(outer: &[Vector2D], holes: &[Vec<Vector2D>]) -> Result<(), String> {
+    let rings: Vec<&[Vector2D]> = std::iter::once(outer).chain(holes.iter().map(Vec::as_slice)).collect();
+    let edges: Vec<(usize, usize)> = rings.iter().enumerate().flat_map(|(r, ring)| (0..ring.len()).map(move |i| (r, i))).collect();
+    let points = |(r, i): (usize, usize)| (rings[r][i], rings[r][(i + 1) % rings[r].len()]);
+    let name = |(r, i): (usize, usize)| {
+        let end = (i + 1) % rings[r].len();
+        match r {
+            0 => format!("edge {}-{} of the outer ring", i, end),
+            _ => format!("edge {}-{} of hole {}", i, end, r - 1),
+        }
+    };
+
+    // Quadratic, which is fine for hand-written scene polygons
+    for (k, &first) in edges.iter().enumerate() {
+        for &second in &edges[k + 1..] {
+            // Consecutive edges of a ring always share a corner
+            let n = rings[first.0].len();
+            if first.0 == second.0 && (second.1 == first.1 + 1 || (first.1 == 0 && second.1 == n - 1)) {
+                continue;
+            }
+            let ((a, b), (c, d)) = (points(first), points(second));
+            if segments_touch(a, b, c, d) {
+                return Err(format!("polygon is self-intersecting: {} touches {}", name(first), name(second)));
+            }
+        }
+    }
+    Ok(())
+}
+
+// Deliği, en sağdaki köşesinden dış halkada görünen bir köşeye köprüyle bağlayıp halkaya ekler
+// (Eberly, "Triangulation by Ear Clipping"). Halka ve delik köşe indeksleri listesidir.
+fn bridge_hole(ring: &mut Vec<usize>, hole: &[usize], points: &[Vector2D]) -> Result<(), String> {
+    let (hole_start, &m) = hole
+        .iter()
+        .enumerate()
+        .max_by(|a, b| points[*a.1].x.total_cmp(&points[*b.1].x))
+        .ok_or("empty hole")?;
+    let mp = points[m];
+
+    // Cast a ray from M towards +x and find the closest ring edge it hits
+    let mut closest: Option<(f32, usize)> = None;
+    for i in 0..ring.len() {
+        let (a, b) = (points[ring[i]], points[ring[(i + 1) % ring.len()]]);
+        if (a.y > mp.y) == (b.y > mp.y) {
+            continue;
+        }
+        let x = a.x + (mp.y - a.y) / (b.y - a.y) * (b.x - a.x);
+        if x >= mp.x && closest.is_none_or(|(best, _)| x < best) {
+            closest = Some((x, i));
+        }
+    }
+    let (hit_x, edge) = closest.ok_or("hole is not inside the outer ring")?;
+    let hit = Vector2D::new(hit_x, mp.y);
+
+    // The edge endpoint with the larger x is visible unless a reflex ring vertex lies inside triangle (M, hit, P);
+    // in that case the one closest in angle to the ray is visible instead
+    let (ea, eb) = (ring[edge], ring[(edge + 1) % ring.len()]);
+    let mut visible = if points[ea].x > points[eb].x { edge } else { (edge + 1) % ring.len() };
+    let p = points[ring[visible]];
+    let (t0, t1, t2) = if cross(mp, hit, p) >= 0.0 { (mp, hit, p) } else { (mp, p, hit) };
+    let mut best_angle = f32::MAX;
+    for i in 0..ring.len() {
+        let v = points[ring[i]];
+        let (prev, next) = (points[ring[(i + ring.len() - 1) % ring.len()]], points[ring[(i + 1) % ring.len()]]);
+        if i == visible || cross(prev, v, next) > 0.0 || same(v, p) || !in_triangle(v, t0, t1, t2) {
+            continue;
+        }
+        let d = v - mp;
+        let angle = d.y.abs().atan2(d.x);
+        if angle < best_angle {
+            best_angle = angle;
+            visible = i;
+        }
+    }
+
+    // Splice: ... P, M, hole..., M, P ...
+    let mut spliced = Vec::with_capacity(hole.len() + 2);
+    spliced.extend((0..=hole.len()).map(|k| hole[(hole_start + k) % hole.len()]));
+    spliced.push(ring[visible]);
+    ring.splice(visible + 1..visible + 1, spliced);
+    Ok(())
+}
+
+// Basit bir çokgeni (içbükey olabilir, delikli olabilir) kulak kırpma yöntemiyle üçgenler; kenarları kesişen
+// çokgenler reddedilir.
+// Köşeler dış halka ve ardından deliklerin sırasıyla birleştirilmiş hâlidir; dönen indeksler bu listeye göredir
+// ve üçgenler saat yönünün tersindedir. Halkaların yönü önemsizdir.
+pub fn triangulate(outer: &[Vector2D], holes: &[Vec<Vector2D>]) -> Result<Vec<u32>, String> {
+    if outer.len() < 3 {
+        return Err(format!("polygon needs at least 3 points, got {}", outer.len()));
+    }
+    if let Some(hole) = holes.iter().find(|hole| hole.len() < 3) {
+        return Err(format!("polygon holes need at least 3 points, got {}", hole.len()));
+    }
+    check_simple(outer, holes)?;
+    if signed_area(outer) == 0.0 {
+        return Err("polygon has zero area".to_string());
+    }
+
+    let points: Vec<Vector2D> = outer.iter().chain(holes.iter().flatten()).copied().collect();
+
+    // The outer ring runs counter-clockwise and holes clockwise
+    let mut ring: Vec<usize> = (0..outer.len()).collect();
+    if signed_area(outer) < 0.0 {
+        ring.reverse();
+    }
+    let mut hole_rings = Vec::with_capacity(holes.len());
+    let mut offset = outer.len();
+    for hole in holes {
+        let mut indices: Vec<usize> = (offset..offset + hole.len()).collect();
+        if signed_area(hole) > 0.0 {
+            indices.reverse();
+        }
+        offset += hole.len();
+        hole_rings.push(indices);
+    }
+
+    // Holes are bridged from right to left so earlier bridges never cross later rays
+    hole_rings.sort_by(|a, b| {
+        let max_x = |ring: &Vec<usize>| ring.iter().map(|&i| points[i].x).fold(f32::MIN, f32::max);
+        max_x(b).total_cmp(&max_x(a))
+    });
+    for hole in &hole_rings {
+        bridge_hole(&mut ring, hole, &points)?;
+    }
+
+    let mut indices = Vec::with_capacity((ring.len() - 2) * 3);
+    while ring.len() > 3 {
+        let n = ring.len();
+        let is_ear = |i: usize| {
+            let (a, b, c) = (points[ring[(i + n - 1) % n]], points[ring[i]], points[ring[(i + 1) % n]]);
+            if cross(a, b, c) <= 0.0 {
+                return false;
+            }
+            // Bridge duplicates share positions with the ear's own corners and do not block it
+            ring.iter().all(|&j| {
+                let p = points[j];
+                same(p, a) || same(p, b) || same(p, c) || !in_triangle(p, a, b, c)
+            })
+        };
+        // A simple polygon always has an ear, but rounding on nearly degenerate input can hide it;
+        // any convex corner keeps progress there
+        let ear = (0..n)
+            .find(|&i| is_ear(i))
+            .or_else(|| (0..n).find(|&i| cross(points[ring[(i + n - 1) % n]], points[ring[i]], points[ring[(i + 1) % n]]) > 0.0))
+            .ok_or("polygon is self-intersecting or degenerate")?;
+        indices.extend_from_slice(&[ring[(ear + n - 1) % n] as u32, ring[ear] as u32, ring[(ear + 1) % n] as u32]);
+        ring.remove(ear);
+    }
+    indices.extend(ring.iter().map(|&i| i as u32));
+    Ok(indices)
+}
+
+pub struct Polygon {
+    vao: GLuint,
+    vbo: GLuint,
+    shader: Rc<Shader>,
+    vertices: Vec<Vector2D>,
+    indices: Vec<u32>,
+}
+
+impl Polygon {
+    // Dış halkası ve delikleri verilen bir çokgen oluşturur; çokgen üçgenlenemezse hata döndürür
+    pub fn new(shader: Rc<Shader>, outer: &[Vector2D], holes: &[Vec<Vector2D>]) -> Result<Polygon, String> {
+        let indices = triangulate(outer, holes)?;
+        let mut polygon = Polygon {
+            vao: 0,
+            vbo: 0,
+            shader,
+            vertices: outer.iter().chain(holes.iter().flatten()).copied().collect(),
+            indices,
+        };
+        polygon.init();
+        Ok(polygon)
+    }
+
+    // Köşelerin sınır kutusuna göre (0-1 aralığında) doku koordinatlarını döndürür
+    fn uvs(&self) -> Vec<[f32; 2]> {
+        let (mut min, mut max) = (self.vertices[0], self.vertices[0]);
+        for v in &self.vertices {
+            min = Vector2D::new(min.x.min(v.x), min.y.min(v.y));
+            max = Vector2D::new(max.x.max(v.x), max.y.max(v.y));
+        }
+        let size = Vector2D::new((max.x - min.x).max(f32::EPSILON), (max.y - min.y).max(f32::EPSILON));
+        self.vertices.iter().map(|v| [(v.x - min.x) / size.x, (v.y - min.y) / size.y]).collect()
+    }
+}
+
+impl Shape for Polygon {
+    // Üçgenleri Triangle ile aynı köşe düzeninde (konum, doku koordinatı ve z = 1) OpenGL'e yükler
+    fn init(&mut self) {
+        let uvs = self.uvs();
+        let mut vertices: Vec<GLfloat> = Vec::with_capacity(self.indices.len() * 6);
+        for &i in &self.indices {
+            let (v, uv) = (self.vertices[i as usize], uvs[i as usize]);
+            vertices.extend_from_slice(&[v.x, v.y, 0.0, uv[0], uv[1], 1.0]);
+        }
+
+        unsafe {
+            gl::GenVertexArrays(1, &mut self.vao);
+            gl::GenBuffers(1, &mut self.vbo);
+
+            gl::BindVertexArray(self.vao);
+
+            gl::BindBuffer(gl::ARRAY_BUFFER, self.vbo);
+            gl::BufferData(
+                gl::ARRAY_BUFFER,
+                (vertices.len() * std::mem::size_of::<GLfloat>()) as isize,
+                vertices.as_ptr() as *const _,
+                gl::STATIC_DRAW,
+            );
+
+            gl::VertexAttribPointer(0, 3, gl::FLOAT, gl::FALSE, 6 * std::mem::size_of::<GLfloat>() as i32, ptr::null());
+            gl::EnableVertexAttribArray(0);
+
+            gl::VertexAttribPointer(1, 3, gl::FLOAT, gl::FALSE, 6 * std::mem::size_of::<GLfloat>() as i32, (3 * std::mem::size_of::<GLfloat>()) as *const _);
+            gl::EnableVertexAttribArray(1);
+
+            gl::BindVertexArray(0);
+        }
+    }
+
+    // Çokgeni çizer
+    fn draw(&self, ctx: &RenderContext, model: &Matrix4) {
+        self.shader.use_program();
+        ctx.apply_2d(&self.shader, model);
+        unsafe {
+            gl::BindVertexArray(self.vao);
+            gl::DrawArrays(gl::TRIANGLES, 0, self.indices.len() as i32);
+            gl::BindVertexArray(0);
+        }
+    }
+
+    // Çokgenin üçgenlerini z = 0 düzleminde döndürür
+    fn mesh_data(&self) -> Option<MeshData> {
+        Some(MeshData {
+            positions: self.vertices.iter().map(|v| [v.x, v.y, 0.0]).collect(),
+            normals: vec![[0.0, 0.0, 1.0]; self.vertices.len()],
+            uvs: self.uvs(),
+            indices: self.indices.clone(),
+        })
+    }
+}
+
+impl Drop for Polygon {
+    // Çokgen silindiğinde OpenGL kaynaklarını temizler
+    fn drop(&mut self) {
+        unsafe {
+            gl::DeleteVertexArrays(1, &self.vao);
+            gl::DeleteBuffers(1, &self.vbo);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points(coords: &[(f32, f32)]) -> Vec<Vector2D> {
+        coords.iter().map(|&(x, y)| Vector2D::new(x, y)).collect()
+    }
+
+    // Üçgenlerin toplam alanı; saat yönündeki bir üçgen alanı azaltır
+    fn area(vertices: &[Vector2D], indices: &[u32]) -> f32 {
+        indices.chunks_exact(3).map(|t| cross(vertices[t[0] as usize], vertices[t[1] as usize], vertices[t[2] as usize]) / 2.0).sum()
+    }
+
+    #[test]
+    fn concave_polygons_keep_their_area() {
+        // An L shape given clockwise
+        let outer = points(&[(0.0, 0.0), (0.0, 2.0), (1.0, 2.0), (1.0, 1.0), (2.0, 1.0), (2.0, 0.0)]);
+        let indices = triangulate(&outer, &[]).unwrap();
+        assert_eq!(indices.len(), (outer.len() - 2) * 3);
+        assert!((area(&outer, &indices) - 3.0).abs() < 1e-5);
+
+        let comb = points(&[(0.0, 0.0), (4.0, 0.0), (4.0, 3.0), (3.0, 1.0), (2.0, 3.0), (1.0, 1.0), (0.0, 3.0)]);
+        let indices = triangulate(&comb, &[]).unwrap();
+        assert!((area(&comb, &indices) - signed_area(&comb)).abs() < 1e-5);
+    }
+
+    #[test]
+    fn holes_are_cut_out() {
+        let outer = points(&[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)]);
+        let holes = vec![
+            points(&[(2.0, 2.0), (4.0, 2.0), (4.0, 4.0), (2.0, 4.0)]),
+            points(&[(6.0, 6.0), (8.0, 6.0), (7.0, 8.0)]),
+            points(&[(6.0, 2.0), (8.0, 2.0), (8.0, 3.0), (6.0, 3.0)]),
+        ];
+        let vertices: Vec<Vector2D> = outer.iter().chain(holes.iter().flatten()).copied().collect();
+        let indices = triangulate(&outer, &holes).unwrap();
+        // Each hole adds two bridge vertices, so n + 2h - 2 triangles
+        assert_eq!(indices.len() / 3, vertices.len() + 2 * holes.len() - 2);
+        assert!((area(&vertices, &indices) - (100.0 - 4.0 - 2.0 - 2.0)).abs() < 1e-4);
+    }
+
+    #[test]
+    fn invalid_polygons_are_rejected() {
+        assert!(triangulate(&points(&[(0.0, 0.0), (1.0, 0.0)]), &[]).is_err());
+        assert!(triangulate(&points(&[(0.0, 0.0), (1.0, 0.0), (2.0, 0.0)]), &[]).is_err());
+        let outer = points(&[(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)]);
+        let outside = points(&[(5.0, 5.0), (6.0, 5.0), (5.0, 6.0)]);
+        assert!(triangulate(&outer, &[outside]).is_err());
+    }
+
+    #[test]
+    fn self_intersecting_polygons_are_rejected() {
+        let bow_tie = points(&[(0.0, 0.0), (2.0, 2.0), (2.0, 0.0), (0.0, 1.0)]);
+        assert_eq!(
+            triangulate(&bow_tie, &[]).unwrap_err(),
+            "polygon is self-intersecting: edge 0-1 of the outer ring touches edge 2-3 of the outer ring"
+        );
+
+        // A hole poking out of the outer ring, and a hole touching another at a corner
+        let outer = points(&[(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)]);
+        let poking = points(&[(1.0, 1.0), (5.0, 1.0), (5.0, 2.0), (1.0, 2.0)]);
+        let error = triangulate(&outer, &[poking]).unwrap_err();
+        assert!(error.ends_with("edge 1-2 of the outer ring touches edge 0-1 of hole 0"), "{}", error);
+        let a = points(&[(1.0, 1.0), (2.0, 1.0), (2.0, 2.0)]);
+        let b = points(&[(2.0, 2.0), (3.0, 2.0), (3.0, 3.0)]);
+        assert!(triangulate(&outer, &[a.clone(), b]).is_err());
+
+        // Collinear neighbours are still simple
+        let collinear = points(&[(0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (2.0, 1.0), (0.0, 1.0)]);
+        assert!(triangulate(&collinear, &[]).is_ok());
+        assert!(triangulate(&outer, &[a]).is_ok());
+    }
+}
@@ -0,0 +1,619 @@
+extern crate gl;
+
+use gl::types::GLuint;
+
+use crate::framebuffer::{Framebuffer, FramebufferSpec};
+use crate::shader::Shader;
+use crate::texture::{Texture, TextureFormat};
+
+// Tam ekran üçgeni çizmek için boş VAO (köşeler vertex shader'da üretilir)
+pub struct FullscreenQuad {
+    vao: GLuint,
+}
+
+impl FullscreenQuad {
+    // Yeni bir tam ekran çizici oluşturur
+    pub fn new() -> FullscreenQuad {
+        let mut vao = 0;
+        unsafe {
+            gl::GenVertexArrays(1, &mut vao);
+        }
+        FullscreenQuad { vao }
+    }
+
+    // Bağlı shader ile tüm hedefi kaplayan üçgeni çizer
+    pub fn draw(&self) {
+        unsafe {
+            gl::BindVertexArray(self.vao);
+            gl::DrawArrays(gl::TRIANGLES, 0, 3);
+            gl::BindVertexArray(0);
+        }
+    }
+}
+
+impl Drop for FullscreenQuad {
+    // Çizici silindiğinde OpenGL kaynaklarını temizler
+    fn drop(&mut self) {
+        unsafe {
+            gl::DeleteVertexArrays(1, &self.vao);
+        }
+    }
+}
+
+// Tam ekran vertex shader'ı ile shader klasöründeki fragment shader'ını yükler
+fn post_shader(fragment_name: &str) -> Shader {
+    Shader::load("fullscreen_vertex_shader.glsl", fragment_name)
+}
+
+// Giriş dokusunu bağlar, hedefe geçer ve tam ekran geçişini çizer
+fn run_pass(shader: &Shader, input: &Texture, output: &Framebuffer, quad: &FullscreenQuad) {
+    output.bind();
+    shader.use_program();
+    input.bind(0);
+    shader.set_int("screenTexture", 0);
+    quad.draw();
+}
+
+pub trait PostEffect {
+    // Efektin adını döndürür
+    fn name(&self) -> &str;
+
+    // Efektin etkin olup olmadığını döndürür
+    fn enabled(&self) -> bool;
+
+    // Efekti açar veya kapatır
+    fn set_enabled(&mut self, enabled: bool);
+
+    // Giriş dokusunu işleyip sonucu çıkış framebuffer'ına çizer
+    fn apply(&mut self, input: &Texture, output: &Framebuffer, quad: &FullscreenQuad);
+
+    // Efekt doğrusal renkleri ekran için sRGB'ye kodluyorsa true döndürür
+    fn encodes_srgb(&self) -> bool {
+        false
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToneMapOperator {
+    Reinhard,
+    Aces,
+}
+
+pub struct ToneMapping {
+    shader: Shader,
+    enabled: bool,
+    pub exposure: f32,
+    pub operator: ToneMapOperator,
+}
+
+impl ToneMapping {
+    // Yeni bir ton eşleme efekti oluşturur
+    pub fn new(exposure: f32, operator: ToneMapOperator) -> ToneMapping {
+        ToneMapping {
+            shader: post_shader("tonemap_fragment_shader.glsl"),
+            enabled: true,
+            exposure,
+            operator,
+        }
+    }
+}
+
+impl PostEffect for ToneMapping {
+    fn name(&self) -> &str {
+        "tone mapping"
+    }
+
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&mut self, input: &Texture, output: &Framebuffer, quad: &FullscreenQuad) {
+        self.shader.use_program();
+        self.shader.set_float("exposure", self.exposure);
+        self.shader.set_int("operatorType", (self.operator == ToneMapOperator::Aces) as i32);
+        run_pass(&self.shader, input, output, quad);
+    }
+}
+
+pub struct GammaCorrection {
+    shader: Shader,
+    enabled: bool,
+    pub gamma: f32,
+}
+
+impl GammaCorrection {
+    // Yeni bir gama düzeltme efekti oluşturur
+    pub fn new(gamma: f32) -> GammaCorrection {
+        GammaCorrection {
+            shader: post_shader("gamma_fragment_shader.glsl"),
+            enabled: true,
+            gamma,
+        }
+    }
+}
+
+impl PostEffect for GammaCorrection {
+    fn name(&self) -> &str {
+        "gamma correction"
+    }
+
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&mut self, input: &Texture, output: &Framebuffer, quad: &FullscreenQuad) {
+        self.shader.use_program();
+        self.shader.set_float("gamma", self.gamma);
+        run_pass(&self.shader, input, output, quad);
+    }
+
+    fn encodes_srgb(&self) -> bool {
+        true
+    }
+}
+
+pub struct Bloom {
+    extract_shader: Shader,
+    blur_shader: Shader,
+    combine_shader: Shader,
+    targets: Option<[Framebuffer; 2]>,
+    enabled: bool,
+    pub threshold: f32,
+    pub soft_knee: f32,
+    pub intensity: f32,
+    pub blur_passes: u32,
+}
+
+impl Bloom {
+    // Yeni bir parlama (bloom) efekti oluşturur
+    pub fn new(threshold: f32, intensity: f32) -> Bloom {
+        Bloom {
+            extract_shader: post_shader("bloom_extract_fragment_shader.glsl"),
+            blur_shader: post_shader("blur_fragment_shader.glsl"),
+            combine_shader: post_shader("bloom_combine_fragment_shader.glsl"),
+            targets: None,
+            enabled: true,
+            threshold,
+            soft_knee: 0.5,
+            intensity,
+            blur_passes: 5,
+        }
+    }
+
+    // Yarım çözünürlüklü bulanıklaştırma hedeflerini girişe göre hazırlar
+    fn prepare_targets(&mut self, width: u32, height: u32) -> Result<(), String> {
+        let (width, height) = ((width / 2).max(1), (height / 2).max(1));
+        match &mut self.targets {
+            Some(targets) => {
+                for target in targets.iter_mut() {
+                    target.resize(width, height)?;
+                }
+            }
+            None => {
+                let spec = FramebufferSpec {
+                    width,
+                    height,
+                    color_formats: vec![TextureFormat::Rgba16F],
+                    depth_format: None,
+                    samples: 1,
+                };
+                self.targets = Some([Framebuffer::new(spec.clone())?, Framebuffer::new(spec)?]);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl PostEffect for Bloom {
+    fn name(&self) -> &str {
+        "bloom"
+    }
+
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&mut self, input: &Texture, output: &Framebuffer, quad: &FullscreenQuad) {
+        // Without blur targets the combine pass with no bloom just copies the input through
+        if let Err(e) = self.prepare_targets(input.width(), input.height()) {
+            eprintln!("Disabling bloom: {}", e);
+            self.targets = None;
+            self.enabled = false;
+            self.combine_shader.use_program();
+            self.combine_shader.set_float("intensity", 0.0);
+            input.bind(1);
+            self.combine_shader.set_int("bloomTexture", 1);
+            run_pass(&self.combine_shader, input, output, quad);
+            return;
+        }
+        let targets = self.targets.as_ref().unwrap();
+
+        self.extract_shader.use_program();
+        self.extract_shader.set_float("threshold", self.threshold);
+        self.extract_shader.set_float("softKnee", self.soft_knee);
+        run_pass(&self.extract_shader, input, &targets[0], quad);
+
+        // Separable gaussian blur, ping-ponging between the two half resolution targets
+        self.blur_shader.use_program();
+        for _ in 0..self.blur_passes {
+            self.blur_shader.set_bool("horizontal", true);
+            run_pass(&self.blur_shader, targets[0].color_texture(0), &targets[1], quad);
+            self.blur_shader.set_bool("horizontal", false);
+            run_pass(&self.blur_shader, targets[1].color_texture(0), &targets[0], quad);
+        }
+
+        self.combine_shader.use_program();
+        self.combine_shader.set_float("intensity", self.intensity);
+        targets[0].color_texture(0).bind(1);
+        self.combine_shader.set_int("bloomTexture", 1);
+        run_pass(&self.combine_shader, input, output, quad);
+    }
+}
+
+pub struct Fxaa {
+    shader: Shader,
+    enabled: bool,
+}
+
+impl Fxaa {
+    // Yeni bir FXAA kenar yumuşatma efekti oluşturur
+    pub fn new() -> Fxaa {
+        Fxaa {
+            shader: post_shader("fxaa_fragment_shader.glsl"),
+            enabled: true,
+        }
+    }
+}
+
+impl PostEffect for Fxaa {
+    fn name(&self) -> &str {
+        "FXAA"
+    }
+
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&mut self, input: &Texture, output: &Framebuffer, quad: &FullscreenQuad) {
+        run_pass(&self.shader, input, output, quad);
+    }
+}
+
+pub struct Vignette {
+    shader: Shader,
+    enabled: bool,
+    pub radius: f32,
+    pub softness: f32,
+    pub strength: f32,
+}
+
+impl Vignette {
+    // Yeni bir vinyet efekti oluşturur
+    pub fn new(radius: f32, softness: f32, strength: f32) -> Vignette {
+        Vignette {
+            shader: post_shader("vignette_fragment_shader.glsl"),
+            enabled: true,
+            radius,
+            softness,
+            strength,
+        }
+    }
+}
+
+impl PostEffect for Vignette {
+    fn name(&self) -> &str {
+        "vignette"
+    }
+
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&mut self, input: &Texture, output: &Framebuffer, quad: &FullscreenQuad) {
+        self.shader.use_program();
+        self.shader.set_float("radius", self.radius);
+        self.shader.set_float("softness", self.softness);
+        self.shader.set_float("strength", self.strength);
+        run_pass(&self.shader, input, output, quad);
+    }
+}
+
+pub struct ColorGrading {
+    shader: Shader,
+    lut: Texture,
+    lut_size: u32,
+    enabled: bool,
+    pub strength: f32,
+}
+
+impl ColorGrading {
+    // Verilen LUT dokusuyla (size*size x size şerit) renk düzenleme efekti oluşturur
+    pub fn new(lut: Texture, lut_size: u32) -> ColorGrading {
+        ColorGrading {
+            shader: post_shader("color_grading_fragment_shader.glsl"),
+            lut,
+            lut_size,
+            enabled: true,
+            strength: 1.0,
+        }
+    }
+
+    // Rengi değiştirmeyen birim LUT ile bir efekt oluşturur
+    pub fn identity(lut_size: u32) -> ColorGrading {
+        let lut = Texture::from_data(lut_size * lut_size, lut_size, TextureFormat::Rgba8, Some(&identity_lut(lut_size)));
+        ColorGrading::new(lut, lut_size)
+    }
+
+    // LUT dokusunu değiştirir
+    pub fn set_lut(&mut self, lut: Texture, lut_size: u32) {
+        self.lut = lut;
+        self.lut_size = lut_size;
+    }
+}
+
+impl PostEffect for ColorGrading {
+    fn name(&self) -> &str {
+        "color grading"
+    }
+
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&mut self, input: &Texture, output: &Framebuffer, quad: &FullscreenQuad) {
+        self.shader.use_program();
+        self.lut.bind(1);
+        self.shader.set_int("lutTexture", 1);
+        self.shader.set_float("lutSize", self.lut_size as f32);
+        self.shader.set_float("strength", self.strength);
+        run_pass(&self.shader, input, output, quad);
+    }
+}
+
+// Birim renk LUT'unun RGBA8 piksellerini üretir
+pub fn identity_lut(size: u32) -> Vec<u8> {
+    let max = (size - 1).max(1) as f32;
+    let mut pixels = Vec::with_capacity((size * size * size * 4) as usize);
+    for g in 0..size {
+        for b in 0..size {
+            for r in 0..size {
+                pixels.extend_from_slice(&[
+                    (r as f32 / max * 255.0).round() as u8,
+                    (g as f32 / max * 255.0).round() as u8,
+                    (b as f32 / max * 255.0).round() as u8,
+                    255,
+                ]);
+            }
+        }
+    }
+    pixels
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorFilterMode {
+    Grayscale,
+    Sepia,
+}
+
+pub struct ColorFilter {
+    shader: Shader,
+    enabled: bool,
+    pub mode: ColorFilterMode,
+    pub strength: f32,
+}
+
+impl ColorFilter {
+    // Yeni bir gri tonlama / sepya efekti oluşturur
+    pub fn new(mode: ColorFilterMode) -> ColorFilter {
+        ColorFilter {
+            shader: post_shader("color_filter_fragment_shader.glsl"),
+            enabled: true,
+            mode,
+            strength: 1.0,
+        }
+    }
+}
+
+impl PostEffect for ColorFilter {
+    fn name(&self) -> &str {
+        match self.mode {
+            ColorFilterMode::Grayscale => "grayscale",
+            ColorFilterMode::Sepia => "sepia",
+        }
+    }
+
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&mut self, input: &Texture, output: &Framebuffer, quad: &FullscreenQuad) {
+        self.shader.use_program();
+        self.shader.set_int("mode", (self.mode == ColorFilterMode::Sepia) as i32);
+        self.shader.set_float("strength", self.strength);
+        run_pass(&self.shader, input, output, quad);
+    }
+}
+
+// Sahneyi sırayla etkin efektlerden geçirip ekrana çizen efekt yığını
+pub struct PostProcessStack {
+    effects: Vec<Box<dyn PostEffect>>,
+    targets: [Framebuffer; 2],
+    quad: FullscreenQuad,
+}
+
+impl PostProcessStack {
+    // Verilen boyutta boş bir efekt yığını oluşturur
+    pub fn new(width: u32, height: u32) -> Result<PostProcessStack, String> {
+        let spec = FramebufferSpec {
+            width,
+            height,
+            color_formats: vec![TextureFormat::Rgba16F],
+            depth_format: None,
+            samples: 1,
+        };
+        Ok(PostProcessStack {
+            effects: Vec::new(),
+            targets: [Framebuffer::new(spec.clone())?, Framebuffer::new(spec)?],
+            quad: FullscreenQuad::new(),
+        })
+    }
+
+    // Yığının sonuna bir efekt ekler
+    pub fn push(&mut self, effect: Box<dyn PostEffect>) {
+        self.effects.push(effect);
+    }
+
+    // Efektleri sırasıyla döndürür
+    pub fn effects(&self) -> &[Box<dyn PostEffect>] {
+        &self.effects
+    }
+
+    // Etkin efektlerden biri çıktıyı sRGB'ye kodluyorsa sahnenin doğrusal renklerle çizilmesi gerekir
+    pub fn expects_linear(&self) -> bool {
+        self.effects.iter().any(|effect| effect.enabled() && effect.encodes_srgb())
+    }
+
+    // Verilen sıradaki efekte değiştirilebilir erişim sağlar
+    pub fn effect_mut(&mut self, index: usize) -> Option<&mut Box<dyn PostEffect>> {
+        self.effects.get_mut(index)
+    }
+
+    // Verilen sıradaki efekti açar/kapatır ve yeni durumunu döndürür
+    pub fn toggle(&mut self, index: usize) -> Option<bool> {
+        let effect = self.effects.get_mut(index)?;
+        let enabled = !effect.enabled();
+        effect.set_enabled(enabled);
+        Some(enabled)
+    }
+
+    // Ara hedefleri yeni pencere boyutuna göre yeniden boyutlandırır
+    pub fn resize(&mut self, width: u32, height: u32) -> Result<(), String> {
+        for target in self.targets.iter_mut() {
+            target.resize(width, height)?;
+        }
+        Ok(())
+    }
+
+    // Sahne hedefini etkin efektlerden geçirip sonucu ekrana kopyalar
+    pub fn render(&mut self, scene: &Framebuffer, screen_width: u32, screen_height: u32) {
+        scene.resolve();
+
+        let mut depth_test = 0;
+        unsafe {
+            gl::GetIntegerv(gl::DEPTH_TEST, &mut depth_test);
+            gl::Disable(gl::DEPTH_TEST);
+        }
+
+        // Ping-pong between the two targets; `current` is None while the scene is still the input
+        let mut current: Option<usize> = None;
+        for effect in self.effects.iter_mut().filter(|effect| effect.enabled()) {
+            let next = current.map_or(0, |index| 1 - index);
+            let input = match current {
+                Some(index) => self.targets[index].color_texture(0),
+                None => scene.color_texture(0),
+            };
+            effect.apply(input, &self.targets[next], &self.quad);
+            current = Some(next);
+        }
+
+        match current {
+            Some(index) => self.targets[index].blit_to_screen(0, screen_width, screen_height),
+            None => scene.blit_to_screen(0, screen_width, screen_height),
+        }
+
+        unsafe {
+            if depth_test != 0 {
+                gl::Enable(gl::DEPTH_TEST);
+            }
+            gl::Viewport(0, 0, screen_width as i32, screen_height as i32);
+        }
+    }
+}
+
+// Varsayılan yığındaki efektler
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EffectKind {
+    Bloom,
+    ToneMapping,
+    ColorGrading,
+    Grayscale,
+    Sepia,
+    Vignette,
+    GammaCorrection,
+    Fxaa,
+}
+
+// Varsayılan yığın ve efektlerin başlangıçta açık olup olmadığı. Bloom parlaklık eşiği için HDR girdiye
+// ihtiyaç duyar, bu yüzden ton eşlemeden önce gelir; renk derecelendirme ve gama [0, 1] aralığındaki renklerle çalışır.
+pub const DEFAULT_STACK: [(EffectKind, bool); 8] = [
+    (EffectKind::Bloom, false),
+    (EffectKind::ToneMapping, false),
+    (EffectKind::ColorGrading, false),
+    (EffectKind::Grayscale, false),
+    (EffectKind::Sepia, false),
+    (EffectKind::Vignette, false),
+    (EffectKind::GammaCorrection, false),
+    (EffectKind::Fxaa, true),
+];
+
+impl EffectKind {
+    // Efekti varsayılan ayarlarıyla oluşturur
+    pub fn create(self) -> Box<dyn PostEffect> {
+        match self {
+            EffectKind::Bloom => Box::new(Bloom::new(1.0, 0.6)),
+            EffectKind::ToneMapping => Box::new(ToneMapping::new(1.0, ToneMapOperator::Aces)),
+            EffectKind::ColorGrading => Box::new(ColorGrading::identity(16)),
+            EffectKind::Grayscale => Box::new(ColorFilter::new(ColorFilterMode::Grayscale)),
+            EffectKind::Sepia => Box::new(ColorFilter::new(ColorFilterMode::Sepia)),
+            EffectKind::Vignette => Box::new(Vignette::new(0.75, 0.45, 0.8)),
+            EffectKind::GammaCorrection => Box::new(GammaCorrection::new(2.2)),
+            EffectKind::Fxaa => Box::new(Fxaa::new()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(kind: EffectKind) -> usize {
+        DEFAULT_STACK.iter().position(|&(effect, _)| effect == kind).unwrap()
+    }
+
+    #[test]
+    fn bloom_sees_hdr_input_and_grading_sees_tone_mapped_colors() {
+        assert!(position(EffectKind::Bloom) < position(EffectKind::ToneMapping));
+        assert!(position(EffectKind::ToneMapping) < position(EffectKind::ColorGrading));
+        assert!(position(EffectKind::ToneMapping) < position(EffectKind::GammaCorrection));
+        // FXAA works on the final, display encoded image
+        assert_eq!(position(EffectKind::Fxaa), DEFAULT_STACK.len() - 1);
+    }
+}
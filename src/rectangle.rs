@@ -1,44 +1,215 @@
 extern crate gl;
 
+use crate::color::{self, Color, VertexColors};
 use crate::shader::Shader;
 use std::ptr;
 use gl::types::{GLuint, GLfloat};
-use crate::draw::Shape;
+use crate::draw::{RenderContext, Shape};
+use crate::matrix4::Matrix4;
+use crate::mesh::MeshData;
+use crate::texture::Texture;
 use std::rc::Rc;  // Import Rc for reference counting
 use crate::vector2d::Vector2D;
+use crate::vector3d::Vector3D;
+
+// Dikdörtgenin döndürme, köşe yuvarlama ve kenarlık ayarları. Köşe sırası: sol üst, sağ üst, sağ alt, sol alt.
+#[derive(Debug, Clone, Copy)]
+pub struct RectangleStyle {
+    pub rotation: f32,  // Radians, counter-clockwise around the center
+    pub radii: [f32; 4],
+    pub border: Option<f32>,  // Outline width; None fills the whole rectangle
+    pub corner_segments: u32,
+}
+
+impl RectangleStyle {
+    // Dönmemiş, sivri köşeli, dolu bir dikdörtgen stili oluşturur
+    pub fn new() -> RectangleStyle {
+        RectangleStyle { rotation: 0.0, radii: [0.0; 4], border: None, corner_segments: 8 }
+    }
+
+    // Dönüş açısını (radyan) değiştirir
+    pub fn with_rotation(mut self, rotation: f32) -> RectangleStyle {
+        self.rotation = rotation;
+        self
+    }
+
+    // Bütün köşelere aynı yarıçapı verir
+    pub fn with_radius(self, radius: f32) -> RectangleStyle {
+        self.with_radii([radius; 4])
+    }
+
+    // Köşe yarıçaplarını değiştirir
+    pub fn with_radii(mut self, radii: [f32; 4]) -> RectangleStyle {
+        self.radii = radii;
+        self
+    }
+
+    // Dikdörtgeni yalnızca verilen kalınlıkta bir kenarlık olarak çizer
+    pub fn with_border(mut self, width: f32) -> RectangleStyle {
+        self.border = Some(width);
+        self
+    }
+}
+
+// Dikdörtgenin dönmemiş köşeleri; `strip` ise köşeler kenarlık için iç ve dış halka arasında üçgen şerididir,
+// değilse merkezden başlayan bir üçgen yelpazesidir. Dönüş köşelere değil çizimdeki model matrisine uygulanır.
+#[derive(Debug, Clone)]
+struct Geometry {
+    positions: Vec<Vector2D>,
+    uvs: Vec<[f32; 2]>,
+    strip: bool,
+    rotation: Matrix4,  // Rotation about the center, applied after the node's world matrix
+}
+
+// Köşe merkezleri çevresinde, saat yönünün tersine giden köşe noktalarını ekler. Sivri köşeler tek nokta,
+// yuvarlak köşeler `segments + 1` nokta verir; `rounded` dış halkanın köşesinin yuvarlak olup olmadığıdır,
+// böylece iç ve dış halkalar aynı sayıda nokta içerir.
+fn outline(min: Vector2D, max: Vector2D, radii: [f32; 4], rounded: [bool; 4], segments: u32) -> Vec<Vector2D> {
+    // Counter-clockwise from the top right corner; radii are stored top left first
+    let corners = [
+        (Vector2D::new(max.x - radii[1], max.y - radii[1]), radii[1], rounded[1], 0.0),
+        (Vector2D::new(min.x + radii[0], max.y - radii[0]), radii[0], rounded[0], 0.5),
+        (Vector2D::new(min.x + radii[3], min.y + radii[3]), radii[3], rounded[3], 1.0),
+        (Vector2D::new(max.x - radii[2], min.y + radii[2]), radii[2], rounded[2], 1.5),
+    ];
+
+    let mut points = Vec::new();
+    for (center, radius, rounded, start) in corners {
+        let count = if rounded { segments.max(1) } else { 0 };
+        for i in 0..=count {
+            let angle = (start + 0.5 * i as f32 / count.max(1) as f32) * std::f32::consts::PI;
+            points.push(center + Vector2D::new(angle.cos(), angle.sin()) * radius);
+        }
+    }
+    points
+}
+
+// Köşe yarıçaplarını negatif olmayacak ve komşu köşelerin toplamı kenarı aşmayacak şekilde küçültür
+fn clamp_radii(radii: [f32; 4], width: f32, height: f32) -> [f32; 4] {
+    let radii = radii.map(|r| r.max(0.0));
+    let sides = [(radii[0] + radii[1], width), (radii[3] + radii[2], width), (radii[0] + radii[3], height), (radii[1] + radii[2], height)];
+    let scale = sides.iter().filter(|(sum, _)| *sum > 0.0).map(|(sum, side)| side / sum).fold(1.0, f32::min);
+    radii.map(|r| r * scale)
+}
+
+// Stile göre dönmemiş dikdörtgenin köşelerini, (0-1 aralığında) doku koordinatlarını ve merkez çevresindeki dönüşü üretir
+fn geometry(a: Vector2D, b: Vector2D, style: &RectangleStyle) -> Geometry {
+    let min = Vector2D::new(a.x.min(b.x), a.y.min(b.y));
+    let max = Vector2D::new(a.x.max(b.x), a.y.max(b.y));
+    let (width, height) = (max.x - min.x, max.y - min.y);
+    let radii = clamp_radii(style.radii, width, height);
+    let rounded = radii.map(|r| r > 0.0);
+    let outer = outline(min, max, radii, rounded, style.corner_segments);
+
+    let (positions, strip) = match style.border {
+        // A border at least half the shorter side covers the whole rectangle
+        Some(border) if border > 0.0 && border * 2.0 < width.min(height) => {
+            let inset = Vector2D::new(border, border);
+            let inner = outline(min + inset, max - inset, radii.map(|r| (r - border).max(0.0)), rounded, style.corner_segments);
+            let mut positions = Vec::with_capacity(outer.len() * 2 + 2);
+            for (inner, outer) in inner.iter().zip(&outer).chain(std::iter::once((&inner[0], &outer[0]))) {
+                positions.push(*inner);
+                positions.push(*outer);
+            }
+            (positions, true)
+        }
+        _ => {
+            let mut positions = Vec::with_capacity(outer.len() + 2);
+            positions.push((min + max) / 2.0);
+            positions.extend_from_slice(&outer);
+            positions.push(outer[0]);
+            (positions, false)
+        }
+    };
+
+    let size = Vector2D::new(width.max(f32::EPSILON), height.max(f32::EPSILON));
+    let uvs = positions.iter().map(|p| [(p.x - min.x) / size.x, (p.y - min.y) / size.y]).collect();
+    let center = (min + max) / 2.0;
+    let center = Vector3D::new(center.x, center.y, 0.0);
+    let rotation = Matrix4::translation(center) * Matrix4::rotation_z(style.rotation) * Matrix4::translation(center * -1.0);
+
+    Geometry { positions, uvs, strip, rotation }
+}
+
+impl Geometry {
+    // Köşe renklerini (sol üst, sağ üst, sağ alt, sol alt) dönmemiş dikdörtgendeki konuma göre her köşeye karıştırır
+    fn blend_corners(&self, corners: [Color; 4]) -> Vec<Color> {
+        let [top_left, top_right, bottom_right, bottom_left] = corners;
+        self.uvs
+            .iter()
+            .map(|&[u, v]| bottom_left.lerp(bottom_right, u).lerp(top_left.lerp(top_right, u), v))
+            .collect()
+    }
+
+    // Yelpaze veya şeridi bağımsız üçgen indekslerine çevirir (saat yönünün tersine)
+    fn indices(&self) -> Vec<u32> {
+        let n = self.positions.len() as u32;
+        if self.strip {
+            (0..n.saturating_sub(2)).flat_map(|i| if i % 2 == 0 { [i, i + 1, i + 2] } else { [i + 1, i, i + 2] }).collect()
+        } else {
+            (1..n.saturating_sub(1)).flat_map(|i| [0, i, i + 1]).collect()
+        }
+    }
+}
 
 pub struct Rectangle {
     vao: GLuint,
     vbo: GLuint,
     shader: Rc<Shader>,  // Use Rc<Shader>
-    top_right: Vector2D,
-    bottom_left: Vector2D,
+    geometry: Geometry,
+    pub color: Color,  // Fill color, used by the flat color shader
+    colors: VertexColors,
+    texture: Option<Rc<Texture>>,  // Sampled as `image` with the rectangle's uvs, tinted by `color`
 }
 
 impl Rectangle {
+    pub const CORNER_COLORS: usize = 4;
+
     // Yeni bir dikdörtgen oluşturur
     pub fn new(shader: Rc<Shader>, top_right: Vector2D, bottom_left: Vector2D) -> Rectangle {  // Accept Rc<Shader> as input
+        Rectangle::styled(shader, top_right, bottom_left, &RectangleStyle::new())
+    }
+
+    // Karşılıklı iki köşesi verilen, döndürülmüş, yuvarlatılmış veya kenarlık olarak çizilen bir dikdörtgen oluşturur
+    pub fn styled(shader: Rc<Shader>, top_right: Vector2D, bottom_left: Vector2D, style: &RectangleStyle) -> Rectangle {
         let mut rectangle = Rectangle { 
             vao: 0, 
             vbo: 0, 
             shader,
-            top_right,
-            bottom_left,
+            geometry: geometry(top_right, bottom_left, style),
+            color: Color::WHITE,
+            colors: VertexColors::new(),
+            texture: None,
         };
         rectangle.init();
         rectangle
     }
+
+    // Köşelere sırasıyla sol üst, sağ üst, sağ alt ve sol alt için birer renk verir. Köşeler dönmemiş
+    // dikdörtgene göredir; renkler yüzey boyunca karışır.
+    pub fn set_vertex_colors(&mut self, colors: &[Color]) -> Result<(), String> {
+        let corners: [Color; 4] = colors.try_into().map_err(|_| {
+            format!("a rectangle takes {} corner colors, got {}", Rectangle::CORNER_COLORS, colors.len())
+        })?;
+        self.colors.set(self.geometry.blend_corners(corners));
+        Ok(())
+    }
+
+    // Dikdörtgeni verilen dokuyla kaplar; doku sol alttan (0, 0) sağ üste (1, 1) yayılır
+    pub fn with_texture(mut self, texture: Option<Rc<Texture>>) -> Rectangle {
+        self.texture = texture;
+        self
+    }
 }
 
 impl Shape for Rectangle {
     // Dikdörtgeni başlatır ve OpenGL'e yükler
     fn init(&mut self) {
-        let vertices: [GLfloat; 12] = [
-            self.top_right.x, self.top_right.y, 0.0,
-            self.top_right.x, self.bottom_left.y, 0.0,
-            self.bottom_left.x, self.bottom_left.y, 0.0,
-            self.bottom_left.x, self.top_right.y, 0.0,
-        ];
+        // Position plus texture coordinates in the normal slot (z = 1), the same layout Triangle uses
+        let mut vertices: Vec<GLfloat> = Vec::with_capacity(self.geometry.positions.len() * 6);
+        for (p, uv) in self.geometry.positions.iter().zip(&self.geometry.uvs) {
+            vertices.extend_from_slice(&[p.x, p.y, 0.0, uv[0], uv[1], 1.0]);
+        }
 
         unsafe {
             gl::GenVertexArrays(1, &mut self.vao);
@@ -59,23 +230,56 @@ impl Shape for Rectangle {
                 3, 
                 gl::FLOAT, 
                 gl::FALSE, 
-                3 * std::mem::size_of::<GLfloat>() as i32, 
+                6 * std::mem::size_of::<GLfloat>() as i32, 
                 ptr::null(),
             );
             gl::EnableVertexAttribArray(0);
 
+            gl::VertexAttribPointer(1, 3, gl::FLOAT, gl::FALSE, 6 * std::mem::size_of::<GLfloat>() as i32, (3 * std::mem::size_of::<GLfloat>()) as *const _);
+            gl::EnableVertexAttribArray(1);
+
+            self.colors.attach(2, self.geometry.positions.len());
+
             gl::BindVertexArray(0);
         }
     }
 
     // Dikdörtgeni çizer
-    fn draw(&self) {
-        unsafe {
-            self.shader.use_program();  // Use the shader before drawing
+    fn draw(&self, ctx: &RenderContext, model: &Matrix4) {
+        let mode = if self.geometry.strip { gl::TRIANGLE_STRIP } else { gl::TRIANGLE_FAN };
+        self.shader.use_program();  // Use the shader before drawing
+        ctx.apply_2d(&self.shader, &(*model * self.geometry.rotation));
+        self.shader.set_vec4("color", ctx.color(self.color));
+        if let Some(texture) = &self.texture {
+            texture.bind(0);
+            self.shader.set_int("image", 0);
+        }
+        // Textures may carry their own alpha, so textured rectangles always blend
+        let translucent = self.color.a < 1.0 || self.colors.translucent() || self.texture.is_some();
+        color::draw_blended(translucent, || unsafe {
             gl::BindVertexArray(self.vao);
-            gl::DrawArrays(gl::TRIANGLE_FAN, 0, 4);
+            gl::DrawArrays(mode, 0, self.geometry.positions.len() as i32);
             gl::BindVertexArray(0);
-        }
+        });
+    }
+
+    // Dikdörtgeni kendi dönüşüyle z = 0 düzleminde üçgenler olarak döndürür
+    fn mesh_data(&self) -> Option<MeshData> {
+        let rotation = &self.geometry.rotation;
+        Some(MeshData {
+            positions: self
+                .geometry
+                .positions
+                .iter()
+                .map(|p| {
+                    let p = rotation.transform_point(Vector3D::new(p.x, p.y, 0.0));
+                    [p.x, p.y, p.z]
+                })
+                .collect(),
+            normals: vec![[0.0, 0.0, 1.0]; self.geometry.positions.len()],
+            uvs: self.geometry.uvs.clone(),
+            indices: self.geometry.indices(),
+        })
     }
 }
 
@@ -88,3 +292,85 @@ impl Drop for Rectangle {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Üçgenlerin işaretli toplam alanı
+    fn area(geometry: &Geometry) -> f32 {
+        let p = &geometry.positions;
+        geometry
+            .indices()
+            .chunks_exact(3)
+            .map(|t| {
+                let (a, b, c) = (p[t[0] as usize], p[t[1] as usize], p[t[2] as usize]);
+                ((b.x - a.x) * (c.y - a.y) - (c.x - a.x) * (b.y - a.y)) / 2.0
+            })
+            .sum()
+    }
+
+    #[test]
+    fn rounded_corners_remove_area() {
+        let (a, b) = (Vector2D::new(4.0, 2.0), Vector2D::new(0.0, 0.0));
+        assert!((area(&geometry(a, b, &RectangleStyle::new())) - 8.0).abs() < 1e-5);
+
+        let mut style = RectangleStyle::new().with_radius(0.5);
+        style.corner_segments = 64;
+        let expected = 8.0 - (4.0 - std::f32::consts::PI) * 0.25;
+        assert!((area(&geometry(a, b, &style)) - expected).abs() < 1e-3);
+
+        // Radii larger than the sides are scaled down until they form a stadium
+        let stadium = geometry(a, b, &style.with_radius(5.0));
+        let expected = 8.0 - (4.0 - std::f32::consts::PI);
+        assert!((area(&stadium) - expected).abs() < 1e-3);
+        assert!(stadium.positions.iter().all(|p| p.y >= -1e-5 && p.y <= 2.0 + 1e-5));
+    }
+
+    #[test]
+    fn borders_leave_the_inside_empty() {
+        let (a, b) = (Vector2D::new(4.0, 2.0), Vector2D::new(0.0, 0.0));
+        let border = geometry(a, b, &RectangleStyle::new().with_border(0.5));
+        assert!(border.strip);
+        assert!((area(&border) - (8.0 - 3.0)).abs() < 1e-5);
+
+        let mut style = RectangleStyle::new().with_radii([1.0, 0.0, 0.0, 0.0]).with_border(0.25);
+        style.corner_segments = 64;
+        let rounded = geometry(a, b, &style);
+        let outer = 8.0 - (4.0 - std::f32::consts::PI) * 0.25;
+        let inner = 3.5 * 1.5 - (4.0 - std::f32::consts::PI) * 0.75 * 0.75 / 4.0;
+        assert!((area(&rounded) - (outer - inner)).abs() < 1e-3);
+
+        // A border thicker than half the rectangle fills it
+        assert!(!geometry(a, b, &RectangleStyle::new().with_border(1.0)).strip);
+    }
+
+    #[test]
+    fn rotation_keeps_texture_coordinates() {
+        let style = RectangleStyle::new().with_rotation(std::f32::consts::FRAC_PI_2);
+        let rotated = geometry(Vector2D::new(1.0, 1.0), Vector2D::new(-1.0, -1.0), &style);
+        // The first outline point is the top right corner, which a quarter turn moves to the top left
+        let (p, uv) = (rotated.positions[1], rotated.uvs[1]);
+        let p = rotated.rotation.transform_point(Vector3D::new(p.x, p.y, 0.0));
+        assert!((p.x + 1.0).abs() < 1e-5 && (p.y - 1.0).abs() < 1e-5);
+        assert_eq!(uv, [1.0, 1.0]);
+        assert!(rotated.uvs.iter().all(|uv| (0.0..=1.0).contains(&uv[0]) && (0.0..=1.0).contains(&uv[1])));
+        assert!((area(&rotated) - 4.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn corner_colors_blend_across_the_rectangle() {
+        let style = RectangleStyle::new().with_rotation(1.0);
+        let geometry = geometry(Vector2D::new(1.0, 1.0), Vector2D::new(-1.0, -1.0), &style);
+        let (red, green, blue) = (Color::rgb(1.0, 0.0, 0.0), Color::rgb(0.0, 1.0, 0.0), Color::rgb(0.0, 0.0, 1.0));
+        let colors = geometry.blend_corners([red, green, blue, Color::WHITE]);
+
+        // The fan starts at the center, then the top right corner, and corners keep their color when rotated
+        assert_eq!(colors.len(), geometry.positions.len());
+        assert_eq!(colors[1], green);
+        assert_eq!(colors[2], red);
+        // The center mixes the corners in linear space, so it is brighter than the sRGB average
+        let center = Color::from_linear([0.5, 0.5, 0.5, 1.0]);
+        assert!(colors[0].to_array().iter().zip(center.to_array()).all(|(a, b)| (a - b).abs() < 1e-4));
+    }
+}
@@ -0,0 +1,286 @@
+use crate::draw::Shape;
+use crate::matrix4::Matrix4;
+use crate::vector3d::Vector3D;
+
+// Sahnedeki bir düğümün kimliği (Scene::nodes içindeki sırası)
+pub type NodeId = usize;
+
+// Bir düğümün ebeveynine göre konumu, dönüşü (x, y, z Euler açıları, radyan) ve ölçeği
+#[derive(Debug, Clone, Copy)]
+pub struct Transform {
+    pub translation: Vector3D,
+    pub rotation: Vector3D,
+    pub scale: Vector3D,
+}
+
+impl Transform {
+    // Hiçbir dönüşüm uygulamayan birim dönüşümü oluşturur
+    pub fn identity() -> Transform {
+        Transform { translation: Vector3D::zero(), rotation: Vector3D::zero(), scale: Vector3D::new(1.0, 1.0, 1.0) }
+    }
+
+    // Yalnızca öteleme içeren bir dönüşüm oluşturur
+    pub fn from_translation(translation: Vector3D) -> Transform {
+        Transform { translation, ..Transform::identity() }
+    }
+
+    // Dönüşümün matrisini döndürür (öteleme * dönüş * ölçek)
+    pub fn matrix(&self) -> Matrix4 {
+        Matrix4::translation(self.translation)
+            * Matrix4::rotation_x(self.rotation.x)
+            * Matrix4::rotation_y(self.rotation.y)
+            * Matrix4::rotation_z(self.rotation.z)
+            * Matrix4::scale(self.scale)
+    }
+
+    // İki dönüşüm arasında bileşen bileşen doğrusal ara değer döndürür
+    pub fn lerp(&self, other: &Transform, alpha: f32) -> Transform {
+        Transform {
+            translation: self.translation + (other.translation - self.translation) * alpha,
+            rotation: self.rotation + (other.rotation - self.rotation) * alpha,
+            scale: self.scale + (other.scale - self.scale) * alpha,
+        }
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Transform::identity()
+    }
+}
+
+// Sahne ağacındaki bir düğüm. İsteğe bağlı olarak çizilebilir bir şekil taşır.
+pub struct Node {
+    pub name: String,
+    pub transform: Transform,
+    pub visible: bool,
+    pub renderable: Option<Box<dyn Shape>>,
+    pub angular_velocity: Vector3D,  // Euler angle change per second, applied in Scene::update
+    previous: Transform,
+    parent: Option<NodeId>,
+    children: Vec<NodeId>,
+    world: Matrix4,
+}
+
+impl Node {
+    // Dönüşümü ara değer olmadan hemen uygular; sahne yüklenirken ya da düzenlenirken düğüm eski konumundan kaymaz
+    pub fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+        self.previous = transform;
+    }
+
+    // Düğümün ebeveynini döndürür
+    pub fn parent(&self) -> Option<NodeId> {
+        self.parent
+    }
+
+    // Düğümün çocuklarını döndürür
+    pub fn children(&self) -> &[NodeId] {
+        &self.children
+    }
+
+    // Son update_world çağrısında hesaplanan dünya matrisini döndürür
+    pub fn world_matrix(&self) -> &Matrix4 {
+        &self.world
+    }
+}
+
+// Ebeveyn/çocuk ilişkili düğümlerden oluşan sahne ağacı
+pub struct Scene {
+    nodes: Vec<Node>,
+    roots: Vec<NodeId>,
+}
+
+impl Scene {
+    // Boş bir sahne oluşturur
+    pub fn new() -> Scene {
+        Scene { nodes: Vec::new(), roots: Vec::new() }
+    }
+
+    // Verilen ebeveynin altına (None ise köke) boş bir düğüm ekler
+    pub fn add_node(&mut self, name: &str, parent: Option<NodeId>) -> NodeId {
+        let id = self.nodes.len();
+        self.nodes.push(Node {
+            name: name.to_string(),
+            transform: Transform::identity(),
+            visible: true,
+            renderable: None,
+            angular_velocity: Vector3D::zero(),
+            previous: Transform::identity(),
+            parent,
+            children: Vec::new(),
+            world: Matrix4::identity(),
+        });
+
+        match parent {
+            Some(parent) => self.nodes[parent].children.push(id),
+            None => self.roots.push(id),
+        }
+        id
+    }
+
+    // Verilen ebeveynin altına bir şekil taşıyan düğüm ekler
+    pub fn add_shape(&mut self, name: &str, parent: Option<NodeId>, shape: Box<dyn Shape>) -> NodeId {
+        let id = self.add_node(name, parent);
+        self.nodes[id].renderable = Some(shape);
+        id
+    }
+
+    // Düğümü döndürür
+    pub fn node(&self, id: NodeId) -> &Node {
+        &self.nodes[id]
+    }
+
+    // Kök düğümleri döndürür
+    pub fn roots(&self) -> &[NodeId] {
+        &self.roots
+    }
+
+    // Düğümü değiştirilebilir olarak döndürür
+    pub fn node_mut(&mut self, id: NodeId) -> &mut Node {
+        &mut self.nodes[id]
+    }
+
+    // Verilen ada sahip ilk düğümü bulur
+    pub fn find(&self, name: &str) -> Option<NodeId> {
+        self.nodes.iter().position(|node| node.name == name)
+    }
+
+    // Düğümü başka bir ebeveynin altına (None ise köke) taşır.
+    // Düğümü kendi alt ağacına taşımak döngü oluşturacağı için hata döndürür.
+    pub fn set_parent(&mut self, id: NodeId, parent: Option<NodeId>) -> Result<(), String> {
+        let mut ancestor = parent;
+        while let Some(current) = ancestor {
+            if current == id {
+                return Err(format!(
+                    "cannot move node '{}' under its own descendant '{}'",
+                    self.nodes[id].name,
+                    self.nodes[parent.unwrap()].name
+                ));
+            }
+            ancestor = self.nodes[current].parent;
+        }
+
+        match self.nodes[id].parent {
+            Some(old) => self.nodes[old].children.retain(|&child| child != id),
+            None => self.roots.retain(|&root| root != id),
+        }
+        match parent {
+            Some(new) => self.nodes[new].children.push(id),
+            None => self.roots.push(id),
+        }
+        self.nodes[id].parent = parent;
+        Ok(())
+    }
+
+    // Sahneyi bir sabit zaman adımı (saniye) kadar ilerletir
+    pub fn update(&mut self, dt: f32) {
+        for node in &mut self.nodes {
+            node.previous = node.transform;
+            node.transform.rotation = node.transform.rotation + node.angular_velocity * dt;
+        }
+    }
+
+    // Son iki güncelleme arasındaki `alpha` konumunda bütün düğümlerin dünya matrislerini hesaplar
+    pub fn update_world(&mut self, alpha: f32) {
+        let mut stack: Vec<(NodeId, Matrix4)> = self.roots.iter().rev().map(|&root| (root, Matrix4::identity())).collect();
+        while let Some((id, parent_world)) = stack.pop() {
+            let node = &mut self.nodes[id];
+            node.world = parent_world * node.previous.lerp(&node.transform, alpha).matrix();
+            let world = node.world;
+            stack.extend(node.children.iter().rev().map(|&child| (child, world)));
+        }
+    }
+
+    // Görünür düğümleri ağaç sırasıyla gezer; gizli bir düğümün alt ağacı da atlanır
+    pub fn visit<'a, F: FnMut(NodeId, &'a Node)>(&'a self, mut f: F) {
+        let mut stack: Vec<NodeId> = self.roots.iter().rev().copied().collect();
+        while let Some(id) = stack.pop() {
+            let node = &self.nodes[id];
+            if !node.visible {
+                continue;
+            }
+            f(id, node);
+            stack.extend(node.children.iter().rev());
+        }
+    }
+
+    // Görünür şekilleri dünya matrisleriyle birlikte döndürür
+    pub fn renderables(&self) -> Vec<(&dyn Shape, Matrix4)> {
+        let mut renderables = Vec::new();
+        self.visit(|_, node| {
+            if let Some(shape) = &node.renderable {
+                renderables.push((shape.as_ref(), *node.world_matrix()));
+            }
+        });
+        renderables
+    }
+
+    // Bütün düğümleri ve şekilleri siler
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.roots.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: Vector3D, b: Vector3D) -> bool {
+        (a - b).magnitude() < 1e-5
+    }
+
+    #[test]
+    fn world_transform_combines_parent_and_child() {
+        let mut scene = Scene::new();
+        let parent = scene.add_node("parent", None);
+        let child = scene.add_node("child", Some(parent));
+        scene.node_mut(parent).transform = Transform::from_translation(Vector3D::new(1.0, 0.0, 0.0));
+        scene.node_mut(parent).transform.scale = Vector3D::new(2.0, 2.0, 2.0);
+        scene.node_mut(child).transform = Transform::from_translation(Vector3D::new(0.0, 1.0, 0.0));
+        scene.update(0.0);
+        scene.update_world(1.0);
+
+        let origin = scene.node(child).world_matrix().transform_point(Vector3D::zero());
+        assert!(approx(origin, Vector3D::new(1.0, 2.0, 0.0)));
+    }
+
+    #[test]
+    fn hidden_nodes_hide_their_subtree() {
+        let mut scene = Scene::new();
+        let root = scene.add_node("root", None);
+        let arm = scene.add_node("arm", Some(root));
+        scene.add_node("hand", Some(arm));
+        scene.add_node("other", None);
+        scene.node_mut(arm).visible = false;
+
+        let mut visited = Vec::new();
+        scene.visit(|_, node| visited.push(node.name.clone()));
+        assert_eq!(visited, ["root", "other"]);
+    }
+
+    #[test]
+    fn set_parent_rejects_cycles_and_moves_nodes() {
+        let mut scene = Scene::new();
+        let a = scene.add_node("a", None);
+        let b = scene.add_node("b", Some(a));
+        assert!(scene.set_parent(a, Some(b)).is_err());
+
+        scene.set_parent(b, None).unwrap();
+        assert_eq!(scene.node(b).parent(), None);
+        assert!(scene.node(a).children().is_empty());
+        assert_eq!(scene.find("b"), Some(b));
+    }
+
+    #[test]
+    fn set_transform_is_not_interpolated() {
+        let mut scene = Scene::new();
+        let node = scene.add_node("node", None);
+        scene.node_mut(node).set_transform(Transform::from_translation(Vector3D::new(2.0, 0.0, 0.0)));
+        scene.update_world(0.0);
+
+        let origin = scene.node(node).world_matrix().transform_point(Vector3D::zero());
+        assert!(approx(origin, Vector3D::new(2.0, 0.0, 0.0)));
+    }
+}
@@ -0,0 +1,1079 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::rc::Rc;
+
+use crate::assets::AssetManager;
+use crate::camera::Camera;
+use crate::circle::Circle;
+use crate::color::Color;
+use crate::cube::Cube;
+use crate::draw::Shape;
+use crate::locator;
+use crate::gltf_import::{self, GltfAsset, GltfCamera, GltfLight, GltfPrimitive, ImportedLight};
+use crate::instancing::{self, Instance, InstancedMesh};
+use crate::light::{DirectionalLight, Lighting, SpotLight, MAX_SPOT_LIGHTS};
+use crate::material::Material;
+use crate::mesh::{Mesh, Model};
+use crate::obj;
+use crate::path2d::{self, FillRule, LineCap, LineJoin, Path2D, PathShape, StrokeStyle, Tessellation};
+use crate::plane::Plane;
+use crate::polygon::{self, Polygon};
+use crate::rectangle::{Rectangle, RectangleStyle};
+use crate::scene::{NodeId, Scene, Transform};
+use crate::shader::{self, Shader};
+use crate::shadow::ShadowSettings;
+use crate::texture::Texture;
+use crate::triangle::Triangle;
+use crate::vector2d::Vector2D;
+use crate::vector3d::Vector3D;
+
+// Bir sahne dosyasının (RON veya JSON) içeriği
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SceneDescription {
+    #[serde(default)]
+    pub shaders: BTreeMap<String, ShaderDescription>,
+    #[serde(default)]
+    pub materials: BTreeMap<String, MaterialDescription>,
+    #[serde(default)]
+    pub camera: Option<CameraDescription>,
+    #[serde(default)]
+    pub lights: LightsDescription,
+    #[serde(default)]
+    pub nodes: Vec<NodeDescription>,
+}
+
+// Dosya adları shader klasörüne göredir (bkz. shader::path)
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ShaderDescription {
+    pub vertex: String,
+    pub fragment: String,
+}
+
+// Verilmeyen alanlar Material::default değerlerini alır
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MaterialDescription {
+    pub diffuse: Option<[f32; 3]>,
+    pub specular: Option<[f32; 3]>,
+    pub shininess: Option<f32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CameraDescription {
+    pub position: [f32; 3],
+    pub target: [f32; 3],
+    pub fov_y: Option<f32>,  // Degrees
+    pub near: Option<f32>,
+    pub far: Option<f32>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LightsDescription {
+    pub ambient: Option<[f32; 3]>,
+    pub directional: Option<DirectionalLightDescription>,
+    #[serde(default)]
+    pub spots: Vec<SpotLightDescription>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DirectionalLightDescription {
+    pub direction: [f32; 3],
+    pub color: [f32; 3],
+    pub intensity: f32,
+    pub shadow: Option<ShadowSettings>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SpotLightDescription {
+    pub position: [f32; 3],
+    pub direction: [f32; 3],
+    pub color: [f32; 3],
+    pub inner_angle: f32,  // Degrees
+    pub outer_angle: f32,  // Degrees
+    pub intensity: Option<f32>,
+    pub range: Option<f32>,
+    pub shadow: Option<ShadowSettings>,
+}
+
+// Sahne ağacındaki bir düğüm. Açılar derece, dönüş hızı (spin) derece/saniye cinsindendir.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NodeDescription {
+    pub name: String,
+    pub shape: Option<ShapeDescription>,
+    pub translation: Option<[f32; 3]>,
+    pub rotation: Option<[f32; 3]>,
+    pub scale: Option<[f32; 3]>,
+    pub spin: Option<[f32; 3]>,
+    #[serde(default = "default_visible")]
+    pub visible: bool,
+    #[serde(default)]
+    pub children: Vec<NodeDescription>,
+}
+
+fn default_visible() -> bool {
+    true
+}
+
+// Desteklenen şekiller ve parametreleri. 2B şekillerin koordinatlarında y ekseni ekranın altından üstüne -1..1 aralığındadır, x ekseni en-boy oranı kadar genişler; düğüm dönüşümleri 2B şekillere de uygulanır.
+// Renkler "#rrggbb" gibi hex metinleri veya (r, g, b[, a]) dizileridir; `color` dolgu rengidir ve
+// `vertex_colors` ile çarpılır. Renkleri yalnızca renk shader'ları (color_*_shader.glsl) ve lit shader kullanır.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum ShapeDescription {
+    // Vertex colors go a, b, c
+    Triangle {
+        shader: String,
+        a: [f32; 2],
+        b: [f32; 2],
+        c: [f32; 2],
+        color: Option<Color>,
+        vertex_colors: Option<Vec<Color>>,
+    },
+    // Rotation is in degrees around the center; radii and vertex colors go top left, top right, bottom right, bottom left.
+    // `texture` is an image file stretched over the rectangle and tinted by `color`; use it with texture_fragment_shader.glsl
+    Rectangle {
+        shader: String,
+        top_right: [f32; 2],
+        bottom_left: [f32; 2],
+        rotation: Option<f32>,
+        radius: Option<f32>,
+        radii: Option<[f32; 4]>,
+        border: Option<f32>,
+        color: Option<Color>,
+        vertex_colors: Option<Vec<Color>>,
+        texture: Option<String>,
+    },
+    // Vertex colors are spread evenly around the edge, counter-clockwise from the rightmost point
+    Circle { shader: String, center: [f32; 2], radius: f32, color: Option<Color>, vertex_colors: Option<Vec<Color>> },
+    // Simple polygon, convex or concave, with optional holes; ring orientation does not matter
+    Polygon {
+        shader: String,
+        points: Vec<[f32; 2]>,
+        #[serde(default)]
+        holes: Vec<Vec<[f32; 2]>>,
+    },
+    // `color` tints the material; the 8 vertex colors are indexed by corner, bits 0-2 picking max x, y and z
+    Cube {
+        shader: String,
+        material: Option<String>,
+        min: [f32; 3],
+        max: [f32; 3],
+        color: Option<Color>,
+        vertex_colors: Option<Vec<Color>>,
+    },
+    Plane { shader: String, material: Option<String>, center: [f32; 3], half_size: f32 },
+    // Box drawn once per instance in a single draw call; the shader must use instanced_vertex_shader.glsl
+    Instanced { shader: String, material: Option<String>, min: [f32; 3], max: [f32; 3], instances: Vec<InstanceDescription> },
+    // Wavefront OBJ (.obj) or glTF 2.0 (.gltf, .glb) file; `material` overrides the file's own materials; `path` is looked up next to the scene file first
+    Model { shader: String, material: Option<String>, path: String },
+    // Filled and/or stroked vector path; the stroke is drawn over the fill
+    Path { shader: String, commands: Vec<PathCommandDescription>, fill: Option<FillRule>, stroke: Option<StrokeDescription> },
+}
+
+// Örneklenmiş bir şeklin tek örneği; açılar derece cinsindendir, renk malzemenin diffuse rengiyle çarpılır
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct InstanceDescription {
+    pub translation: [f32; 3],
+    pub rotation: Option<[f32; 3]>,
+    pub scale: Option<[f32; 3]>,
+    pub color: Option<Color>,
+}
+
+// Yol komutları. Yay açıları derece cinsindendir.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum PathCommandDescription {
+    MoveTo([f32; 2]),
+    LineTo([f32; 2]),
+    QuadraticTo { control: [f32; 2], to: [f32; 2] },
+    CubicTo { control1: [f32; 2], control2: [f32; 2], to: [f32; 2] },
+    Arc { center: [f32; 2], radius: f32, start: f32, sweep: f32 },
+    Close,
+}
+
+// Yol konturu; verilmeyen alanlar StrokeStyle::new varsayılanlarını kullanır
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StrokeDescription {
+    pub width: f32,
+    pub join: Option<LineJoin>,
+    pub cap: Option<LineCap>,
+    pub miter_limit: Option<f32>,
+}
+
+impl ShapeDescription {
+    // Şeklin kullandığı shader adını döndürür
+    fn shader(&self) -> &str {
+        match self {
+            ShapeDescription::Triangle { shader, .. }
+            | ShapeDescription::Rectangle { shader, .. }
+            | ShapeDescription::Circle { shader, .. }
+            | ShapeDescription::Polygon { shader, .. }
+            | ShapeDescription::Cube { shader, .. }
+            | ShapeDescription::Plane { shader, .. }
+            | ShapeDescription::Instanced { shader, .. }
+            | ShapeDescription::Model { shader, .. }
+            | ShapeDescription::Path { shader, .. } => shader,
+        }
+    }
+
+    // Şeklin kullandığı malzeme adını döndürür
+    fn material(&self) -> Option<&str> {
+        match self {
+            ShapeDescription::Cube { material, .. }
+            | ShapeDescription::Plane { material, .. }
+            | ShapeDescription::Instanced { material, .. }
+            | ShapeDescription::Model { material, .. } => material.as_deref(),
+            _ => None,
+        }
+    }
+
+    // Model şeklinin dosya yolunu döndürür
+    fn model_path(&self) -> Option<&str> {
+        match self {
+            ShapeDescription::Model { path, .. } => Some(path),
+            _ => None,
+        }
+    }
+
+    // Dikdörtgenin doku dosyasının yolunu döndürür
+    fn texture_path(&self) -> Option<&str> {
+        match self {
+            ShapeDescription::Rectangle { texture, .. } => texture.as_deref(),
+            _ => None,
+        }
+    }
+
+    // Şeklin okuduğu model veya doku dosyasının yolunu değiştirilebilir olarak döndürür
+    fn file_mut(&mut self) -> Option<&mut String> {
+        match self {
+            ShapeDescription::Model { path, .. } => Some(path),
+            ShapeDescription::Rectangle { texture, .. } => texture.as_mut(),
+            _ => None,
+        }
+    }
+
+    // Şeklin köşe renklerini ve kabul ettiği renk sayısını döndürür; sayı None ise en az bir renk yeterlidir
+    fn vertex_colors(&self) -> Option<(&[Color], Option<usize>)> {
+        match self {
+            ShapeDescription::Triangle { vertex_colors: Some(colors), .. } => Some((colors, Some(Triangle::VERTEX_COLORS))),
+            ShapeDescription::Rectangle { vertex_colors: Some(colors), .. } => Some((colors, Some(Rectangle::CORNER_COLORS))),
+            ShapeDescription::Circle { vertex_colors: Some(colors), .. } => Some((colors, None)),
+            ShapeDescription::Cube { vertex_colors: Some(colors), .. } => Some((colors, Some(Cube::CORNER_COLORS))),
+            _ => None,
+        }
+    }
+}
+
+fn vec3(v: [f32; 3]) -> Vector3D {
+    Vector3D::new(v[0], v[1], v[2])
+}
+
+fn vec2(v: [f32; 2]) -> Vector2D {
+    Vector2D::new(v[0], v[1])
+}
+
+// Yol tanımını doldurur ve/veya konturunu çıkarır
+fn tessellate_path(commands: &[PathCommandDescription], fill: Option<FillRule>, stroke: Option<&StrokeDescription>) -> Result<Tessellation, String> {
+    if fill.is_none() && stroke.is_none() {
+        return Err("path needs a fill rule, a stroke or both".to_string());
+    }
+    let path = commands.iter().fold(Path2D::new(), |path, command| match *command {
+        PathCommandDescription::MoveTo(to) => path.move_to(vec2(to)),
+        PathCommandDescription::LineTo(to) => path.line_to(vec2(to)),
+        PathCommandDescription::QuadraticTo { control, to } => path.quadratic_to(vec2(control), vec2(to)),
+        PathCommandDescription::CubicTo { control1, control2, to } => path.cubic_to(vec2(control1), vec2(control2), vec2(to)),
+        PathCommandDescription::Arc { center, radius, start, sweep } => {
+            path.arc(vec2(center), radius, start.to_radians(), sweep.to_radians())
+        }
+        PathCommandDescription::Close => path.close(),
+    });
+
+    let mut tessellation = match fill {
+        Some(rule) => path.fill(rule, path2d::DEFAULT_TOLERANCE)?,
+        None => Tessellation::default(),
+    };
+    if let Some(stroke) = stroke {
+        if stroke.width <= 0.0 {
+            return Err(format!("stroke width must be positive, got {}", stroke.width));
+        }
+        let mut style = StrokeStyle::new(stroke.width);
+        style.join = stroke.join.unwrap_or(style.join);
+        style.cap = stroke.cap.unwrap_or(style.cap);
+        style.miter_limit = stroke.miter_limit.unwrap_or(style.miter_limit);
+        tessellation.append(&path.stroke(&style, path2d::DEFAULT_TOLERANCE)?);
+    }
+    Ok(tessellation)
+}
+
+// Çokgen tanımının dış halkasını ve deliklerini Vector2D listelerine çevirir
+fn polygon_rings(points: &[[f32; 2]], holes: &[Vec<[f32; 2]>]) -> (Vec<Vector2D>, Vec<Vec<Vector2D>>) {
+    let ring = |points: &[[f32; 2]]| points.iter().copied().map(vec2).collect::<Vec<_>>();
+    (ring(points), holes.iter().map(|hole| ring(hole)).collect())
+}
+
+fn radians(v: [f32; 3]) -> Vector3D {
+    Vector3D::new(v[0].to_radians(), v[1].to_radians(), v[2].to_radians())
+}
+
+// Örnek tanımını düğümün dönüşüm sırasıyla (öteleme * dönüş * ölçek) bir örneğe çevirir
+fn instance(description: &InstanceDescription) -> Instance {
+    let transform = Transform {
+        translation: vec3(description.translation),
+        rotation: description.rotation.map_or(Vector3D::zero(), radians),
+        scale: description.scale.map_or(Vector3D::new(1.0, 1.0, 1.0), vec3),
+    };
+    Instance::new(transform.matrix()).with_color(description.color.unwrap_or(Color::WHITE).to_array())
+}
+
+// Sahne dosyasını varlık klasörlerinde bulur, uzantısına göre (.ron veya .json) okur ve doğrular
+pub fn load<P: AsRef<Path>>(path: P) -> Result<SceneDescription, String> {
+    let path = path.as_ref();
+    let source = locator::read_to_string(path)?;
+
+    let mut description = match path.extension().and_then(|ext| ext.to_str()) {
+        Some("ron") => parse_ron(&source),
+        Some("json") => parse_json(&source),
+        _ => Err("unknown scene format, expected a .ron or .json file".to_string()),
+    }
+    .map_err(|e| format!("{}:{}", path.display(), e))?;
+
+    // Model and texture files are looked up next to the scene file first, then in the asset roots
+    if let Some(directory) = locator::resolve(path).as_deref().and_then(Path::parent) {
+        description.prefer_directory(directory);
+    }
+    description.validate().map_err(|e| format!("{}: {}", path.display(), e))?;
+    Ok(description)
+}
+
+// RON metnini ayrıştırır; hatalar "satır:sütun: mesaj" biçimindedir
+pub fn parse_ron(source: &str) -> Result<SceneDescription, String> {
+    ron::from_str(source).map_err(|e| format!("{}:{}: {}", e.position.line, e.position.col, e.code))
+}
+
+// JSON metnini ayrıştırır; hatalar "satır:sütun: mesaj" biçimindedir
+pub fn parse_json(source: &str) -> Result<SceneDescription, String> {
+    serde_json::from_str(source).map_err(|e| {
+        // The message ends with the same position in words
+        let message = e.to_string();
+        let suffix = format!(" at line {} column {}", e.line(), e.column());
+        format!("{}:{}: {}", e.line(), e.column(), message.strip_suffix(&suffix).unwrap_or(&message))
+    })
+}
+
+impl SceneDescription {
+    // Verilen klasörde bulunan göreli model ve doku yollarını o klasöre bağlar
+    fn prefer_directory(&mut self, directory: &Path) {
+        fn visit(nodes: &mut [NodeDescription], directory: &Path) {
+            for node in nodes {
+                if let Some(file) = node.shape.as_mut().and_then(ShapeDescription::file_mut) {
+                    let candidate = directory.join(&*file);
+                    if Path::new(file).is_relative() && candidate.exists() {
+                        *file = candidate.to_string_lossy().into_owned();
+                    }
+                }
+                visit(&mut node.children, directory);
+            }
+        }
+        visit(&mut self.nodes, directory);
+    }
+
+    // Shader ve malzeme adlarını, shader dosyalarını ve ışık sayısını denetler.
+    // Hata mesajı sorunlu girdinin yolunu içerir, örneğin: nodes[2].children[0] 'arm': unknown shader 'lit'
+    pub fn validate(&self) -> Result<(), String> {
+        for (name, shader) in &self.shaders {
+            for file in [&shader.vertex, &shader.fragment] {
+                let path = shader::path(file);
+                if !locator::exists(&path) {
+                    return Err(format!("shaders '{}': file '{}' does not exist", name, path.display()));
+                }
+            }
+        }
+
+        if self.lights.spots.len() > MAX_SPOT_LIGHTS {
+            return Err(format!(
+                "lights.spots: {} spot lights given, at most {} are supported",
+                self.lights.spots.len(),
+                MAX_SPOT_LIGHTS
+            ));
+        }
+
+        if let Some(shadow) = self.lights.directional.as_ref().and_then(|light| light.shadow) {
+            shadow.validate().map_err(|e| format!("lights.directional.shadow: {}", e))?;
+        }
+        for (i, spot) in self.lights.spots.iter().enumerate() {
+            if let Some(shadow) = spot.shadow {
+                shadow.validate().map_err(|e| format!("lights.spots[{}].shadow: {}", i, e))?;
+            }
+        }
+
+        for (i, node) in self.nodes.iter().enumerate() {
+            self.validate_node(node, &format!("nodes[{}]", i))?;
+        }
+        Ok(())
+    }
+
+    // Düğümün ve çocuklarının başvurduğu shader ve malzemelerin tanımlı olduğunu denetler
+    fn validate_node(&self, node: &NodeDescription, location: &str) -> Result<(), String> {
+        if let Some(shape) = &node.shape {
+            if !self.shaders.contains_key(shape.shader()) {
+                return Err(format!(
+                    "{} '{}': unknown shader '{}' (defined: {})",
+                    location,
+                    node.name,
+                    shape.shader(),
+                    self.shaders.keys().cloned().collect::<Vec<_>>().join(", ")
+                ));
+            }
+            if let ShapeDescription::Model { path, .. } = shape {
+                if !matches!(model_format(path).as_deref(), Some("obj" | "gltf" | "glb")) {
+                    return Err(format!("{} '{}': unknown model format '{}', expected .obj, .gltf or .glb", location, node.name, path));
+                }
+                if !locator::exists(path) {
+                    return Err(format!("{} '{}': model file '{}' does not exist", location, node.name, path));
+                }
+            }
+            if let ShapeDescription::Rectangle { radius: Some(_), radii: Some(_), .. } = shape {
+                return Err(format!("{} '{}': give either radius or radii, not both", location, node.name));
+            }
+            if let ShapeDescription::Instanced { shader, .. } = shape {
+                let vertex = &self.shaders[shader].vertex;
+                if Path::new(vertex).file_name().and_then(|name| name.to_str()) != Some(instancing::VERTEX_SHADER) {
+                    return Err(format!(
+                        "{} '{}': instanced shapes need a shader with {} as its vertex stage, '{}' uses '{}'",
+                        location, node.name, instancing::VERTEX_SHADER, shader, vertex
+                    ));
+                }
+            }
+            if let ShapeDescription::Rectangle { texture: Some(path), .. } = shape {
+                if !locator::exists(path) {
+                    return Err(format!("{} '{}': texture file '{}' does not exist", location, node.name, path));
+                }
+            }
+            match shape.vertex_colors() {
+                Some((colors, Some(expected))) if colors.len() != expected => {
+                    return Err(format!("{} '{}': expected {} vertex colors, got {}", location, node.name, expected, colors.len()));
+                }
+                Some((&[], None)) => {
+                    return Err(format!("{} '{}': vertex_colors needs at least one color", location, node.name));
+                }
+                _ => {}
+            }
+            if let ShapeDescription::Polygon { points, holes, .. } = shape {
+                let (outer, holes) = polygon_rings(points, holes);
+                polygon::triangulate(&outer, &holes).map_err(|e| format!("{} '{}': {}", location, node.name, e))?;
+            }
+            if let ShapeDescription::Path { commands, fill, stroke, .. } = shape {
+                tessellate_path(commands, *fill, stroke.as_ref()).map_err(|e| format!("{} '{}': {}", location, node.name, e))?;
+            }
+            if let Some(material) = shape.material() {
+                if !self.materials.contains_key(material) {
+                    return Err(format!(
+                        "{} '{}': unknown material '{}' (defined: {})",
+                        location,
+                        node.name,
+                        material,
+                        self.materials.keys().cloned().collect::<Vec<_>>().join(", ")
+                    ));
+                }
+            }
+        }
+
+        for (i, child) in node.children.iter().enumerate() {
+            self.validate_node(child, &format!("{}.children[{}]", location, i))?;
+        }
+        Ok(())
+    }
+
+    // Shader'ları varlık yöneticisiyle yükler, şekilleri oluşturur ve sahneye, kameraya ve ışıklara uygular.
+    // OpenGL bağlamı hazır olduktan sonra çağrılmalıdır.
+    pub fn build(
+        &self,
+        assets: &mut AssetManager,
+        scene: &mut Scene,
+        camera: &mut Camera,
+        lighting: &mut Lighting,
+    ) -> Result<(), String> {
+        let mut shaders: BTreeMap<&str, Rc<Shader>> = BTreeMap::new();
+        for (name, shader) in &self.shaders {
+            let handle = assets
+                .load_shader(&shader.vertex, &shader.fragment)
+                .map_err(|e| format!("shaders '{}': {}", name, e))?;
+            shaders.insert(name.as_str(), handle.shared());
+        }
+
+        let materials: BTreeMap<&str, Material> = self
+            .materials
+            .iter()
+            .map(|(name, material)| {
+                let default = Material::default();
+                let material = Material::new(
+                    material.diffuse.map_or(default.diffuse, vec3),
+                    material.specular.map_or(default.specular, vec3),
+                    material.shininess.unwrap_or(default.shininess),
+                );
+                (name.as_str(), material)
+            })
+            .collect();
+
+        let mut models = BTreeMap::new();
+        for path in self.shape_paths(ShapeDescription::model_path) {
+            models.insert(path, load_model(path, assets)?);
+        }
+        // The texture shader linearizes the texels itself, like the flat colors
+        let mut textures = BTreeMap::new();
+        for path in self.shape_paths(ShapeDescription::texture_path) {
+            let texture = assets.load_texture(path, false).map_err(|e| format!("texture '{}': {}", path, e))?;
+            textures.insert(path, texture.shared());
+        }
+        let resources = Resources { shaders, materials, models, textures };
+
+        if let Some(description) = &self.camera {
+            camera.position = vec3(description.position);
+            camera.target = vec3(description.target);
+            camera.fov_y = description.fov_y.unwrap_or(camera.fov_y);
+            camera.near = description.near.unwrap_or(camera.near);
+            camera.far = description.far.unwrap_or(camera.far);
+        }
+
+        if let Some(ambient) = self.lights.ambient {
+            lighting.ambient = vec3(ambient);
+        }
+        lighting.directional = self.lights.directional.as_ref().map(|description| {
+            let light = DirectionalLight::new(vec3(description.direction), vec3(description.color), description.intensity);
+            match description.shadow {
+                Some(settings) => light.with_shadow(settings),
+                None => light,
+            }
+        });
+        lighting.spots = self
+            .lights
+            .spots
+            .iter()
+            .map(|description| {
+                let mut light = SpotLight::new(
+                    vec3(description.position),
+                    vec3(description.direction),
+                    vec3(description.color),
+                    description.inner_angle,
+                    description.outer_angle,
+                );
+                light.intensity = description.intensity.unwrap_or(light.intensity);
+                light.range = description.range.unwrap_or(light.range);
+                match description.shadow {
+                    Some(settings) => light.with_shadow(settings),
+                    None => light,
+                }
+            })
+            .collect();
+
+        let mut imported = Vec::new();
+        for node in &self.nodes {
+            build_node(node, None, scene, &resources, assets, &mut imported);
+        }
+        if !imported.is_empty() {
+            self.apply_imported(&imported, scene, camera, lighting);
+        }
+        Ok(())
+    }
+
+    // glTF dosyalarındaki kamera ve ışıkları, düğümlerinin dünya konumlarıyla uygular.
+    // Sahne dosyasının kendi kamerası ve ışıkları önceliklidir; noktasal ışıklar desteklenmez.
+    fn apply_imported(&self, imported: &[(NodeId, Imported)], scene: &mut Scene, camera: &mut Camera, lighting: &mut Lighting) {
+        scene.update(0.0);
+        scene.update_world(1.0);
+
+        let mut camera_set = self.camera.is_some();
+        for (id, object) in imported {
+            let world = scene.node(*id).world_matrix();
+            match object {
+                Imported::Camera(imported_camera) if !camera_set => {
+                    imported_camera.apply(camera, world);
+                    camera_set = true;
+                }
+                Imported::Camera(_) => {}
+                Imported::Light(light) => match light.to_light(world) {
+                    Some(ImportedLight::Directional(light)) if lighting.directional.is_none() => {
+                        lighting.directional = Some(light)
+                    }
+                    Some(ImportedLight::Spot(light)) if lighting.spots.len() < MAX_SPOT_LIGHTS => lighting.spots.push(light),
+                    Some(ImportedLight::Directional(_)) => {
+                        eprintln!("Skipping directional light '{}': the scene already has a directional light", light.name)
+                    }
+                    Some(ImportedLight::Spot(_)) => {
+                        eprintln!("Skipping spot light '{}': at most {} spot lights are supported", light.name, MAX_SPOT_LIGHTS)
+                    }
+                    None => eprintln!("Skipping point light '{}': point lights are not supported", light.name),
+                },
+            }
+        }
+    }
+
+    // Düğümlerin şekillerinden `path_of` ile seçilen dosyaları döndürür (her dosya bir kez)
+    fn shape_paths(&self, path_of: fn(&ShapeDescription) -> Option<&str>) -> Vec<&str> {
+        fn collect<'a>(nodes: &'a [NodeDescription], path_of: fn(&ShapeDescription) -> Option<&str>, paths: &mut Vec<&'a str>) {
+            for node in nodes {
+                if let Some(path) = node.shape.as_ref().and_then(path_of) {
+                    if !paths.contains(&path) {
+                        paths.push(path);
+                    }
+                }
+                collect(&node.children, path_of, paths);
+            }
+        }
+
+        let mut paths = Vec::new();
+        collect(&self.nodes, path_of, &mut paths);
+        paths
+    }
+}
+
+// Model dosyasının uzantısını küçük harfle döndürür
+fn model_format(path: &str) -> Option<String> {
+    Path::new(path).extension().and_then(|ext| ext.to_str()).map(str::to_ascii_lowercase)
+}
+
+// Bir model dosyasından GPU'ya yüklenmiş tek malzemeli parça
+struct ModelPart {
+    name: String,
+    mesh: Rc<Mesh>,
+    material: Option<Material>,
+    diffuse_map: Option<Rc<Texture>>,
+}
+
+// GPU'ya yüklenmiş bir glTF dosyası
+struct GltfModel {
+    asset: GltfAsset,
+    meshes: Vec<Vec<ModelPart>>,  // Indexed by glTF mesh, then by primitive
+}
+
+// GPU'ya yüklenmiş bir model dosyası
+enum LoadedModel {
+    Obj(Vec<ModelPart>),
+    Gltf(Box<GltfModel>),
+}
+
+// Model dosyasını biçimine göre okur ve mesh'lerini varlık yöneticisiyle GPU'ya yükler
+fn load_model(path: &str, assets: &mut AssetManager) -> Result<LoadedModel, String> {
+    if model_format(path).as_deref() == Some("obj") {
+        let model = obj::load(path)?;
+        let parts = model
+            .meshes
+            .iter()
+            .enumerate()
+            .map(|(i, mesh)| ModelPart {
+                name: mesh.name.clone(),
+                mesh: assets.add_mesh(&format!("{}#{}", path, i), &mesh.data).shared(),
+                material: model.material_of(mesh),
+                // A missing texture only loses the texture, like a missing material library
+                diffuse_map: model.diffuse_map_of(mesh).and_then(|map| match assets.load_texture(map, true) {
+                    Ok(texture) => Some(texture.shared()),
+                    Err(e) => {
+                        eprintln!("Skipping texture of '{}': {}", mesh.name, e);
+                        None
+                    }
+                }),
+            })
+            .collect();
+        return Ok(LoadedModel::Obj(parts));
+    }
+
+    let asset = gltf_import::load(path)?;
+    // Base color textures are sRGB; the other material textures are not used by the lit shader.
+    // PNG files go through the texture cache shared with OBJ and rectangle textures.
+    let diffuse_map = |primitive: &GltfPrimitive, assets: &mut AssetManager| {
+        let image = asset.materials[primitive.material?].base_color_texture?;
+        let texture = match &asset.image_files[image] {
+            Some(file) => assets.load_texture(file, true).ok(),
+            None => None,
+        };
+        let texture = texture.unwrap_or_else(|| assets.add_texture(&format!("{}#image{}", path, image), &asset.images[image], true));
+        Some(texture.shared())
+    };
+    let meshes = asset
+        .meshes
+        .iter()
+        .enumerate()
+        .map(|(i, mesh)| {
+            mesh.primitives
+                .iter()
+                .enumerate()
+                .map(|(j, primitive)| ModelPart {
+                    name: mesh.name.clone(),
+                    mesh: assets.add_mesh(&format!("{}#{}.{}", path, i, j), &primitive.data).shared(),
+                    material: Some(asset.material_of(primitive)),
+                    diffuse_map: diffuse_map(primitive, assets),
+                })
+                .collect()
+        })
+        .collect();
+    Ok(LoadedModel::Gltf(Box::new(GltfModel { asset, meshes })))
+}
+
+// Düğümler oluşturulurken adlarıyla başvurulan, yüklenmiş kaynaklar
+struct Resources<'a> {
+    shaders: BTreeMap<&'a str, Rc<Shader>>,
+    materials: BTreeMap<&'a str, Material>,
+    models: BTreeMap<&'a str, LoadedModel>,
+    textures: BTreeMap<&'a str, Rc<Texture>>,
+}
+
+// Model dosyalarından gelen, sahne kurulduktan sonra yerleştirilecek kamera ve ışıklar
+enum Imported<'r> {
+    Camera(&'r GltfCamera),
+    Light(&'r GltfLight),
+}
+
+// Modelin parçalarını verilen düğümün altına şekil olarak ekler
+fn add_parts(parts: &[ModelPart], parent: NodeId, scene: &mut Scene, shader: &Rc<Shader>, material: Option<Material>) {
+    for part in parts {
+        let material = material.or(part.material).unwrap_or_default();
+        let model = Model::new(part.mesh.clone(), shader.clone(), material).with_diffuse_map(part.diffuse_map.clone());
+        scene.add_shape(&part.name, Some(parent), Box::new(model));
+    }
+}
+
+// glTF düğümünü ve çocuklarını sahneye ekler
+fn build_gltf_node<'r>(
+    model: &'r GltfModel,
+    index: usize,
+    parent: NodeId,
+    scene: &mut Scene,
+    shader: &Rc<Shader>,
+    material: Option<Material>,
+    imported: &mut Vec<(NodeId, Imported<'r>)>,
+) {
+    let asset = &model.asset;
+    let node = &asset.nodes[index];
+    let id = scene.add_node(&node.name, Some(parent));
+    scene.node_mut(id).set_transform(node.transform);
+
+    if let Some(mesh) = node.mesh {
+        add_parts(&model.meshes[mesh], id, scene, shader, material);
+    }
+    if let Some(camera) = node.camera {
+        imported.push((id, Imported::Camera(&asset.cameras[camera])));
+    }
+    if let Some(light) = node.light {
+        imported.push((id, Imported::Light(&asset.lights[light])));
+    }
+    for &child in &node.children {
+        build_gltf_node(model, child, id, scene, shader, material, imported);
+    }
+}
+
+// Düğümü ve çocuklarını sahneye ekler
+fn build_node<'r>(
+    description: &NodeDescription,
+    parent: Option<NodeId>,
+    scene: &mut Scene,
+    resources: &'r Resources,
+    assets: &mut AssetManager,
+    imported: &mut Vec<(NodeId, Imported<'r>)>,
+) {
+    let id = match &description.shape {
+        Some(ShapeDescription::Model { shader, material, path }) => {
+            // Every part of the model becomes a child node so the parts can be transformed separately
+            let id = scene.add_node(&description.name, parent);
+            let shader = &resources.shaders[shader.as_str()];
+            let material = material.as_ref().map(|name| resources.materials[name.as_str()]);
+            match &resources.models[path.as_str()] {
+                LoadedModel::Obj(parts) => add_parts(parts, id, scene, shader, material),
+                LoadedModel::Gltf(model) => {
+                    for &root in &model.asset.roots {
+                        build_gltf_node(model, root, id, scene, shader, material, imported);
+                    }
+                }
+            }
+            id
+        }
+        Some(shape) => scene.add_shape(&description.name, parent, build_shape(shape, resources, assets)),
+        None => scene.add_node(&description.name, parent),
+    };
+
+    let node = scene.node_mut(id);
+    node.visible = description.visible;
+    node.set_transform(Transform {
+        translation: description.translation.map_or(Vector3D::zero(), vec3),
+        rotation: description.rotation.map_or(Vector3D::zero(), radians),
+        scale: description.scale.map_or(Vector3D::new(1.0, 1.0, 1.0), vec3),
+    });
+    node.angular_velocity = description.spin.map_or(Vector3D::zero(), radians);
+
+    for child in &description.children {
+        build_node(child, Some(id), scene, resources, assets, imported);
+    }
+}
+
+// Şekil tanımından şekli oluşturur (shader ve malzeme adları validate ile denetlenmiştir)
+fn build_shape(description: &ShapeDescription, resources: &Resources, assets: &mut AssetManager) -> Box<dyn Shape> {
+    let shader = resources.shaders[description.shader()].clone();
+    let material = description.material().map(|name| resources.materials[name]);
+
+    match description {
+        ShapeDescription::Triangle { a, b, c, color, vertex_colors, .. } => {
+            let mut triangle = Triangle::new(shader, vec2(*a), vec2(*b), vec2(*c));
+            triangle.color = color.unwrap_or(triangle.color);
+            if let Some(colors) = vertex_colors {
+                triangle.set_vertex_colors(colors).expect("vertex colors are counted by validate");
+            }
+            Box::new(triangle)
+        }
+        ShapeDescription::Rectangle { top_right, bottom_left, rotation, radius, radii, border, color, vertex_colors, texture, .. } => {
+            let mut style = RectangleStyle::new().with_rotation(rotation.unwrap_or(0.0).to_radians());
+            if let Some(radius) = radius {
+                style = style.with_radius(*radius);
+            }
+            if let Some(radii) = radii {
+                style = style.with_radii(*radii);
+            }
+            if let Some(border) = border {
+                style = style.with_border(*border);
+            }
+            let texture = texture.as_ref().map(|path| resources.textures[path.as_str()].clone());
+            let mut rectangle = Rectangle::styled(shader, vec2(*top_right), vec2(*bottom_left), &style).with_texture(texture);
+            rectangle.color = color.unwrap_or(rectangle.color);
+            if let Some(colors) = vertex_colors {
+                rectangle.set_vertex_colors(colors).expect("vertex colors are counted by validate");
+            }
+            Box::new(rectangle)
+        }
+        ShapeDescription::Circle { center, radius, color, vertex_colors, .. } => {
+            let mut circle = Circle::new(vec2(*center), *radius, shader);
+            circle.color = color.unwrap_or(circle.color);
+            if let Some(colors) = vertex_colors {
+                circle.set_vertex_colors(colors).expect("vertex colors are counted by validate");
+            }
+            Box::new(circle)
+        }
+        ShapeDescription::Polygon { points, holes, .. } => {
+            let (outer, holes) = polygon_rings(points, holes);
+            Box::new(Polygon::new(shader, &outer, &holes).expect("polygons are triangulated by validate"))
+        }
+        ShapeDescription::Cube { min, max, color, vertex_colors, .. } => {
+            let mut cube = Cube::new(shader, vec3(*min), vec3(*max));
+            if let Some(material) = material {
+                cube.material = material;
+            }
+            cube.color = color.unwrap_or(cube.color);
+            if let Some(colors) = vertex_colors {
+                cube.set_vertex_colors(colors).expect("vertex colors are counted by validate");
+            }
+            Box::new(cube)
+        }
+        ShapeDescription::Plane { center, half_size, .. } => {
+            let mut plane = Plane::new(shader, vec3(*center), *half_size);
+            if let Some(material) = material {
+                plane.material = material;
+            }
+            Box::new(plane)
+        }
+        ShapeDescription::Instanced { min, max, instances, .. } => {
+            // Boxes with the same corners share one mesh
+            let key = format!("cube{:?}{:?}", min, max);
+            let mesh = assets.add_mesh(&key, &Cube::mesh(vec3(*min), vec3(*max))).shared();
+            let mut instanced = InstancedMesh::new(mesh, shader, material.unwrap_or_default());
+            instanced.init();
+            for description in instances {
+                instanced.add(instance(description));
+            }
+            Box::new(instanced)
+        }
+        ShapeDescription::Path { commands, fill, stroke, .. } => {
+            // Paths are tessellated once by validate already, so this cannot fail here
+            let tessellation = tessellate_path(commands, *fill, stroke.as_ref()).unwrap_or_default();
+            Box::new(PathShape::new(shader, tessellation))
+        }
+        ShapeDescription::Model { .. } => unreachable!("models are built by build_node"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn demo_scene_is_valid() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("scenes").join("demo.ron");
+        let description = load(path).unwrap();
+        assert!(description.nodes.iter().any(|node| node.name == "cube"));
+    }
+
+    #[test]
+    fn json_and_ron_describe_the_same_scene() {
+        let ron = parse_ron(r#"(nodes: [(name: "root", children: [(name: "child", spin: Some((0.0, 90.0, 0.0)))])])"#).unwrap();
+        let json = parse_json(r#"{"nodes": [{"name": "root", "children": [{"name": "child", "spin": [0.0, 90.0, 0.0]}]}]}"#).unwrap();
+        assert_eq!(ron.nodes[0].children[0].name, json.nodes[0].children[0].name);
+        assert_eq!(ron.nodes[0].children[0].spin, json.nodes[0].children[0].spin);
+    }
+
+    #[test]
+    fn errors_point_to_the_offending_entry() {
+        let description = parse_ron(
+            r#"(nodes: [(name: "a"), (name: "b", children: [(name: "c", shape: Some(Circle(shader: "missing", center: (0.0, 0.0), radius: 1.0)))])])"#,
+        )
+        .unwrap();
+        let error = description.validate().unwrap_err();
+        assert!(error.starts_with("nodes[1].children[0] 'c': unknown shader 'missing'"), "{}", error);
+
+        let description = parse_ron(
+            r#"(shaders: {"lit": (vertex: "lit_vertex_shader.glsl", fragment: "lit_fragment_shader.glsl")},
+                nodes: [(name: "ship", shape: Some(Model(shader: "lit", path: "models/missing.obj")))])"#,
+        )
+        .unwrap();
+        let error = description.validate().unwrap_err();
+        assert_eq!(error, "nodes[0] 'ship': model file 'models/missing.obj' does not exist");
+        let description = parse_ron(
+            r#"(shaders: {"lit": (vertex: "lit_vertex_shader.glsl", fragment: "lit_fragment_shader.glsl")},
+                nodes: [(name: "ship", shape: Some(Model(shader: "lit", path: "models/ship.fbx")))])"#,
+        )
+        .unwrap();
+        let error = description.validate().unwrap_err();
+        assert!(error.starts_with("nodes[0] 'ship': unknown model format"), "{}", error);
+
+        let description = parse_ron(
+            r#"(shaders: {"lit": (vertex: "lit_vertex_shader.glsl", fragment: "lit_fragment_shader.glsl")},
+                nodes: [(name: "crowd", shape: Some(Instanced(shader: "lit", min: (0.0, 0.0, 0.0), max: (1.0, 1.0, 1.0),
+                    instances: [(translation: (0.0, 0.0, 0.0))])))])"#,
+        )
+        .unwrap();
+        let error = description.validate().unwrap_err();
+        assert_eq!(
+            error,
+            "nodes[0] 'crowd': instanced shapes need a shader with instanced_vertex_shader.glsl as its vertex stage, 'lit' uses 'lit_vertex_shader.glsl'"
+        );
+
+        let description = parse_ron(
+            r#"(shaders: {"red": (vertex: "vertex_shader.glsl", fragment: "red_fragment_shader.glsl")},
+                nodes: [(name: "line", shape: Some(Path(shader: "red", commands: [MoveTo((0.0, 0.0)), LineTo((1.0, 0.0))])))])"#,
+        )
+        .unwrap();
+        let error = description.validate().unwrap_err();
+        assert_eq!(error, "nodes[0] 'line': path needs a fill rule, a stroke or both");
+
+        let description = parse_ron(
+            r#"(shaders: {"red": (vertex: "vertex_shader.glsl", fragment: "red_fragment_shader.glsl")},
+                nodes: [(name: "sliver", shape: Some(Polygon(shader: "red", points: [(0.0, 0.0), (1.0, 1.0)])))])"#,
+        )
+        .unwrap();
+        let error = description.validate().unwrap_err();
+        assert_eq!(error, "nodes[0] 'sliver': polygon needs at least 3 points, got 2");
+        let description = parse_ron(
+            r#"(shaders: {"red": (vertex: "vertex_shader.glsl", fragment: "red_fragment_shader.glsl")},
+                nodes: [(name: "bow tie", shape: Some(Polygon(shader: "red", points: [(0.0, 0.0), (2.0, 2.0), (2.0, 0.0), (0.0, 1.0)])))])"#,
+        )
+        .unwrap();
+        let error = description.validate().unwrap_err();
+        assert!(error.starts_with("nodes[0] 'bow tie': polygon is self-intersecting"), "{}", error);
+
+        let description = parse_ron(
+            r##"(shaders: {"flat": (vertex: "color_vertex_shader.glsl", fragment: "color_fragment_shader.glsl")},
+                nodes: [(name: "tri", shape: Some(Triangle(shader: "flat", a: (0.0, 0.0), b: (1.0, 0.0), c: (0.0, 1.0),
+                    color: Some("#ff8000"), vertex_colors: Some(["#f00", (0.0, 1.0, 0.0)]))))])"##,
+        )
+        .unwrap();
+        let error = description.validate().unwrap_err();
+        assert_eq!(error, "nodes[0] 'tri': expected 3 vertex colors, got 2");
+
+        let error = parse_ron(r##"(nodes: [(name: "c", shape: Some(Circle(shader: "s", center: (0.0, 0.0), radius: 1.0, color: Some("#12"))))])"##)
+            .unwrap_err();
+        assert!(error.contains("invalid hex color '#12'"), "{}", error);
+
+        let error = parse_ron("(nodes: [(name: \"a\",\n  colour: 1)])").unwrap_err();
+        assert!(error.starts_with("2:"), "{}", error);
+    }
+
+    #[test]
+    fn json_errors_give_the_position_once() {
+        let error = parse_json("{\"nodes\": [{\"name\": 1}]}").unwrap_err();
+        assert!(error.starts_with("1:"), "{}", error);
+        assert!(!error.contains(" at line "), "{}", error);
+    }
+
+    #[test]
+    fn models_are_found_next_to_the_scene_file() {
+        let directory = std::env::temp_dir().join(format!("scene_file_test_{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(directory.join("ship.obj"), "v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n").unwrap();
+        let scene = directory.join("scene.json");
+        std::fs::write(
+            &scene,
+            r#"{"shaders": {"lit": {"vertex": "lit_vertex_shader.glsl", "fragment": "lit_fragment_shader.glsl"}},
+                "nodes": [{"name": "ship", "shape": {"Model": {"shader": "lit", "path": "ship.obj"}}}]}"#,
+        )
+        .unwrap();
+
+        let description = load(&scene).unwrap();
+        let Some(ShapeDescription::Model { path, .. }) = &description.nodes[0].shape else { panic!("expected a model") };
+        assert_eq!(Path::new(path), directory.join("ship.obj"));
+    }
+
+    #[test]
+    fn rectangle_textures_must_exist() {
+        let scene = |texture: &str| {
+            parse_ron(&format!(
+                r#"(shaders: {{"textured": (vertex: "vertex_shader.glsl", fragment: "texture_fragment_shader.glsl")}},
+                    nodes: [(name: "sign", shape: Some(Rectangle(shader: "textured", top_right: (1.0, 1.0), bottom_left: (0.0, 0.0),
+                        texture: Some({:?}))))])"#,
+                texture
+            ))
+            .unwrap()
+        };
+
+        let error = scene("textures/missing.png").validate().unwrap_err();
+        assert_eq!(error, "nodes[0] 'sign': texture file 'textures/missing.png' does not exist");
+
+        let image = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("golden").join("demo.png");
+        let description = scene(image.to_str().unwrap());
+        description.validate().unwrap();
+        assert_eq!(description.shape_paths(ShapeDescription::texture_path), [image.to_str().unwrap()]);
+    }
+
+    #[test]
+    fn shadow_settings_are_checked() {
+        let scene = |shadow: &str| {
+            parse_ron(&format!(
+                "(lights: (directional: Some((direction: (0.0, -1.0, 0.0), color: (1.0, 1.0, 1.0), intensity: 1.0, shadow: Some({})))))",
+                shadow
+            ))
+            .unwrap()
+            .validate()
+        };
+        assert_eq!(scene("(resolution: 0)").unwrap_err(), "lights.directional.shadow: resolution must be positive");
+        assert_eq!(scene("(cascade_count: 0)").unwrap_err(), "lights.directional.shadow: cascade_count must be between 1 and 4, got 0");
+        assert_eq!(scene("(cascade_count: 5)").unwrap_err(), "lights.directional.shadow: cascade_count must be between 1 and 4, got 5");
+        scene("(resolution: 512, cascade_count: 4)").unwrap();
+
+        let error = parse_ron(
+            "(lights: (spots: [(position: (0.0, 1.0, 0.0), direction: (0.0, -1.0, 0.0), color: (1.0, 1.0, 1.0),
+                inner_angle: 10.0, outer_angle: 20.0, shadow: Some((resolution: 0)))]))",
+        )
+        .unwrap()
+        .validate()
+        .unwrap_err();
+        assert_eq!(error, "lights.spots[0].shadow: resolution must be positive");
+    }
+
+    #[test]
+    fn instances_use_the_node_transform_order() {
+        let description = InstanceDescription {
+            translation: [1.0, 2.0, 3.0],
+            rotation: Some([0.0, 90.0, 0.0]),
+            scale: Some([2.0, 2.0, 2.0]),
+            color: None,
+        };
+        let instance = instance(&description);
+        let corner = instance.transform.transform_point(Vector3D::new(1.0, 0.0, 0.0));
+        assert!((corner - Vector3D::new(1.0, 2.0, 1.0)).magnitude() < 1e-5, "{:?}", corner);
+        assert_eq!(instance.color, [1.0; 4]);
+    }
+}
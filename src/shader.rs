@@ -1,32 +1,85 @@
 extern crate gl;
 use std::ffi::CString;
-use std::fs::File;
-use std::io::Read;
+use std::path::{Path, PathBuf};
 use std::ptr;
 use std::str;
+use std::sync::Mutex;
 use gl::types::*;
 
+use crate::locator;
+use crate::matrix4::Matrix4;
+use crate::vector3d::Vector3D;
+
+// Shader dosya adlarının çözüldüğü varsayılan klasör
+pub const DEFAULT_SHADER_DIR: &str = "src/Shaders";
+
+// set_directory ile değiştirilen shader klasörü
+static SHADER_DIR: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+// Shader dosya adlarının çözüldüğü klasörü değiştirir
+pub fn set_directory<P: AsRef<Path>>(directory: P) {
+    *SHADER_DIR.lock().unwrap() = Some(directory.as_ref().to_path_buf());
+}
+
+// Shader dosya adını shader klasörüne göre bir varlık yoluna çevirir (mutlak yollar değişmez)
+pub fn path(name: &str) -> PathBuf {
+    match &*SHADER_DIR.lock().unwrap() {
+        Some(directory) => directory.join(name),
+        None => Path::new(DEFAULT_SHADER_DIR).join(name),
+    }
+}
+
 pub struct Shader {
     id: gl::types::GLuint,
 }
 
-impl Shader {
-    // Yeni bir shader programı oluşturur
-    pub fn new(vertex_path: &str, fragment_path: &str) -> Shader {
-        let vertex_code = Shader::read_shader_source(vertex_path);
-        let fragment_code = Shader::read_shader_source(fragment_path);
-
-        let vertex_shader = Shader::compile_shader(&vertex_code, gl::VERTEX_SHADER);
-        let fragment_shader = Shader::compile_shader(&fragment_code, gl::FRAGMENT_SHADER);
+// Tek bir dosyadan derlenmiş vertex veya fragment aşaması
+pub struct ShaderStage {
+    id: GLuint,
+    path: PathBuf,
+}
 
-        let program_id = Shader::link_program(vertex_shader, fragment_shader);
+impl ShaderStage {
+    // Shader kaynak kodunu varlık klasörlerinden (veya gömülü kopyasından) okur ve verilen türde derler
+    pub fn load(path: &Path, kind: GLenum) -> Result<ShaderStage, String> {
+        let source = locator::read_to_string(path).map_err(|e| format!("Shader dosyası okunamadı: {}", e))?;
+        let id = Shader::compile_shader(&source, kind).map_err(|e| format!("{}: {}", path.display(), e))?;
+        Ok(ShaderStage { id, path: path.to_path_buf() })
+    }
+}
 
+impl Drop for ShaderStage {
+    // Aşama silindiğinde OpenGL shader nesnesini temizler; bağlanmış programlar etkilenmez
+    fn drop(&mut self) {
         unsafe {
-            gl::DeleteShader(vertex_shader);
-            gl::DeleteShader(fragment_shader);
+            gl::DeleteShader(self.id);
         }
+    }
+}
+
+impl Shader {
+    // Yeni bir shader programı oluşturur
+    pub fn new(vertex_path: impl AsRef<Path>, fragment_path: impl AsRef<Path>) -> Shader {
+        Shader::try_new(vertex_path, fragment_path).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    // Yeni bir shader programı oluşturur; okuma, derleme veya bağlama hatasında hata döndürür
+    pub fn try_new(vertex_path: impl AsRef<Path>, fragment_path: impl AsRef<Path>) -> Result<Shader, String> {
+        let vertex = ShaderStage::load(vertex_path.as_ref(), gl::VERTEX_SHADER)?;
+        let fragment = ShaderStage::load(fragment_path.as_ref(), gl::FRAGMENT_SHADER)?;
+        Shader::link(&vertex, &fragment)
+    }
+
+    // Derlenmiş vertex ve fragment aşamalarını bir programa bağlar; aşamalar başka programlarda yeniden kullanılabilir
+    pub fn link(vertex: &ShaderStage, fragment: &ShaderStage) -> Result<Shader, String> {
+        let program_id = Shader::link_program(vertex.id, fragment.id)
+            .map_err(|e| format!("{} + {}: {}", vertex.path.display(), fragment.path.display(), e))?;
+        Ok(Shader { id: program_id })
+    }
 
-        Shader { id: program_id }
+    // Shader klasöründeki vertex ve fragment dosyalarından bir shader programı oluşturur
+    pub fn load(vertex_name: &str, fragment_name: &str) -> Shader {
+        Shader::new(path(vertex_name), path(fragment_name))
     }
 
     // Shader programını kullanır
@@ -36,20 +89,12 @@ impl Shader {
         }
     }
 
-    // Shader kaynak kodunu dosyadan okur
-    fn read_shader_source(path: &str) -> String {
-        let mut file = File::open(path).expect("Shader dosyası açılamadı");
-        let mut source = String::new();
-        file.read_to_string(&mut source).expect("Shader dosyası okunamadı");
-        source
-    }
-
     // Shader'ı derler
-    fn compile_shader(source: &str, shader_type: GLenum) -> GLuint {
+    fn compile_shader(source: &str, shader_type: GLenum) -> Result<GLuint, String> {
         let shader;
         unsafe {
             shader = gl::CreateShader(shader_type);
-            let c_str = CString::new(source.as_bytes()).unwrap();
+            let c_str = CString::new(source.as_bytes()).map_err(|e| e.to_string())?;
             gl::ShaderSource(shader, 1, &c_str.as_ptr(), ptr::null());
             gl::CompileShader(shader);
 
@@ -58,16 +103,17 @@ impl Shader {
             if success != gl::TRUE as GLint {
                 let mut len = 0;
                 gl::GetShaderiv(shader, gl::INFO_LOG_LENGTH, &mut len);
-                let error = CString::from_vec_unchecked(vec![b' '; len as usize]);
-                gl::GetShaderInfoLog(shader, len, ptr::null_mut(), error.as_ptr() as *mut GLchar);
-                panic!("Shader derleme hatası: {}", error.to_str().unwrap());
+                let mut log = vec![0u8; len.max(1) as usize];
+                gl::GetShaderInfoLog(shader, len, ptr::null_mut(), log.as_mut_ptr() as *mut GLchar);
+                gl::DeleteShader(shader);
+                return Err(format!("Shader derleme hatası: {}", String::from_utf8_lossy(&log).trim_end_matches('\0')));
             }
         }
-        shader
+        Ok(shader)
     }
 
     // Vertex ve fragment shader'ları birleştirir
-    fn link_program(vertex_shader: GLuint, fragment_shader: GLuint) -> GLuint {
+    fn link_program(vertex_shader: GLuint, fragment_shader: GLuint) -> Result<GLuint, String> {
         let program;
         unsafe {
             program = gl::CreateProgram();
@@ -80,16 +126,72 @@ impl Shader {
             if success != gl::TRUE as GLint {
                 let mut len = 0;
                 gl::GetProgramiv(program, gl::INFO_LOG_LENGTH, &mut len);
-                let error = CString::from_vec_unchecked(vec![b' '; len as usize]);
-                gl::GetProgramInfoLog(program, len, ptr::null_mut(), error.as_ptr() as *mut GLchar);
-                panic!("Program bağlama hatası: {}", error.to_str().unwrap());
+                let mut log = vec![0u8; len.max(1) as usize];
+                gl::GetProgramInfoLog(program, len, ptr::null_mut(), log.as_mut_ptr() as *mut GLchar);
+                gl::DeleteProgram(program);
+                return Err(format!("Program bağlama hatası: {}", String::from_utf8_lossy(&log).trim_end_matches('\0')));
             }
         }
-        program
+        Ok(program)
     }
 
     // Shader program ID'sini döndürür
     pub fn id(&self) -> gl::types::GLuint {
         self.id
     }
+
+    // Uniform değişkeninin konumunu döndürür
+    fn uniform_location(&self, name: &str) -> GLint {
+        let c_name = CString::new(name).unwrap();
+        unsafe { gl::GetUniformLocation(self.id, c_name.as_ptr()) }
+    }
+
+    // mat4 uniform değerini atar
+    pub fn set_mat4(&self, name: &str, value: &Matrix4) {
+        unsafe {
+            gl::UniformMatrix4fv(self.uniform_location(name), 1, gl::FALSE, value.as_ptr());
+        }
+    }
+
+    // vec3 uniform değerini atar
+    pub fn set_vec3(&self, name: &str, value: Vector3D) {
+        unsafe {
+            gl::Uniform3f(self.uniform_location(name), value.x, value.y, value.z);
+        }
+    }
+
+    // vec4 uniform değerini atar
+    pub fn set_vec4(&self, name: &str, value: [f32; 4]) {
+        unsafe {
+            gl::Uniform4f(self.uniform_location(name), value[0], value[1], value[2], value[3]);
+        }
+    }
+
+    // float uniform değerini atar
+    pub fn set_float(&self, name: &str, value: f32) {
+        unsafe {
+            gl::Uniform1f(self.uniform_location(name), value);
+        }
+    }
+
+    // int (veya sampler) uniform değerini atar
+    pub fn set_int(&self, name: &str, value: i32) {
+        unsafe {
+            gl::Uniform1i(self.uniform_location(name), value);
+        }
+    }
+
+    // bool uniform değerini atar
+    pub fn set_bool(&self, name: &str, value: bool) {
+        self.set_int(name, value as i32);
+    }
+}
+
+impl Drop for Shader {
+    // Shader silindiğinde OpenGL programını temizler
+    fn drop(&mut self) {
+        unsafe {
+            gl::DeleteProgram(self.id);
+        }
+    }
 }
@@ -0,0 +1,389 @@
+extern crate gl;
+
+use gl::types::{GLint, GLuint};
+use serde::Deserialize;
+use std::ptr;
+
+use crate::camera::Camera;
+use crate::draw::Shape;
+use crate::light::{Lighting, MAX_SPOT_LIGHTS};
+use crate::matrix4::Matrix4;
+use crate::shader::Shader;
+use crate::vector3d::Vector3D;
+
+// Lit shader'ındaki kademe (cascade) dizisinin boyutu
+pub const MAX_CASCADES: usize = 4;
+
+// Gölge haritalarının bağlandığı doku birimleri
+const DIRECTIONAL_SHADOW_UNIT: u32 = 8;
+const SPOT_SHADOW_UNIT: u32 = 9;
+
+// Sahne dosyalarında verilmeyen alanlar varsayılan değerlerini alır
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ShadowSettings {
+    pub resolution: u32,
+    pub bias: f32,
+    pub normal_bias: f32,
+    pub pcf_radius: i32,
+    pub cascade_count: usize,   // Only used by directional lights
+    pub cascade_lambda: f32,    // 0 = uniform splits, 1 = logarithmic splits
+    pub max_distance: f32,      // Shadows are not rendered past this camera distance
+}
+
+impl Default for ShadowSettings {
+    fn default() -> Self {
+        ShadowSettings {
+            resolution: 2048,
+            bias: 0.002,
+            normal_bias: 0.01,
+            pcf_radius: 1,
+            cascade_count: 3,
+            cascade_lambda: 0.75,
+            max_distance: 20.0,
+        }
+    }
+}
+
+impl ShadowSettings {
+    // Gölge haritası oluşturulamayacak ayarlar için hata döndürür
+    pub fn validate(&self) -> Result<(), String> {
+        if self.resolution == 0 {
+            return Err("resolution must be positive".to_string());
+        }
+        if self.cascade_count == 0 || self.cascade_count > MAX_CASCADES {
+            return Err(format!("cascade_count must be between 1 and {}, got {}", MAX_CASCADES, self.cascade_count));
+        }
+        Ok(())
+    }
+}
+
+// Katmanlı derinlik dokusu ve ona bağlı framebuffer
+pub struct ShadowMap {
+    fbo: GLuint,
+    texture: GLuint,
+    resolution: u32,
+    layers: usize,
+}
+
+impl ShadowMap {
+    // Verilen çözünürlük ve katman sayısında yeni bir gölge haritası oluşturur
+    pub fn new(resolution: u32, layers: usize) -> ShadowMap {
+        let mut map = ShadowMap { fbo: 0, texture: 0, resolution, layers };
+
+        unsafe {
+            gl::GenTextures(1, &mut map.texture);
+            gl::BindTexture(gl::TEXTURE_2D_ARRAY, map.texture);
+            gl::TexImage3D(
+                gl::TEXTURE_2D_ARRAY,
+                0,
+                gl::DEPTH_COMPONENT24 as GLint,
+                resolution as i32,
+                resolution as i32,
+                layers as i32,
+                0,
+                gl::DEPTH_COMPONENT,
+                gl::FLOAT,
+                ptr::null(),
+            );
+            gl::TexParameteri(gl::TEXTURE_2D_ARRAY, gl::TEXTURE_MIN_FILTER, gl::NEAREST as GLint);
+            gl::TexParameteri(gl::TEXTURE_2D_ARRAY, gl::TEXTURE_MAG_FILTER, gl::NEAREST as GLint);
+            gl::TexParameteri(gl::TEXTURE_2D_ARRAY, gl::TEXTURE_WRAP_S, gl::CLAMP_TO_BORDER as GLint);
+            gl::TexParameteri(gl::TEXTURE_2D_ARRAY, gl::TEXTURE_WRAP_T, gl::CLAMP_TO_BORDER as GLint);
+            let border = [1.0f32, 1.0, 1.0, 1.0];
+            gl::TexParameterfv(gl::TEXTURE_2D_ARRAY, gl::TEXTURE_BORDER_COLOR, border.as_ptr());
+            gl::BindTexture(gl::TEXTURE_2D_ARRAY, 0);
+
+            gl::GenFramebuffers(1, &mut map.fbo);
+            gl::BindFramebuffer(gl::FRAMEBUFFER, map.fbo);
+            gl::FramebufferTextureLayer(gl::FRAMEBUFFER, gl::DEPTH_ATTACHMENT, map.texture, 0, 0);
+            gl::DrawBuffer(gl::NONE);
+            gl::ReadBuffer(gl::NONE);
+            if gl::CheckFramebufferStatus(gl::FRAMEBUFFER) != gl::FRAMEBUFFER_COMPLETE {
+                panic!("Gölge haritası framebuffer'ı eksik");
+            }
+            gl::BindFramebuffer(gl::FRAMEBUFFER, 0);
+        }
+
+        map
+    }
+
+    // Verilen katmanı derinlik hedefi olarak bağlar ve temizler
+    fn begin_layer(&self, layer: usize) {
+        unsafe {
+            gl::BindFramebuffer(gl::FRAMEBUFFER, self.fbo);
+            gl::FramebufferTextureLayer(gl::FRAMEBUFFER, gl::DEPTH_ATTACHMENT, self.texture, 0, layer as i32);
+            gl::Viewport(0, 0, self.resolution as i32, self.resolution as i32);
+            gl::Clear(gl::DEPTH_BUFFER_BIT);
+        }
+    }
+
+    // Derinlik dokusunu verilen doku birimine bağlar
+    fn bind_texture(&self, unit: u32) {
+        unsafe {
+            gl::ActiveTexture(gl::TEXTURE0 + unit);
+            gl::BindTexture(gl::TEXTURE_2D_ARRAY, self.texture);
+            gl::ActiveTexture(gl::TEXTURE0);
+        }
+    }
+
+    // Haritanın çözünürlüğünü döndürür
+    pub fn resolution(&self) -> u32 {
+        self.resolution
+    }
+
+    // Haritanın katman sayısını döndürür
+    pub fn layers(&self) -> usize {
+        self.layers
+    }
+}
+
+impl Drop for ShadowMap {
+    // Gölge haritası silindiğinde OpenGL kaynaklarını temizler
+    fn drop(&mut self) {
+        unsafe {
+            gl::DeleteFramebuffers(1, &self.fbo);
+            gl::DeleteTextures(1, &self.texture);
+        }
+    }
+}
+
+struct DirectionalShadow {
+    map: ShadowMap,
+    settings: ShadowSettings,
+    matrices: Vec<Matrix4>,
+    splits: Vec<f32>,
+}
+
+struct SpotShadows {
+    map: ShadowMap,
+    settings: [Option<ShadowSettings>; MAX_SPOT_LIGHTS],
+    matrices: [Matrix4; MAX_SPOT_LIGHTS],
+}
+
+pub struct ShadowRenderer {
+    depth_shader: Shader,
+    directional: Option<DirectionalShadow>,
+    spots: Option<SpotShadows>,
+}
+
+impl ShadowRenderer {
+    // Derinlik shader'ı ile yeni bir gölge çizici oluşturur
+    pub fn new(depth_shader: Shader) -> ShadowRenderer {
+        ShadowRenderer { depth_shader, directional: None, spots: None }
+    }
+
+    // Işıklara ve kameraya göre gölge haritalarını ve ışık matrislerini günceller
+    pub fn update(&mut self, lighting: &Lighting, camera: &Camera) {
+        self.update_directional(lighting, camera);
+        self.update_spots(lighting);
+    }
+
+    fn update_directional(&mut self, lighting: &Lighting, camera: &Camera) {
+        let light = match &lighting.directional {
+            Some(light) if light.shadow.is_some() => light,
+            _ => {
+                self.directional = None;
+                return;
+            }
+        };
+        let mut settings = light.shadow.unwrap();
+        settings.cascade_count = settings.cascade_count.clamp(1, MAX_CASCADES);
+
+        let reuse = matches!(&self.directional, Some(d)
+            if d.map.resolution() == settings.resolution && d.map.layers() == settings.cascade_count);
+        if !reuse {
+            self.directional = Some(DirectionalShadow {
+                map: ShadowMap::new(settings.resolution, settings.cascade_count),
+                settings,
+                matrices: Vec::new(),
+                splits: Vec::new(),
+            });
+        }
+
+        let far = camera.far.min(settings.max_distance);
+        let splits = cascade_splits(camera.near, far, settings.cascade_count, settings.cascade_lambda);
+        let mut matrices = Vec::with_capacity(splits.len());
+        let mut previous = camera.near;
+        for &split in &splits {
+            let corners = camera.frustum_corners(previous, split);
+            matrices.push(cascade_matrix(&corners, light.direction, settings.resolution));
+            previous = split;
+        }
+
+        let shadow = self.directional.as_mut().unwrap();
+        shadow.settings = settings;
+        shadow.splits = splits;
+        shadow.matrices = matrices;
+    }
+
+    fn update_spots(&mut self, lighting: &Lighting) {
+        let mut settings = [None; MAX_SPOT_LIGHTS];
+        let mut matrices = [Matrix4::identity(); MAX_SPOT_LIGHTS];
+        let mut resolution = 0;
+        for (i, light) in lighting.spots.iter().take(MAX_SPOT_LIGHTS).enumerate() {
+            if let Some(shadow) = light.shadow {
+                settings[i] = Some(shadow);
+                matrices[i] = light.light_space_matrix();
+                resolution = resolution.max(shadow.resolution);
+            }
+        }
+
+        if resolution == 0 {
+            self.spots = None;
+            return;
+        }
+
+        // All spot lights share one texture array sized for the most demanding light
+        let reuse = matches!(&self.spots, Some(s) if s.map.resolution() == resolution);
+        if !reuse {
+            self.spots = Some(SpotShadows {
+                map: ShadowMap::new(resolution, MAX_SPOT_LIGHTS),
+                settings,
+                matrices,
+            });
+        } else {
+            let spots = self.spots.as_mut().unwrap();
+            spots.settings = settings;
+            spots.matrices = matrices;
+        }
+    }
+
+    // Gölge düşüren şekilleri her ışığın derinlik haritasına çizer
+    pub fn render(&self, casters: &[(&dyn Shape, Matrix4)]) {
+        let mut viewport = [0 as GLint; 4];
+        let mut framebuffer = 0 as GLint;
+        unsafe {
+            gl::GetIntegerv(gl::VIEWPORT, viewport.as_mut_ptr());
+            gl::GetIntegerv(gl::FRAMEBUFFER_BINDING, &mut framebuffer);
+        }
+
+        self.depth_shader.use_program();
+
+        if let Some(directional) = &self.directional {
+            for (layer, matrix) in directional.matrices.iter().enumerate() {
+                directional.map.begin_layer(layer);
+                self.depth_shader.set_mat4("lightSpace", matrix);
+                for (caster, model) in casters {
+                    caster.draw_depth(&self.depth_shader, model);
+                }
+            }
+        }
+
+        if let Some(spots) = &self.spots {
+            for (layer, matrix) in spots.matrices.iter().enumerate() {
+                if spots.settings[layer].is_none() {
+                    continue;
+                }
+                spots.map.begin_layer(layer);
+                self.depth_shader.set_mat4("lightSpace", matrix);
+                for (caster, model) in casters {
+                    caster.draw_depth(&self.depth_shader, model);
+                }
+            }
+        }
+
+        unsafe {
+            gl::BindFramebuffer(gl::FRAMEBUFFER, framebuffer as GLuint);
+            gl::Viewport(viewport[0], viewport[1], viewport[2], viewport[3]);
+        }
+    }
+
+    // Gölge haritası çizen ışıkların ışık uzayı matrislerini döndürür: önce yönlü ışığın kademeleri, sonra spot ışıklar
+    pub fn light_matrices(&self) -> Vec<Matrix4> {
+        let cascades = self.directional.iter().flat_map(|directional| directional.matrices.iter().copied());
+        let spots = self.spots.iter().flat_map(|spots| {
+            spots.matrices.iter().zip(&spots.settings).filter(|(_, settings)| settings.is_some()).map(|(matrix, _)| *matrix)
+        });
+        cascades.chain(spots).collect()
+    }
+
+    // Gölge uniform'larını yükler ve gölge haritalarını bağlar
+    pub fn apply(&self, shader: &Shader) {
+        shader.set_int("dirShadowMap", DIRECTIONAL_SHADOW_UNIT as i32);
+        shader.set_int("spotShadowMap", SPOT_SHADOW_UNIT as i32);
+
+        match &self.directional {
+            Some(directional) => {
+                directional.map.bind_texture(DIRECTIONAL_SHADOW_UNIT);
+                let settings = &directional.settings;
+                shader.set_bool("dirShadow.enabled", true);
+                shader.set_int("dirShadow.cascadeCount", directional.matrices.len() as i32);
+                shader.set_float("dirShadow.bias", settings.bias);
+                shader.set_float("dirShadow.normalBias", settings.normal_bias);
+                shader.set_int("dirShadow.pcfRadius", settings.pcf_radius);
+                for (i, (matrix, split)) in directional.matrices.iter().zip(&directional.splits).enumerate() {
+                    shader.set_mat4(&format!("dirShadow.matrices[{}]", i), matrix);
+                    shader.set_float(&format!("dirShadow.splits[{}]", i), *split);
+                }
+            }
+            None => shader.set_bool("dirShadow.enabled", false),
+        }
+
+        for i in 0..MAX_SPOT_LIGHTS {
+            let prefix = format!("spotShadows[{}]", i);
+            let active = self.spots.as_ref().and_then(|s| s.settings[i].map(|settings| (s, settings)));
+            match active {
+                Some((spots, settings)) => {
+                    shader.set_bool(&format!("{}.enabled", prefix), true);
+                    shader.set_mat4(&format!("{}.matrix", prefix), &spots.matrices[i]);
+                    shader.set_float(&format!("{}.bias", prefix), settings.bias);
+                    shader.set_float(&format!("{}.normalBias", prefix), settings.normal_bias);
+                    shader.set_int(&format!("{}.pcfRadius", prefix), settings.pcf_radius);
+                }
+                None => shader.set_bool(&format!("{}.enabled", prefix), false),
+            }
+        }
+        if let Some(spots) = &self.spots {
+            spots.map.bind_texture(SPOT_SHADOW_UNIT);
+        }
+    }
+}
+
+// Kamera görüş hacmini kademelere bölen uzaklıkları hesaplar (uniform ve logaritmik karışımı)
+fn cascade_splits(near: f32, far: f32, count: usize, lambda: f32) -> Vec<f32> {
+    (1..=count)
+        .map(|i| {
+            let p = i as f32 / count as f32;
+            let log = near * (far / near).powf(p);
+            let uniform = near + (far - near) * p;
+            lambda * log + (1.0 - lambda) * uniform
+        })
+        .collect()
+}
+
+// Bir kademenin köşelerini kapsayan, texel'e hizalı ortografik ışık matrisini hesaplar
+fn cascade_matrix(corners: &[Vector3D; 8], direction: Vector3D, resolution: u32) -> Matrix4 {
+    let mut center = Vector3D::zero();
+    for corner in corners {
+        center = center + *corner;
+    }
+    center = center / 8.0;
+
+    // A bounding sphere keeps the projection size stable while the camera rotates
+    let mut radius: f32 = 0.0;
+    for corner in corners {
+        radius = radius.max((*corner - center).magnitude());
+    }
+    radius = (radius * 16.0).ceil() / 16.0;
+
+    let up = if direction.y.abs() > 0.99 {
+        Vector3D::new(1.0, 0.0, 0.0)
+    } else {
+        Vector3D::new(0.0, 1.0, 0.0)
+    };
+    // Pull the eye back so casters between the light and the cascade are not clipped
+    let eye = center - direction * (radius * 3.0);
+    let view = Matrix4::look_at(eye, center, up);
+    let mut projection = Matrix4::orthographic(-radius, radius, -radius, radius, 0.0, radius * 6.0);
+
+    // Snap the origin to whole texels to avoid shimmering edges
+    let half = resolution as f32 / 2.0;
+    let origin = (projection * view).transform_point(Vector3D::zero());
+    let offset_x = ((origin.x * half).round() - origin.x * half) / half;
+    let offset_y = ((origin.y * half).round() - origin.y * half) / half;
+    projection.set(3, 0, projection.get(3, 0) + offset_x);
+    projection.set(3, 1, projection.get(3, 1) + offset_y);
+
+    projection * view
+}
@@ -0,0 +1,542 @@
+use std::collections::HashMap;
+use std::ffi::c_void;
+use std::mem;
+use std::rc::Rc;
+
+use crate::matrix4::Matrix4;
+use crate::shader::Shader;
+use crate::texture::{Texture, TextureFormat};
+use crate::vector2d::Vector2D;
+
+// Bir köşenin kayan nokta sayısı: konum (2), doku koordinatı (2), renk (4)
+const VERTEX_FLOATS: usize = 8;
+
+// Dokudaki dikdörtgen bir bölgenin doku koordinatları
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UvRect {
+    pub min: [f32; 2],
+    pub max: [f32; 2],
+}
+
+impl UvRect {
+    // Dokunun tamamı
+    pub const FULL: UvRect = UvRect { min: [0.0, 0.0], max: [1.0, 1.0] };
+
+    // Köşeleri verilen bölgeyi oluşturur
+    pub fn new(min: [f32; 2], max: [f32; 2]) -> UvRect {
+        UvRect { min, max }
+    }
+
+    // Bölgedeki (0-1 aralığında) göreli bir noktanın doku koordinatını döndürür
+    pub fn lerp(&self, s: f32, t: f32) -> [f32; 2] {
+        [self.min[0] + (self.max[0] - self.min[0]) * s, self.min[1] + (self.max[1] - self.min[1]) * t]
+    }
+}
+
+// Dönebilen, dokulu veya düz renkli bir dörtgen.
+// Origin, dönüş ve konumlandırma noktasıdır ve boyuta göre (0-1 aralığında) verilir.
+#[derive(Debug, Clone, Copy)]
+pub struct Quad {
+    pub position: Vector2D,
+    pub size: Vector2D,
+    pub origin: Vector2D,
+    pub rotation: f32,  // Radians
+    pub uv: UvRect,
+    pub color: [f32; 4],
+    pub depth: f32,
+}
+
+impl Quad {
+    // Sol üst köşesi verilen konumda olan, beyaz ve dönmemiş bir dörtgen oluşturur
+    pub fn new(position: Vector2D, size: Vector2D) -> Quad {
+        Quad {
+            position,
+            size,
+            origin: Vector2D::zero(),
+            rotation: 0.0,
+            uv: UvRect::FULL,
+            color: [1.0; 4],
+            depth: 0.0,
+        }
+    }
+
+    // Dönüş noktası ve açısı değiştirilmiş bir kopya döndürür
+    pub fn with_rotation(mut self, origin: Vector2D, rotation: f32) -> Quad {
+        self.origin = origin;
+        self.rotation = rotation;
+        self
+    }
+
+    // Doku bölgesi değiştirilmiş bir kopya döndürür
+    pub fn with_uv(mut self, uv: UvRect) -> Quad {
+        self.uv = uv;
+        self
+    }
+
+    // Rengi değiştirilmiş bir kopya döndürür
+    pub fn with_color(mut self, color: [f32; 4]) -> Quad {
+        self.color = color;
+        self
+    }
+
+    // Derinliği değiştirilmiş bir kopya döndürür
+    pub fn with_depth(mut self, depth: f32) -> Quad {
+        self.depth = depth;
+        self
+    }
+
+    // Köşeleri sol üst, sağ üst, sağ alt, sol alt sırasıyla döndürür
+    pub fn corners(&self) -> [Vector2D; 4] {
+        let (sin, cos) = self.rotation.sin_cos();
+        [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)].map(|(s, t)| {
+            let local = Vector2D::new((s - self.origin.x) * self.size.x, (t - self.origin.y) * self.size.y);
+            self.position + Vector2D::new(local.x * cos - local.y * sin, local.x * sin + local.y * cos)
+        })
+    }
+}
+
+// Çizimlerin gruplanma sırası. Çizici alfa karışımıyla çizdiği için varsayılan BackToFront'tur;
+// Texture daha az çizim çağrısı yapar ama farklı dokulu çizimler arasında derinlik sırasını bozar,
+// bu yüzden yalnızca opak veya birbiriyle örtüşmeyen çizimler için uygundur.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortMode {
+    // Gönderim sırası korunur; yalnızca art arda gelen aynı doku ve shader'lı çizimler birleşir
+    Submission,
+    // Çizim çağrısı sayısını en aza indirmek için shader ve dokuya, sonra derinliğe göre sıralar
+    Texture,
+    // Saydam çizimler için önce derinliğe (uzaktan yakına), sonra shader ve dokuya göre sıralar
+    BackToFront,
+}
+
+// Bir çizimin birleştirilebileceği çizimleri belirleyen anahtar; sıfır varsayılanı belirtir
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct BatchKey {
+    pub shader: u32,
+    pub texture: u32,
+}
+
+// Kuyruktaki tek bir çizim (dörtgen, üçgen veya daire)
+#[derive(Debug, Clone, Copy)]
+struct Item {
+    key: BatchKey,
+    depth: f32,
+    first_vertex: u32,
+    first_index: usize,
+    index_count: usize,
+}
+
+// Tek çağrıda çizilecek indeks aralığı
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DrawCall {
+    pub key: BatchKey,
+    pub first_index: usize,
+    pub index_count: usize,
+}
+
+// Sıralanmış ve çizim çağrılarına bölünmüş geometri
+#[derive(Debug, Clone, Default)]
+pub struct BatchGeometry {
+    pub vertices: Vec<f32>,
+    pub indices: Vec<u32>,
+    pub draws: Vec<DrawCall>,
+}
+
+// Çizimleri CPU tarafında biriktiren kuyruk; OpenGL'e bağımlı değildir
+#[derive(Debug, Clone)]
+pub struct SpriteQueue {
+    vertices: Vec<f32>,
+    indices: Vec<u32>,
+    items: Vec<Item>,
+    pub circle_segments: u32,
+}
+
+impl Default for SpriteQueue {
+    fn default() -> Self {
+        SpriteQueue { vertices: Vec::new(), indices: Vec::new(), items: Vec::new(), circle_segments: 32 }
+    }
+}
+
+impl SpriteQueue {
+    // Boş bir kuyruk oluşturur
+    pub fn new() -> SpriteQueue {
+        SpriteQueue::default()
+    }
+
+    // Köşeleri ve bunlara göre (sıfırdan başlayan) indeksleri tek bir çizim olarak ekler
+    pub fn push(&mut self, key: BatchKey, depth: f32, vertices: &[([f32; 2], [f32; 2], [f32; 4])], indices: &[u32]) {
+        self.items.push(Item {
+            key,
+            depth,
+            first_vertex: (self.vertices.len() / VERTEX_FLOATS) as u32,
+            first_index: self.indices.len(),
+            index_count: indices.len(),
+        });
+        for (position, uv, color) in vertices {
+            self.vertices.extend_from_slice(position);
+            self.vertices.extend_from_slice(uv);
+            self.vertices.extend_from_slice(color);
+        }
+        self.indices.extend_from_slice(indices);
+    }
+
+    // Dörtgen ekler
+    pub fn quad(&mut self, key: BatchKey, quad: &Quad) {
+        let corners = quad.corners();
+        let uvs = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)].map(|(s, t)| quad.uv.lerp(s, t));
+        let vertices: Vec<_> = (0..4).map(|i| ([corners[i].x, corners[i].y], uvs[i], quad.color)).collect();
+        self.push(key, quad.depth, &vertices, &[0, 1, 2, 0, 2, 3]);
+    }
+
+    // Köşe doku koordinatlarıyla birlikte üçgen ekler
+    pub fn triangle(&mut self, key: BatchKey, points: [Vector2D; 3], uvs: [[f32; 2]; 3], color: [f32; 4], depth: f32) {
+        let vertices: Vec<_> = (0..3).map(|i| ([points[i].x, points[i].y], uvs[i], color)).collect();
+        self.push(key, depth, &vertices, &[0, 1, 2]);
+    }
+
+    // Merkezden yelpaze biçiminde üçgenlenmiş daire ekler; doku daireyi çevreleyen kareye oturtulur
+    pub fn circle(&mut self, key: BatchKey, center: Vector2D, radius: f32, uv: UvRect, color: [f32; 4], depth: f32) {
+        let segments = self.circle_segments.max(3);
+        let mut vertices = vec![([center.x, center.y], uv.lerp(0.5, 0.5), color)];
+        for i in 0..segments {
+            let theta = 2.0 * std::f32::consts::PI * i as f32 / segments as f32;
+            let (sin, cos) = theta.sin_cos();
+            vertices.push((
+                [center.x + radius * cos, center.y + radius * sin],
+                uv.lerp((cos + 1.0) / 2.0, (sin + 1.0) / 2.0),
+                color,
+            ));
+        }
+        let indices: Vec<u32> = (1..=segments).flat_map(|i| [0, i, i % segments + 1]).collect();
+        self.push(key, depth, &vertices, &indices);
+    }
+
+    // Kuyruktaki çizim sayısını döndürür
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    // Kuyruk boşsa true döndürür
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    // Kuyruğu boşaltır; ayrılan bellek sonraki kare için korunur
+    pub fn clear(&mut self) {
+        self.vertices.clear();
+        self.indices.clear();
+        self.items.clear();
+    }
+
+    // Çizimleri sıralar ve aynı anahtarlı ardışık çizimleri tek çağrıda birleştirir.
+    // Köşeler gönderim sırasında kalır, yalnızca indeksler yeniden dizilir.
+    pub fn build(&self, mode: SortMode) -> BatchGeometry {
+        let mut order: Vec<usize> = (0..self.items.len()).collect();
+        // Stable sorts keep submission order between items that compare equal
+        match mode {
+            SortMode::Submission => {}
+            SortMode::Texture => order.sort_by(|&a, &b| {
+                let (a, b) = (&self.items[a], &self.items[b]);
+                a.key.cmp(&b.key).then(b.depth.total_cmp(&a.depth))
+            }),
+            SortMode::BackToFront => order.sort_by(|&a, &b| {
+                let (a, b) = (&self.items[a], &self.items[b]);
+                b.depth.total_cmp(&a.depth).then(a.key.cmp(&b.key))
+            }),
+        }
+
+        let mut geometry = BatchGeometry { vertices: self.vertices.clone(), ..BatchGeometry::default() };
+        for item in order.into_iter().map(|i| &self.items[i]) {
+            let first_index = geometry.indices.len();
+            let local = &self.indices[item.first_index..item.first_index + item.index_count];
+            geometry.indices.extend(local.iter().map(|index| index + item.first_vertex));
+
+            match geometry.draws.last_mut() {
+                Some(draw) if draw.key == item.key => draw.index_count += item.index_count,
+                _ => geometry.draws.push(DrawCall { key: item.key, first_index, index_count: item.index_count }),
+            }
+        }
+        geometry
+    }
+}
+
+// Son boşaltmanın istatistikleri
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BatchStats {
+    pub primitives: usize,
+    pub triangles: usize,
+    pub draw_calls: usize,
+}
+
+// Dörtgen, üçgen ve daireleri tek dinamik tamponda biriktirip en az çizim çağrısıyla çizen 2B çizici.
+// Özel shader'lar sprite_vertex_shader.glsl ile aynı köşe özelliklerini ve uniform adlarını kullanmalıdır.
+pub struct SpriteBatch {
+    queue: SpriteQueue,
+    pub sort_mode: SortMode,
+    default_shader: Rc<Shader>,
+    shader: Option<Rc<Shader>>,
+    white: Rc<Texture>,
+    textures: HashMap<u32, Rc<Texture>>,
+    shaders: HashMap<u32, Rc<Shader>>,
+    vao: gl::types::GLuint,
+    vbo: gl::types::GLuint,
+    ebo: gl::types::GLuint,
+    stats: BatchStats,
+}
+
+impl SpriteBatch {
+    // Varsayılan sprite shader'ı ve boş tamponlarla yeni bir çizici oluşturur
+    pub fn new() -> SpriteBatch {
+        let white = Texture::from_data(1, 1, TextureFormat::Rgba8, Some(&[255; 4]));
+        let mut batch = SpriteBatch {
+            queue: SpriteQueue::new(),
+            sort_mode: SortMode::BackToFront,
+            default_shader: Rc::new(Shader::load("sprite_vertex_shader.glsl", "sprite_fragment_shader.glsl")),
+            shader: None,
+            white: Rc::new(white),
+            textures: HashMap::new(),
+            shaders: HashMap::new(),
+            vao: 0,
+            vbo: 0,
+            ebo: 0,
+            stats: BatchStats::default(),
+        };
+
+        let stride = (VERTEX_FLOATS * mem::size_of::<f32>()) as gl::types::GLsizei;
+        let float = mem::size_of::<f32>();
+        unsafe {
+            gl::GenVertexArrays(1, &mut batch.vao);
+            gl::GenBuffers(1, &mut batch.vbo);
+            gl::GenBuffers(1, &mut batch.ebo);
+
+            gl::BindVertexArray(batch.vao);
+            gl::BindBuffer(gl::ARRAY_BUFFER, batch.vbo);
+            gl::BindBuffer(gl::ELEMENT_ARRAY_BUFFER, batch.ebo);
+
+            gl::VertexAttribPointer(0, 2, gl::FLOAT, gl::FALSE, stride, std::ptr::null());
+            gl::EnableVertexAttribArray(0);
+            gl::VertexAttribPointer(1, 2, gl::FLOAT, gl::FALSE, stride, (2 * float) as *const c_void);
+            gl::EnableVertexAttribArray(1);
+            gl::VertexAttribPointer(2, 4, gl::FLOAT, gl::FALSE, stride, (4 * float) as *const c_void);
+            gl::EnableVertexAttribArray(2);
+
+            gl::BindVertexArray(0);
+            gl::BindBuffer(gl::ARRAY_BUFFER, 0);
+            gl::BindBuffer(gl::ELEMENT_ARRAY_BUFFER, 0);
+        }
+        batch
+    }
+
+    // Sol üst köşesi (0, 0) olan, piksel birimli ekran izdüşümünü döndürür
+    pub fn screen_projection(width: u32, height: u32) -> Matrix4 {
+        Matrix4::orthographic(0.0, width as f32, height as f32, 0.0, -1.0, 1.0)
+    }
+
+    // Sonraki çizimlerin shader'ını seçer; None varsayılan sprite shader'ına döner
+    pub fn set_shader(&mut self, shader: Option<Rc<Shader>>) {
+        self.shader = shader;
+    }
+
+    // Çizimin anahtarını oluşturur ve anahtarın gösterdiği doku ve shader'ı saklar
+    fn key(&mut self, texture: Option<&Rc<Texture>>) -> BatchKey {
+        let texture = texture.map_or(0, |texture| {
+            self.textures.entry(texture.id()).or_insert_with(|| texture.clone());
+            texture.id()
+        });
+        let shader = self.shader.as_ref().map_or(0, |shader| {
+            self.shaders.entry(shader.id()).or_insert_with(|| shader.clone());
+            shader.id()
+        });
+        BatchKey { shader, texture }
+    }
+
+    // Dörtgen ekler; doku verilmezse düz renkli çizilir
+    pub fn quad(&mut self, texture: Option<&Rc<Texture>>, quad: &Quad) {
+        let key = self.key(texture);
+        self.queue.quad(key, quad);
+    }
+
+    // Düz renkli üçgen ekler
+    pub fn triangle(&mut self, points: [Vector2D; 3], color: [f32; 4], depth: f32) {
+        let key = self.key(None);
+        self.queue.triangle(key, points, [[0.0, 0.0]; 3], color, depth);
+    }
+
+    // Dokulu üçgen ekler
+    pub fn textured_triangle(&mut self, texture: &Rc<Texture>, points: [Vector2D; 3], uvs: [[f32; 2]; 3], color: [f32; 4], depth: f32) {
+        let key = self.key(Some(texture));
+        self.queue.triangle(key, points, uvs, color, depth);
+    }
+
+    // Daire ekler; doku verilirse daireyi çevreleyen kareye oturtulur
+    pub fn circle(&mut self, texture: Option<&Rc<Texture>>, center: Vector2D, radius: f32, color: [f32; 4], depth: f32) {
+        let key = self.key(texture);
+        self.queue.circle(key, center, radius, UvRect::FULL, color, depth);
+    }
+
+    // Dairelerin kenar sayısını ayarlar
+    pub fn set_circle_segments(&mut self, segments: u32) {
+        self.queue.circle_segments = segments;
+    }
+
+    // Biriken çizimleri verilen izdüşümle çizer ve kuyruğu boşaltır.
+    // Derinlik testi kapatılır ve alfa karışımı açılır; önceki durum geri yüklenir.
+    pub fn flush(&mut self, projection: &Matrix4) -> BatchStats {
+        let geometry = self.queue.build(self.sort_mode);
+        self.stats = BatchStats {
+            primitives: self.queue.len(),
+            triangles: geometry.indices.len() / 3,
+            draw_calls: geometry.draws.len(),
+        };
+        self.queue.clear();
+        if geometry.draws.is_empty() {
+            return self.stats;
+        }
+
+        let (mut depth_test, mut blend) = (0, 0);
+        unsafe {
+            gl::GetIntegerv(gl::DEPTH_TEST, &mut depth_test);
+            gl::GetIntegerv(gl::BLEND, &mut blend);
+            gl::Disable(gl::DEPTH_TEST);
+            gl::Enable(gl::BLEND);
+            gl::BlendFunc(gl::SRC_ALPHA, gl::ONE_MINUS_SRC_ALPHA);
+
+            gl::BindVertexArray(self.vao);
+            // Re-specifying the whole buffer every flush lets the driver orphan the previous frame's storage
+            gl::BindBuffer(gl::ARRAY_BUFFER, self.vbo);
+            gl::BufferData(
+                gl::ARRAY_BUFFER,
+                (geometry.vertices.len() * mem::size_of::<f32>()) as gl::types::GLsizeiptr,
+                geometry.vertices.as_ptr() as *const c_void,
+                gl::STREAM_DRAW,
+            );
+            gl::BufferData(
+                gl::ELEMENT_ARRAY_BUFFER,
+                (geometry.indices.len() * mem::size_of::<u32>()) as gl::types::GLsizeiptr,
+                geometry.indices.as_ptr() as *const c_void,
+                gl::STREAM_DRAW,
+            );
+        }
+
+        let mut current_shader = None;
+        for draw in &geometry.draws {
+            if current_shader != Some(draw.key.shader) {
+                let shader = self.shaders.get(&draw.key.shader).unwrap_or(&self.default_shader);
+                shader.use_program();
+                shader.set_mat4("projection", projection);
+                shader.set_int("spriteTexture", 0);
+                current_shader = Some(draw.key.shader);
+            }
+            self.textures.get(&draw.key.texture).unwrap_or(&self.white).bind(0);
+            unsafe {
+                gl::DrawElements(
+                    gl::TRIANGLES,
+                    draw.index_count as i32,
+                    gl::UNSIGNED_INT,
+                    (draw.first_index * mem::size_of::<u32>()) as *const c_void,
+                );
+            }
+        }
+
+        unsafe {
+            gl::BindVertexArray(0);
+            gl::BindBuffer(gl::ARRAY_BUFFER, 0);
+            if blend == 0 {
+                gl::Disable(gl::BLEND);
+            }
+            if depth_test != 0 {
+                gl::Enable(gl::DEPTH_TEST);
+            }
+        }
+        // Textures and shaders are only kept alive until the frame that used them is drawn
+        self.textures.clear();
+        self.shaders.clear();
+        self.stats
+    }
+
+    // Son boşaltmanın istatistiklerini döndürür
+    pub fn stats(&self) -> BatchStats {
+        self.stats
+    }
+}
+
+impl Drop for SpriteBatch {
+    // Çizici silindiğinde OpenGL kaynaklarını temizler
+    fn drop(&mut self) {
+        unsafe {
+            gl::DeleteVertexArrays(1, &self.vao);
+            gl::DeleteBuffers(1, &self.vbo);
+            gl::DeleteBuffers(1, &self.ebo);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(texture: u32) -> BatchKey {
+        BatchKey { shader: 0, texture }
+    }
+
+    fn square(depth: f32) -> Quad {
+        Quad::new(Vector2D::new(0.0, 0.0), Vector2D::new(1.0, 1.0)).with_depth(depth)
+    }
+
+    #[test]
+    fn quad_rotates_around_its_origin() {
+        let quad = Quad::new(Vector2D::new(10.0, 10.0), Vector2D::new(4.0, 2.0))
+            .with_rotation(Vector2D::new(0.5, 0.5), std::f32::consts::FRAC_PI_2);
+        let corners = quad.corners();
+        // A quarter turn maps the top-left corner (-2, -1) around the centre to (1, -2)
+        assert!((corners[0].x - 11.0).abs() < 1e-5 && (corners[0].y - 8.0).abs() < 1e-5, "{:?}", corners[0]);
+        assert!((corners[2].x - 9.0).abs() < 1e-5 && (corners[2].y - 12.0).abs() < 1e-5, "{:?}", corners[2]);
+    }
+
+    #[test]
+    fn texture_sort_merges_draws() {
+        let mut queue = SpriteQueue::new();
+        for texture in [1, 2, 1, 2, 1] {
+            queue.quad(key(texture), &square(0.0));
+        }
+
+        let submission = queue.build(SortMode::Submission);
+        assert_eq!(submission.draws.len(), 5);
+
+        let sorted = queue.build(SortMode::Texture);
+        assert_eq!(
+            sorted.draws,
+            vec![
+                DrawCall { key: key(1), first_index: 0, index_count: 18 },
+                DrawCall { key: key(2), first_index: 18, index_count: 12 },
+            ]
+        );
+        // The second texture-1 quad was submitted third, so its vertices start at 8
+        assert_eq!(&sorted.indices[6..12], &[8, 9, 10, 8, 10, 11]);
+        assert_eq!(sorted.vertices.len(), 5 * 4 * VERTEX_FLOATS);
+    }
+
+    #[test]
+    fn back_to_front_orders_by_depth_first() {
+        let mut queue = SpriteQueue::new();
+        queue.quad(key(1), &square(0.0));
+        queue.quad(key(2), &square(5.0));
+        queue.quad(key(1), &square(5.0));
+
+        let geometry = queue.build(SortMode::BackToFront);
+        let keys: Vec<_> = geometry.draws.iter().map(|draw| draw.key.texture).collect();
+        assert_eq!(keys, vec![1, 2, 1]);
+        assert_eq!(geometry.indices[0], 8);
+    }
+
+    #[test]
+    fn circle_is_a_closed_fan() {
+        let mut queue = SpriteQueue::new();
+        queue.circle_segments = 8;
+        queue.circle(key(0), Vector2D::new(0.0, 0.0), 1.0, UvRect::FULL, [1.0; 4], 0.0);
+
+        let geometry = queue.build(SortMode::Texture);
+        assert_eq!(geometry.indices.len(), 8 * 3);
+        assert_eq!(&geometry.indices[21..], &[0, 8, 1]);
+        assert_eq!(geometry.vertices.len(), 9 * VERTEX_FLOATS);
+    }
+}
@@ -0,0 +1,319 @@
+use serde::de::{Deserializer, MapAccess, Visitor};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::atlas::Rect;
+use crate::locator;
+use crate::sprite_batch::UvRect;
+
+// Kare süresi verilmemiş JSON karelerinin süresi (saniye)
+const DEFAULT_FRAME_DURATION: f32 = 0.1;
+
+// Sprite sayfasındaki tek bir kare
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame {
+    pub name: String,
+    pub rect: Rect,
+    pub duration: f32,  // Seconds
+}
+
+// Sayfanın karelerinden oluşan bir animasyon dizisi
+#[derive(Debug, Clone, PartialEq)]
+pub struct Animation {
+    pub frames: Vec<usize>,  // Indices into SpriteSheet::frames
+    pub durations: Vec<f32>,  // Seconds, one per entry in `frames`
+    pub looping: bool,
+}
+
+impl Animation {
+    // Animasyonun toplam süresini döndürür
+    pub fn duration(&self) -> f32 {
+        self.durations.iter().sum()
+    }
+
+    // Verilen zamanda gösterilecek karenin sayfadaki indeksini döndürür.
+    // Döngüsüz animasyonlar son karede kalır.
+    pub fn frame_at(&self, time: f32) -> usize {
+        let total = self.duration();
+        let mut time = if self.looping && total > 0.0 { time.rem_euclid(total) } else { time.max(0.0) };
+        for (&frame, &duration) in self.frames.iter().zip(&self.durations) {
+            if time < duration {
+                return frame;
+            }
+            time -= duration;
+        }
+        self.frames.last().copied().unwrap_or(0)
+    }
+}
+
+// Tek görüntüdeki kareleri ve bu karelerden oluşan animasyonları tanımlayan sprite sayfası
+#[derive(Debug, Clone, Default)]
+pub struct SpriteSheet {
+    pub width: u32,
+    pub height: u32,
+    pub image: Option<PathBuf>,  // Image file named by a JSON sheet, relative to the asset roots
+    pub frames: Vec<Frame>,
+    pub animations: BTreeMap<String, Animation>,
+}
+
+// Aseprite / TexturePacker JSON biçimi. Kareler ad-değer eşlemesi ("hash") veya dizi ("array") olabilir.
+#[derive(Deserialize)]
+struct JsonSheet {
+    frames: JsonFrames,
+    meta: JsonMeta,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum JsonFrames {
+    Hash(OrderedFrames),
+    Array(Vec<JsonNamedFrame>),
+}
+
+// Frame tags refer to frames by position, so hash frames must keep the file's order
+struct OrderedFrames(Vec<(String, JsonFrame)>);
+
+impl<'de> Deserialize<'de> for OrderedFrames {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct OrderedVisitor;
+
+        impl<'de> Visitor<'de> for OrderedVisitor {
+            type Value = OrderedFrames;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a map of frame names to frames")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<OrderedFrames, A::Error> {
+                let mut frames = Vec::new();
+                while let Some(entry) = map.next_entry()? {
+                    frames.push(entry);
+                }
+                Ok(OrderedFrames(frames))
+            }
+        }
+
+        deserializer.deserialize_map(OrderedVisitor)
+    }
+}
+
+#[derive(Deserialize)]
+struct JsonFrame {
+    frame: JsonRect,
+    duration: Option<f32>,  // Milliseconds
+}
+
+#[derive(Deserialize)]
+struct JsonNamedFrame {
+    filename: String,
+    frame: JsonRect,
+    duration: Option<f32>,
+}
+
+#[derive(Deserialize)]
+struct JsonRect {
+    x: u32,
+    y: u32,
+    w: u32,
+    h: u32,
+}
+
+#[derive(Deserialize)]
+struct JsonMeta {
+    image: Option<String>,
+    size: JsonSize,
+    #[serde(default, rename = "frameTags")]
+    frame_tags: Vec<JsonTag>,
+}
+
+#[derive(Deserialize)]
+struct JsonSize {
+    w: u32,
+    h: u32,
+}
+
+#[derive(Deserialize)]
+struct JsonTag {
+    name: String,
+    from: usize,
+    to: usize,
+    #[serde(default)]
+    direction: Option<String>,
+}
+
+impl SpriteSheet {
+    // Görüntüyü eşit boyutlu karelere böler. Kareler soldan sağa, yukarıdan aşağıya
+    // "0", "1", ... diye adlandırılır; margin kenarlardaki, spacing kareler arasındaki boşluktur.
+    pub fn grid(width: u32, height: u32, frame_width: u32, frame_height: u32, margin: u32, spacing: u32) -> Result<SpriteSheet, String> {
+        if frame_width == 0 || frame_height == 0 {
+            return Err("frame size must not be zero".to_string());
+        }
+        let count = |size: u32, frame: u32| (size.saturating_sub(2 * margin) + spacing) / (frame + spacing);
+        let (columns, rows) = (count(width, frame_width), count(height, frame_height));
+        if columns == 0 || rows == 0 {
+            return Err(format!("{}x{} frames do not fit in a {}x{} image", frame_width, frame_height, width, height));
+        }
+
+        let mut frames = Vec::new();
+        for row in 0..rows {
+            for column in 0..columns {
+                frames.push(Frame {
+                    name: frames.len().to_string(),
+                    rect: Rect::new(
+                        margin + column * (frame_width + spacing),
+                        margin + row * (frame_height + spacing),
+                        frame_width,
+                        frame_height,
+                    ),
+                    duration: DEFAULT_FRAME_DURATION,
+                });
+            }
+        }
+        Ok(SpriteSheet { width, height, image: None, frames, animations: BTreeMap::new() })
+    }
+
+    // JSON sprite sayfasını ayrıştırır. meta.frameTags animasyon olarak eklenir;
+    // "reverse" ve "pingpong" yönleri desteklenir ve tüm etiketler döngülüdür.
+    pub fn parse_json(source: &str) -> Result<SpriteSheet, String> {
+        let sheet: JsonSheet =
+            serde_json::from_str(source).map_err(|e| format!("{}:{}: {}", e.line(), e.column(), e))?;
+
+        let frame = |name: String, rect: JsonRect, duration: Option<f32>| Frame {
+            name,
+            rect: Rect::new(rect.x, rect.y, rect.w, rect.h),
+            duration: duration.map_or(DEFAULT_FRAME_DURATION, |ms| ms / 1000.0),
+        };
+        let frames: Vec<Frame> = match sheet.frames {
+            JsonFrames::Hash(OrderedFrames(frames)) => frames.into_iter().map(|(name, f)| frame(name, f.frame, f.duration)).collect(),
+            JsonFrames::Array(frames) => frames.into_iter().map(|f| frame(f.filename, f.frame, f.duration)).collect(),
+        };
+
+        let mut result = SpriteSheet {
+            width: sheet.meta.size.w,
+            height: sheet.meta.size.h,
+            image: sheet.meta.image.map(PathBuf::from),
+            frames,
+            animations: BTreeMap::new(),
+        };
+        for tag in sheet.meta.frame_tags {
+            if tag.from > tag.to || tag.to >= result.frames.len() {
+                return Err(format!("frame tag '{}': frames {}..{} out of range", tag.name, tag.from, tag.to));
+            }
+            let forward: Vec<usize> = (tag.from..=tag.to).collect();
+            let frames = match tag.direction.as_deref() {
+                None | Some("forward") => forward,
+                Some("reverse") => forward.into_iter().rev().collect(),
+                // There and back again, without repeating either end frame
+                Some("pingpong") => {
+                    let back = forward.iter().rev().skip(1).take(forward.len().saturating_sub(2)).copied().collect::<Vec<_>>();
+                    forward.into_iter().chain(back).collect()
+                }
+                Some(other) => return Err(format!("frame tag '{}': unknown direction '{}'", tag.name, other)),
+            };
+            result.add_animation(&tag.name, frames, true)?;
+        }
+        Ok(result)
+    }
+
+    // JSON sprite sayfasını varlık klasörlerinden okur; görüntü yolu JSON dosyasına göre çözülür
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<SpriteSheet, String> {
+        let path = path.as_ref();
+        let source = locator::read_to_string(path)?;
+        let mut sheet = SpriteSheet::parse_json(&source).map_err(|e| format!("{}:{}", path.display(), e))?;
+        if let Some(image) = sheet.image.take() {
+            sheet.image = Some(path.parent().map_or(image.clone(), |parent| parent.join(image)));
+        }
+        Ok(sheet)
+    }
+
+    // Verilen karelerden, karelerin kendi süreleriyle bir animasyon ekler
+    pub fn add_animation(&mut self, name: &str, frames: Vec<usize>, looping: bool) -> Result<(), String> {
+        if let Some(&frame) = frames.iter().find(|&&frame| frame >= self.frames.len()) {
+            return Err(format!("animation '{}': frame {} does not exist", name, frame));
+        }
+        let durations = frames.iter().map(|&frame| self.frames[frame].duration).collect();
+        self.animations.insert(name.to_string(), Animation { frames, durations, looping });
+        Ok(())
+    }
+
+    // Verilen karelerden, saniyede fps kare hızında bir animasyon ekler
+    pub fn add_animation_fps(&mut self, name: &str, frames: Vec<usize>, fps: f32, looping: bool) -> Result<(), String> {
+        self.add_animation(name, frames, looping)?;
+        let animation = self.animations.get_mut(name).unwrap();
+        animation.durations.iter_mut().for_each(|duration| *duration = 1.0 / fps);
+        Ok(())
+    }
+
+    // Adı verilen karenin indeksini döndürür
+    pub fn frame_index(&self, name: &str) -> Option<usize> {
+        self.frames.iter().position(|frame| frame.name == name)
+    }
+
+    // Karenin sayfa görüntüsündeki doku koordinatlarını döndürür
+    pub fn uv(&self, frame: usize) -> UvRect {
+        self.frames[frame].rect.uv(self.width, self.height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_respects_margin_and_spacing() {
+        // 2px margin, 1px spacing: 2 + 3 * 16 + 2 * 1 + 2 = 54
+        let sheet = SpriteSheet::grid(54, 37, 16, 16, 2, 1).unwrap();
+        assert_eq!(sheet.frames.len(), 3 * 2);
+        assert_eq!(sheet.frames[4].rect, Rect::new(19, 19, 16, 16));
+        assert_eq!(sheet.frames[4].name, "4");
+        assert!(SpriteSheet::grid(8, 8, 16, 16, 0, 0).is_err());
+    }
+
+    #[test]
+    fn json_frames_and_tags_become_animations() {
+        let source = r#"{
+            "frames": {
+                "walk 2": { "frame": { "x": 0, "y": 0, "w": 8, "h": 8 }, "duration": 100 },
+                "walk 10": { "frame": { "x": 8, "y": 0, "w": 8, "h": 8 }, "duration": 200 },
+                "walk 1": { "frame": { "x": 16, "y": 0, "w": 8, "h": 8 } }
+            },
+            "meta": {
+                "image": "walk.png",
+                "size": { "w": 32, "h": 8 },
+                "frameTags": [
+                    { "name": "walk", "from": 0, "to": 2, "direction": "pingpong" },
+                    { "name": "back", "from": 1, "to": 2, "direction": "reverse" }
+                ]
+            }
+        }"#;
+        let sheet = SpriteSheet::parse_json(source).unwrap();
+
+        // Frames keep the file's order rather than sorting by name
+        assert_eq!(sheet.frame_index("walk 10"), Some(1));
+        assert_eq!(sheet.uv(1), UvRect::new([0.25, 0.0], [0.5, 1.0]));
+        assert_eq!(sheet.animations["walk"].frames, vec![0, 1, 2, 1]);
+        assert_eq!(sheet.animations["back"].frames, vec![2, 1]);
+        assert!((sheet.animations["walk"].duration() - 0.6).abs() < 1e-6);
+
+        let bad = source.replace(r#""to": 2, "direction": "reverse""#, r#""to": 5"#);
+        assert!(SpriteSheet::parse_json(&bad).unwrap_err().contains("'back'"));
+    }
+
+    #[test]
+    fn animations_loop_or_hold_the_last_frame() {
+        let mut sheet = SpriteSheet::grid(64, 16, 16, 16, 0, 0).unwrap();
+        sheet.add_animation_fps("spin", vec![0, 1, 2, 3], 10.0, true).unwrap();
+        sheet.add_animation_fps("once", vec![3, 2], 10.0, false).unwrap();
+
+        let spin = &sheet.animations["spin"];
+        assert_eq!(spin.frame_at(0.05), 0);
+        assert_eq!(spin.frame_at(0.25), 2);
+        assert_eq!(spin.frame_at(0.45), 0);
+        let once = &sheet.animations["once"];
+        assert_eq!(once.frame_at(0.15), 2);
+        assert_eq!(once.frame_at(5.0), 2);
+        assert!(sheet.add_animation("missing", vec![9], true).is_err());
+    }
+}
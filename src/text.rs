@@ -0,0 +1,497 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::rc::Rc;
+
+use crate::atlas::{Rect, SkylinePacker};
+use crate::capture::Image;
+use crate::locator;
+use crate::matrix4::Matrix4;
+use crate::shader::Shader;
+use crate::sprite_batch::{Quad, SpriteBatch};
+use crate::texture::{Texture, TextureFormat};
+use crate::vector2d::Vector2D;
+use crate::vector3d::Vector3D;
+
+// SDF glifleri her boyutta ortak kullanılmak üzere bu piksel boyutunda taranır
+const SDF_SIZE: f32 = 48.0;
+
+// Yerleşim için gereken yazı tipi ölçüleri; testlerde sahte bir yazı tipiyle değiştirilebilir
+pub trait FontMetrics {
+    // Karakterden sonra kalemin ilerleme miktarı
+    fn advance(&self, character: char, size: f32) -> f32;
+    // İki karakter arasındaki kerning düzeltmesi
+    fn kerning(&self, left: char, right: char, size: f32) -> f32;
+    // Ardışık satırların taban çizgileri arasındaki uzaklık
+    fn line_height(&self, size: f32) -> f32;
+    // Taban çizgisinin satırın üstünden uzaklığı
+    fn ascent(&self, size: f32) -> f32;
+}
+
+// TrueType/OpenType yazı tipi
+pub struct Font {
+    inner: fontdue::Font,
+}
+
+impl Font {
+    // Yazı tipi dosyasının içeriğinden bir yazı tipi oluşturur
+    pub fn from_bytes(bytes: &[u8]) -> Result<Font, String> {
+        let inner = fontdue::Font::from_bytes(bytes, fontdue::FontSettings::default()).map_err(|e| e.to_string())?;
+        Ok(Font { inner })
+    }
+
+    // egui ile gelen eş aralıklı Hack yazı tipini yükler; yazı tipi dosyası verilmediğinde yedek olarak kullanılır
+    pub fn fallback() -> Result<Font, String> {
+        let fonts = egui::FontDefinitions::default();
+        let data = fonts.font_data.get("Hack").ok_or("egui has no built-in Hack font")?;
+        Font::from_bytes(&data.font)
+    }
+
+    // Yazı tipi dosyasını varlık klasörlerinde bulup yükler
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Font, String> {
+        let path = path.as_ref();
+        let resolved = locator::resolve(path)
+            .ok_or_else(|| format!("{}: font not found in any asset directory", path.display()))?;
+        let bytes = fs::read(&resolved).map_err(|e| format!("{}: {}", resolved.display(), e))?;
+        Font::from_bytes(&bytes).map_err(|e| format!("{}: {}", resolved.display(), e))
+    }
+}
+
+impl FontMetrics for Font {
+    fn advance(&self, character: char, size: f32) -> f32 {
+        self.inner.metrics(character, size).advance_width
+    }
+
+    fn kerning(&self, left: char, right: char, size: f32) -> f32 {
+        self.inner.horizontal_kern(left, right, size).unwrap_or(0.0)
+    }
+
+    fn line_height(&self, size: f32) -> f32 {
+        self.inner.horizontal_line_metrics(size).map_or(size * 1.2, |metrics| metrics.new_line_size)
+    }
+
+    fn ascent(&self, size: f32) -> f32 {
+        self.inner.horizontal_line_metrics(size).map_or(size, |metrics| metrics.ascent)
+    }
+}
+
+// Satırların yatay hizalaması
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    Left,
+    Center,
+    Right,
+}
+
+// Yazının boyutu, rengi ve yerleşimi. max_width verilirse satırlar sözcük sınırlarında kaydırılır
+// ve hizalama bu genişliğe göre yapılır.
+#[derive(Debug, Clone, Copy)]
+pub struct TextStyle {
+    pub size: f32,  // Pixels, or world units with world_projection
+    pub color: [f32; 4],
+    pub align: Align,
+    pub max_width: Option<f32>,
+    pub line_spacing: f32,  // Multiplier of the font's line height
+}
+
+impl TextStyle {
+    // Verilen boyutta, beyaz ve sola hizalı bir stil oluşturur
+    pub fn new(size: f32) -> TextStyle {
+        TextStyle { size, color: [1.0; 4], align: Align::Left, max_width: None, line_spacing: 1.0 }
+    }
+
+    // Rengi değiştirilmiş bir kopya döndürür
+    pub fn with_color(mut self, color: [f32; 4]) -> TextStyle {
+        self.color = color;
+        self
+    }
+
+    // Hizalaması değiştirilmiş bir kopya döndürür
+    pub fn with_align(mut self, align: Align) -> TextStyle {
+        self.align = align;
+        self
+    }
+
+    // Verilen genişlikte kaydırılan bir kopya döndürür
+    pub fn wrapped(mut self, max_width: f32) -> TextStyle {
+        self.max_width = Some(max_width);
+        self
+    }
+}
+
+// Yerleştirilmiş bir karakter: kalemin x konumu ve taban çizgisinin bloğun üstünden uzaklığı (y aşağı)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlacedChar {
+    pub character: char,
+    pub x: f32,
+    pub baseline: f32,
+}
+
+// Yerleştirilmiş yazı bloğu; boşluk karakterleri çizilmediği için listede yer almaz
+#[derive(Debug, Clone, Default)]
+pub struct TextLayout {
+    pub glyphs: Vec<PlacedChar>,
+    pub width: f32,
+    pub height: f32,
+    pub lines: usize,
+}
+
+// Yazıyı satırlara böler ve karakterleri kerning ve hizalamayla yerleştirir.
+// "\n" yeni satır başlatır; kaydırmada sözcüğe sığmayan uzun sözcükler karakter sınırında bölünür.
+pub fn layout(font: &dyn FontMetrics, text: &str, style: &TextStyle) -> TextLayout {
+    let size = style.size;
+    // Each line holds its placed characters and its width without trailing spaces
+    let mut lines: Vec<(Vec<(char, f32)>, f32)> = Vec::new();
+
+    for paragraph in text.split('\n') {
+        let (mut line, mut pen, mut width, mut previous) = (Vec::new(), 0.0f32, 0.0f32, None::<char>);
+
+        let mut rest = paragraph;
+        while !rest.is_empty() {
+            // Take the next run of either spaces or non-spaces
+            let first_space = rest.starts_with(char::is_whitespace);
+            let end = rest.find(|c: char| c.is_whitespace() != first_space).unwrap_or(rest.len());
+            let (word, tail) = rest.split_at(end);
+            rest = tail;
+
+            // Spaces only move the pen; when the next word wraps they are dropped with the line's end
+            if first_space {
+                for c in word.chars() {
+                    pen += previous.map_or(0.0, |p| font.kerning(p, c, size)) + font.advance(c, size);
+                    previous = Some(c);
+                }
+                continue;
+            }
+
+            if let Some(max_width) = style.max_width {
+                let word_width: f32 = word.chars().map(|c| font.advance(c, size)).sum();
+                if !line.is_empty() && pen + word_width > max_width {
+                    lines.push((std::mem::take(&mut line), width));
+                    (pen, width, previous) = (0.0, 0.0, None);
+                }
+            }
+
+            for c in word.chars() {
+                let advance = font.advance(c, size);
+                if let Some(max_width) = style.max_width {
+                    if !line.is_empty() && pen + advance > max_width {
+                        lines.push((std::mem::take(&mut line), width));
+                        (pen, previous) = (0.0, None);
+                    }
+                }
+                pen += previous.map_or(0.0, |p| font.kerning(p, c, size));
+                line.push((c, pen));
+                pen += advance;
+                width = pen;
+                previous = Some(c);
+            }
+        }
+        lines.push((line, width));
+    }
+
+    let block_width = lines.iter().map(|(_, width)| *width).fold(0.0, f32::max);
+    let align_width = style.max_width.unwrap_or(block_width);
+    let line_height = font.line_height(size) * style.line_spacing;
+    let ascent = font.ascent(size);
+
+    let mut glyphs = Vec::new();
+    for (i, (line, width)) in lines.iter().enumerate() {
+        let offset = match style.align {
+            Align::Left => 0.0,
+            Align::Center => (align_width - width) / 2.0,
+            Align::Right => align_width - width,
+        };
+        let baseline = ascent + i as f32 * line_height;
+        glyphs.extend(line.iter().map(|&(character, x)| PlacedChar { character, x: x + offset, baseline }));
+    }
+
+    TextLayout { glyphs, width: block_width, height: lines.len() as f32 * line_height, lines: lines.len() }
+}
+
+// Glif dokusunun türü
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GlyphMode {
+    // Her boyut ayrı taranır; küçük ekran yazıları için en keskin sonuç
+    Bitmap,
+    // Glifler bir kez işaretli uzaklık alanı olarak taranır ve her boyutta ölçeklenir.
+    // Spread, alanın kenardan taştığı piksel sayısıdır.
+    Sdf { spread: u32 },
+}
+
+// Atlastaki bir glif: bölgesi ve taban çizgisindeki kaleme göre sol üst köşesinin konumu
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Glyph {
+    pub rect: Rect,
+    pub offset: [f32; 2],  // At the rasterized size
+    pub scale: f32,  // Rasterized size to requested size
+}
+
+// Tek kanallı kapsama bitmap'inden işaretli uzaklık alanı üretir. Sonuç her kenarda spread piksel büyüktür;
+// 128 değeri şeklin kenarı, üstü içi, altı dışıdır.
+pub fn signed_distance_field(coverage: &[u8], width: usize, height: usize, spread: usize) -> Vec<u8> {
+    let (out_width, out_height) = (width + 2 * spread, height + 2 * spread);
+    let inside = |x: isize, y: isize| {
+        x >= 0 && y >= 0 && (x as usize) < width && (y as usize) < height && coverage[y as usize * width + x as usize] >= 128
+    };
+
+    let mut field = vec![0u8; out_width * out_height];
+    let reach = spread as isize;
+    for y in 0..out_height as isize {
+        for x in 0..out_width as isize {
+            let (sx, sy) = (x - reach, y - reach);
+            let here = inside(sx, sy);
+            // Brute force search for the nearest pixel on the other side of the edge
+            let mut nearest = spread as f32 + 0.5;
+            for dy in -reach..=reach {
+                for dx in -reach..=reach {
+                    if inside(sx + dx, sy + dy) != here {
+                        nearest = nearest.min(((dx * dx + dy * dy) as f32).sqrt());
+                    }
+                }
+            }
+            // The edge lies halfway between the two pixels
+            let distance = if here { nearest - 0.5 } else { -(nearest - 0.5) };
+            let value = 0.5 + distance / (2.0 * spread as f32);
+            field[y as usize * out_width + x as usize] = (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+        }
+    }
+    field
+}
+
+// Taranan glifleri tek bir atlas görüntüsünde biriktiren önbellek; OpenGL'e bağımlı değildir
+pub struct GlyphCache {
+    mode: GlyphMode,
+    image: Image,
+    packer: SkylinePacker,
+    glyphs: HashMap<(char, u32), Option<Glyph>>,
+    dirty: bool,
+}
+
+impl GlyphCache {
+    // Verilen kenar uzunluğunda boş bir atlasla önbellek oluşturur
+    pub fn new(mode: GlyphMode, size: u32) -> GlyphCache {
+        GlyphCache {
+            mode,
+            image: Image::new(size, size, [255, 255, 255, 0]),
+            packer: SkylinePacker::new(size, size, 1),
+            glyphs: HashMap::new(),
+            dirty: true,
+        }
+    }
+
+    // Önbelleğin türünü döndürür
+    pub fn mode(&self) -> GlyphMode {
+        self.mode
+    }
+
+    // Atlas görüntüsünü döndürür; beyaz üzerine alfa kanalında kapsama veya uzaklık tutar
+    pub fn image(&self) -> &Image {
+        &self.image
+    }
+
+    // Son çağrıdan beri yeni glif eklendiyse true döndürür
+    pub fn take_dirty(&mut self) -> bool {
+        std::mem::replace(&mut self.dirty, false)
+    }
+
+    // Karakterin glifini döndürür, gerekirse tarayıp atlasa ekler.
+    // Boşluk gibi görünmeyen karakterler ve atlasa sığmayan glifler için None döner.
+    pub fn glyph(&mut self, font: &Font, character: char, size: f32) -> Option<Glyph> {
+        let raster_size = match self.mode {
+            GlyphMode::Bitmap => size.round().max(1.0),
+            GlyphMode::Sdf { .. } => SDF_SIZE,
+        };
+        let key = (character, raster_size as u32);
+        if let Some(glyph) = self.glyphs.get(&key) {
+            return glyph.map(|glyph| Glyph { scale: size / raster_size, ..glyph });
+        }
+
+        let (metrics, coverage) = font.inner.rasterize(character, raster_size);
+        let glyph = if metrics.width == 0 || metrics.height == 0 {
+            None
+        } else {
+            let (bitmap, width, height, margin) = match self.mode {
+                GlyphMode::Bitmap => (coverage, metrics.width, metrics.height, 0),
+                GlyphMode::Sdf { spread } => {
+                    let spread = spread as usize;
+                    let field = signed_distance_field(&coverage, metrics.width, metrics.height, spread);
+                    (field, metrics.width + 2 * spread, metrics.height + 2 * spread, spread)
+                }
+            };
+            self.insert(&bitmap, width as u32, height as u32).map(|rect| Glyph {
+                rect,
+                // fontdue measures ymin upwards from the baseline, the atlas rows go downwards
+                offset: [
+                    metrics.xmin as f32 - margin as f32,
+                    -(metrics.ymin as f32 + metrics.height as f32) - margin as f32,
+                ],
+                scale: 1.0,
+            })
+        };
+
+        self.glyphs.insert(key, glyph);
+        glyph.map(|glyph| Glyph { scale: size / raster_size, ..glyph })
+    }
+
+    // Tek kanallı bitmap'i atlasın alfa kanalına yerleştirir
+    fn insert(&mut self, bitmap: &[u8], width: u32, height: u32) -> Option<Rect> {
+        let rect = self.packer.pack(width, height)?;
+        for y in 0..height {
+            for x in 0..width {
+                let alpha = bitmap[(y * width + x) as usize];
+                self.image.set_pixel(rect.x + x, rect.y + y, [255, 255, 255, alpha]);
+            }
+        }
+        self.dirty = true;
+        Some(rect)
+    }
+}
+
+// Dünya uzayında yazı için SpriteBatch izdüşümü: model matrisiyle yerleştirilen yazının bir pikseli
+// units_per_pixel dünya birimi olur ve y ekseni yukarı çevrilir
+pub fn world_projection(view_projection: &Matrix4, model: &Matrix4, units_per_pixel: f32) -> Matrix4 {
+    *view_projection * *model * Matrix4::scale(Vector3D::new(units_per_pixel, -units_per_pixel, 1.0))
+}
+
+// Yazıları glif atlasıyla SpriteBatch'e dörtgen olarak ekleyen çizici
+pub struct TextRenderer {
+    font: Font,
+    cache: GlyphCache,
+    texture: Rc<Texture>,
+    sdf_shader: Option<Rc<Shader>>,
+}
+
+impl TextRenderer {
+    // Glif atlasının kenar uzunluğu
+    const ATLAS_SIZE: u32 = 1024;
+
+    // Verilen yazı tipi ve glif türüyle bir çizici oluşturur
+    pub fn new(font: Font, mode: GlyphMode) -> TextRenderer {
+        let cache = GlyphCache::new(mode, TextRenderer::ATLAS_SIZE);
+        let image = cache.image();
+        let texture = Texture::from_data(image.width, image.height, TextureFormat::Rgba8, Some(&image.pixels));
+        let sdf_shader = match mode {
+            GlyphMode::Bitmap => None,
+            GlyphMode::Sdf { .. } => Some(Rc::new(Shader::load("sprite_vertex_shader.glsl", "text_sdf_fragment_shader.glsl"))),
+        };
+        TextRenderer { font, cache, texture: Rc::new(texture), sdf_shader }
+    }
+
+    // Yazı tipini döndürür
+    pub fn font(&self) -> &Font {
+        &self.font
+    }
+
+    // Yazının çizileceği bloğun genişliğini ve yüksekliğini döndürür
+    pub fn measure(&self, text: &str, style: &TextStyle) -> (f32, f32) {
+        let layout = layout(&self.font, text, style);
+        (layout.width, layout.height)
+    }
+
+    // Yazıyı sol üst köşesi verilen konumda olacak şekilde çiziciye ekler.
+    // Ekran uzayında SpriteBatch::screen_projection, dünya uzayında world_projection ile boşaltılmalıdır.
+    pub fn draw(&mut self, batch: &mut SpriteBatch, text: &str, position: Vector2D, style: &TextStyle) {
+        let layout = layout(&self.font, text, style);
+        let mut quads = Vec::with_capacity(layout.glyphs.len());
+        for placed in &layout.glyphs {
+            if let Some(glyph) = self.cache.glyph(&self.font, placed.character, style.size) {
+                let corner = Vector2D::new(placed.x + glyph.offset[0] * glyph.scale, placed.baseline + glyph.offset[1] * glyph.scale);
+                let size = Vector2D::new(glyph.rect.width as f32, glyph.rect.height as f32) * glyph.scale;
+                let uv = glyph.rect.uv(self.cache.image().width, self.cache.image().height);
+                quads.push(Quad::new(position + corner, size).with_uv(uv).with_color(style.color));
+            }
+        }
+
+        // The atlas only grows, so glyphs queued earlier in the frame stay valid after the update
+        if self.cache.take_dirty() {
+            self.texture.update(&self.cache.image().pixels);
+        }
+
+        batch.set_shader(self.sdf_shader.clone());
+        for quad in &quads {
+            batch.quad(Some(&self.texture), quad);
+        }
+        batch.set_shader(None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Every character is 10 units wide, except "i" (4); "AV" kerns by -2
+    struct FixedFont;
+
+    impl FontMetrics for FixedFont {
+        fn advance(&self, character: char, size: f32) -> f32 {
+            if character == 'i' { 0.4 * size } else { size }
+        }
+
+        fn kerning(&self, left: char, right: char, size: f32) -> f32 {
+            if (left, right) == ('A', 'V') { -0.2 * size } else { 0.0 }
+        }
+
+        fn line_height(&self, size: f32) -> f32 {
+            1.5 * size
+        }
+
+        fn ascent(&self, size: f32) -> f32 {
+            size
+        }
+    }
+
+    fn positions(layout: &TextLayout) -> Vec<(char, f32, f32)> {
+        layout.glyphs.iter().map(|g| (g.character, g.x, g.baseline)).collect()
+    }
+
+    #[test]
+    fn kerning_and_line_breaks() {
+        let layout = layout(&FixedFont, "AVi\nA", &TextStyle::new(10.0));
+        assert_eq!(positions(&layout), vec![('A', 0.0, 10.0), ('V', 8.0, 10.0), ('i', 18.0, 10.0), ('A', 0.0, 25.0)]);
+        assert_eq!(layout.lines, 2);
+        assert_eq!((layout.width, layout.height), (22.0, 30.0));
+    }
+
+    #[test]
+    fn wraps_at_word_boundaries_and_aligns() {
+        let style = TextStyle::new(10.0).wrapped(45.0).with_align(Align::Right);
+        let layout = layout(&FixedFont, "ab cd ef", &style);
+        // "ab cd" is 50 wide, so every word starts a new line, without its leading space
+        assert_eq!(layout.lines, 3);
+        assert_eq!(positions(&layout)[2..4], [('c', 25.0, 25.0), ('d', 35.0, 25.0)]);
+
+        let centered = layout_of("abcdefgh", TextStyle::new(10.0).wrapped(30.0).with_align(Align::Center));
+        // A word longer than the box breaks between characters
+        assert_eq!(centered.lines, 3);
+        assert_eq!(centered.glyphs[6].x, 5.0);
+    }
+
+    fn layout_of(text: &str, style: TextStyle) -> TextLayout {
+        layout(&FixedFont, text, &style)
+    }
+
+    #[test]
+    fn distance_field_is_half_on_the_edge() {
+        // A 4x4 filled square in the middle of an 8x8 bitmap
+        let mut coverage = vec![0u8; 64];
+        for y in 2..6 {
+            for x in 2..6 {
+                coverage[y * 8 + x] = 255;
+            }
+        }
+        let field = signed_distance_field(&coverage, 8, 8, 4);
+        let at = |x: usize, y: usize| field[(y + 4) * 16 + x + 4];
+
+        assert!(at(3, 3) > 128 && at(2, 3) > 128, "{} {}", at(3, 3), at(2, 3));
+        assert!(at(1, 3) < 128);
+        assert!(at(3, 3) > at(2, 3));
+        assert_eq!(field[0], 0);
+    }
+
+    #[test]
+    fn fallback_font_is_monospaced() {
+        let font = Font::fallback().unwrap();
+        assert!(font.ascent(16.0) > 0.0);
+        assert_eq!(font.advance('i', 16.0), font.advance('W', 16.0));
+    }
+}
@@ -0,0 +1,160 @@
+extern crate gl;
+
+use gl::types::{GLenum, GLint, GLuint};
+use std::ffi::c_void;
+use std::ptr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextureFormat {
+    R8,
+    Rgba8,
+    Srgb8Alpha8,
+    R32F,
+    Rg16F,
+    Rgba16F,
+    Rgba32F,
+    Depth24,
+    Depth32F,
+    Depth24Stencil8,
+}
+
+impl TextureFormat {
+    // OpenGL iç formatını döndürür
+    pub fn internal_format(&self) -> GLenum {
+        match self {
+            TextureFormat::R8 => gl::R8,
+            TextureFormat::Rgba8 => gl::RGBA8,
+            TextureFormat::Srgb8Alpha8 => gl::SRGB8_ALPHA8,
+            TextureFormat::R32F => gl::R32F,
+            TextureFormat::Rg16F => gl::RG16F,
+            TextureFormat::Rgba16F => gl::RGBA16F,
+            TextureFormat::Rgba32F => gl::RGBA32F,
+            TextureFormat::Depth24 => gl::DEPTH_COMPONENT24,
+            TextureFormat::Depth32F => gl::DEPTH_COMPONENT32F,
+            TextureFormat::Depth24Stencil8 => gl::DEPTH24_STENCIL8,
+        }
+    }
+
+    // Piksel verisinin formatını ve bileşen tipini döndürür
+    pub fn pixel_format(&self) -> (GLenum, GLenum) {
+        match self {
+            TextureFormat::R8 => (gl::RED, gl::UNSIGNED_BYTE),
+            TextureFormat::Rgba8 | TextureFormat::Srgb8Alpha8 => (gl::RGBA, gl::UNSIGNED_BYTE),
+            TextureFormat::R32F => (gl::RED, gl::FLOAT),
+            TextureFormat::Rg16F => (gl::RG, gl::FLOAT),
+            TextureFormat::Rgba16F | TextureFormat::Rgba32F => (gl::RGBA, gl::FLOAT),
+            TextureFormat::Depth24 | TextureFormat::Depth32F => (gl::DEPTH_COMPONENT, gl::FLOAT),
+            TextureFormat::Depth24Stencil8 => (gl::DEPTH_STENCIL, gl::UNSIGNED_INT_24_8),
+        }
+    }
+
+    // Formatın derinlik (ve/veya stencil) formatı olup olmadığını döndürür
+    pub fn is_depth(&self) -> bool {
+        matches!(self, TextureFormat::Depth24 | TextureFormat::Depth32F | TextureFormat::Depth24Stencil8)
+    }
+
+    // Formatın stencil bileşeni içerip içermediğini döndürür
+    pub fn has_stencil(&self) -> bool {
+        matches!(self, TextureFormat::Depth24Stencil8)
+    }
+}
+
+pub struct Texture {
+    id: GLuint,
+    width: u32,
+    height: u32,
+    format: TextureFormat,
+}
+
+impl Texture {
+    // Verilen boyut ve formatta boş bir 2D doku oluşturur
+    pub fn new(width: u32, height: u32, format: TextureFormat) -> Texture {
+        Texture::from_data(width, height, format, None)
+    }
+
+    // Verilen piksel verisiyle (formatın bileşen tipinde) bir 2D doku oluşturur
+    pub fn from_data(width: u32, height: u32, format: TextureFormat, data: Option<&[u8]>) -> Texture {
+        let mut texture = Texture { id: 0, width, height, format };
+        let filter = if format.is_depth() { gl::NEAREST } else { gl::LINEAR };
+
+        unsafe {
+            gl::GenTextures(1, &mut texture.id);
+            gl::BindTexture(gl::TEXTURE_2D, texture.id);
+            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MIN_FILTER, filter as GLint);
+            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MAG_FILTER, filter as GLint);
+            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_WRAP_S, gl::CLAMP_TO_EDGE as GLint);
+            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_WRAP_T, gl::CLAMP_TO_EDGE as GLint);
+            gl::BindTexture(gl::TEXTURE_2D, 0);
+        }
+
+        texture.upload(data);
+        texture
+    }
+
+    // Doku belleğini ayırır ve varsa veriyi yükler
+    fn upload(&self, data: Option<&[u8]>) {
+        let (pixel_format, pixel_type) = self.format.pixel_format();
+        let pixels = data.map_or(ptr::null(), |bytes| bytes.as_ptr() as *const c_void);
+
+        unsafe {
+            gl::BindTexture(gl::TEXTURE_2D, self.id);
+            gl::PixelStorei(gl::UNPACK_ALIGNMENT, 1);
+            gl::TexImage2D(
+                gl::TEXTURE_2D,
+                0,
+                self.format.internal_format() as GLint,
+                self.width as i32,
+                self.height as i32,
+                0,
+                pixel_format,
+                pixel_type,
+                pixels,
+            );
+            gl::PixelStorei(gl::UNPACK_ALIGNMENT, 4);
+            gl::BindTexture(gl::TEXTURE_2D, 0);
+        }
+    }
+
+    // Dokunun tüm piksel verisini (formatın bileşen tipinde) değiştirir; doku ID'si aynı kalır
+    pub fn update(&self, data: &[u8]) {
+        self.upload(Some(data));
+    }
+
+    // Dokuyu verilen doku birimine bağlar
+    pub fn bind(&self, unit: u32) {
+        unsafe {
+            gl::ActiveTexture(gl::TEXTURE0 + unit);
+            gl::BindTexture(gl::TEXTURE_2D, self.id);
+            gl::ActiveTexture(gl::TEXTURE0);
+        }
+    }
+
+    // Doku ID'sini döndürür
+    pub fn id(&self) -> GLuint {
+        self.id
+    }
+
+    // Doku genişliğini döndürür
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    // Doku yüksekliğini döndürür
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    // Doku formatını döndürür
+    pub fn format(&self) -> TextureFormat {
+        self.format
+    }
+}
+
+impl Drop for Texture {
+    // Doku silindiğinde OpenGL kaynaklarını temizler
+    fn drop(&mut self) {
+        unsafe {
+            gl::DeleteTextures(1, &self.id);
+        }
+    }
+}
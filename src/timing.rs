@@ -0,0 +1,163 @@
+use std::thread;
+use std::time::{Duration, Instant};
+
+// Tek karede kabul edilen en uzun süre; hata ayıklayıcıda durma gibi uzun duraklamalardan sonra
+// simülasyonun bir anda ileri atlamasını önler
+const MAX_FRAME_TIME: f32 = 0.25;
+
+// Kareler arasında geçen süreyi ölçen saat
+pub struct Clock {
+    start: Instant,
+    last: Instant,
+}
+
+impl Clock {
+    // Yeni bir saat oluşturur
+    pub fn new() -> Clock {
+        let now = Instant::now();
+        Clock { start: now, last: now }
+    }
+
+    // Son çağrıdan bu yana geçen süreyi saniye cinsinden döndürür
+    pub fn tick(&mut self) -> f32 {
+        let now = Instant::now();
+        let delta = now.duration_since(self.last).as_secs_f32();
+        self.last = now;
+        delta.min(MAX_FRAME_TIME)
+    }
+
+    // Saatin oluşturulmasından bu yana geçen toplam süreyi döndürür
+    pub fn elapsed(&self) -> f32 {
+        self.start.elapsed().as_secs_f32()
+    }
+}
+
+// Kare hızından bağımsız, sabit adımlarla ilerleyen güncelleme zamanlayıcısı
+pub struct FixedTimestep {
+    step: f32,
+    accumulator: f32,
+    max_steps: u32,
+}
+
+impl FixedTimestep {
+    // Saniyede verilen sayıda güncelleme yapan bir zamanlayıcı oluşturur
+    pub fn new(updates_per_second: u32) -> FixedTimestep {
+        FixedTimestep { step: 1.0 / updates_per_second.max(1) as f32, accumulator: 0.0, max_steps: 8 }
+    }
+
+    // Bir güncelleme adımının süresini döndürür
+    pub fn step(&self) -> f32 {
+        self.step
+    }
+
+    // Geçen süreyi ekler ve bu karede çalıştırılması gereken güncelleme sayısını döndürür.
+    // Yavaş makinelerde adım sayısı sınırlanır ve yetişilemeyen süre atılır.
+    pub fn advance(&mut self, delta: f32) -> u32 {
+        self.accumulator += delta;
+        let steps = (self.accumulator / self.step).floor() as u32;
+        if steps > self.max_steps {
+            self.accumulator = 0.0;
+            return self.max_steps;
+        }
+        self.accumulator -= steps as f32 * self.step;
+        steps
+    }
+
+    // Son güncelleme ile bir sonraki arasındaki konumu [0, 1) aralığında döndürür
+    pub fn alpha(&self) -> f32 {
+        (self.accumulator / self.step).clamp(0.0, 1.0)
+    }
+}
+
+// Kare hızını verilen üst sınırda tutmak için kareler arasında bekler
+pub struct FrameLimiter {
+    period: Option<Duration>,
+    next: Instant,
+}
+
+impl FrameLimiter {
+    // Saniyede en fazla `max_fps` kare çizen bir sınırlayıcı oluşturur (None: sınırsız)
+    pub fn new(max_fps: Option<u32>) -> FrameLimiter {
+        let period = max_fps.filter(|&fps| fps > 0).map(|fps| Duration::from_secs_f64(1.0 / fps as f64));
+        FrameLimiter { period, next: Instant::now() }
+    }
+
+    // Bir sonraki karenin zamanı gelene kadar bekler
+    pub fn wait(&mut self) {
+        let Some(period) = self.period else {
+            return;
+        };
+
+        let now = Instant::now();
+        if self.next > now {
+            thread::sleep(self.next - now);
+        }
+        // Falling behind resets the schedule instead of rendering a burst of frames to catch up
+        self.next = (self.next + period).max(Instant::now());
+    }
+}
+
+// Kare hızını yarım saniyelik aralıklarla ölçer; ekranda okunabilir, titremeyen bir değer verir
+pub struct FpsCounter {
+    frames: u32,
+    elapsed: f32,
+    fps: f32,
+}
+
+impl FpsCounter {
+    // Ölçüm aralığı (saniye)
+    const INTERVAL: f32 = 0.5;
+
+    // Yeni bir sayaç oluşturur
+    pub fn new() -> FpsCounter {
+        FpsCounter { frames: 0, elapsed: 0.0, fps: 0.0 }
+    }
+
+    // Bir karenin süresini ekler
+    pub fn tick(&mut self, delta: f32) {
+        self.frames += 1;
+        self.elapsed += delta;
+        if self.elapsed >= FpsCounter::INTERVAL {
+            self.fps = self.frames as f32 / self.elapsed;
+            self.frames = 0;
+            self.elapsed = 0.0;
+        }
+    }
+
+    // Son tamamlanan aralığın kare hızını döndürür
+    pub fn fps(&self) -> f32 {
+        self.fps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_timestep_accumulates_partial_frames() {
+        let mut timestep = FixedTimestep::new(60);
+        assert_eq!(timestep.advance(0.5 / 60.0), 0);
+        assert!((timestep.alpha() - 0.5).abs() < 1e-4);
+        assert_eq!(timestep.advance(1.0 / 60.0), 1);
+        assert!((timestep.alpha() - 0.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn fixed_timestep_limits_catch_up_steps() {
+        let mut timestep = FixedTimestep::new(60);
+        assert_eq!(timestep.advance(1.0), 8);
+        assert_eq!(timestep.alpha(), 0.0);
+    }
+
+    #[test]
+    fn fps_counter_averages_over_an_interval() {
+        let mut counter = FpsCounter::new();
+        for _ in 0..15 {
+            counter.tick(1.0 / 32.0);
+        }
+        assert_eq!(counter.fps(), 0.0);
+        counter.tick(1.0 / 32.0);
+        assert_eq!(counter.fps(), 32.0);
+    }
+}
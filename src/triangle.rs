@@ -1,9 +1,12 @@
 extern crate gl;
 
+use crate::color::{self, Color, VertexColors};
 use crate::shader::Shader;
 use std::ptr;
 use gl::types::{GLuint, GLfloat};
-use crate::draw::Shape;
+use crate::draw::{RenderContext, Shape};
+use crate::matrix4::Matrix4;
+use crate::mesh::MeshData;
 use std::rc::Rc;  // Import Rc for reference counting
 use crate::vector2d::Vector2D;  // Import Vector2D
 
@@ -13,9 +16,13 @@ pub struct Triangle {
     shader: Rc<Shader>,
     vertices: [Vector2D; 3],
     normals: [Vector2D; 3],
+    pub color: Color,  // Fill color, used by the flat color shader
+    colors: VertexColors,
 }
 
 impl Triangle {
+    pub const VERTEX_COLORS: usize = 3;
+
     // Yeni bir üçgen oluşturur
     pub fn new(shader: Rc<Shader>, v1: Vector2D, v2: Vector2D, v3: Vector2D) -> Triangle {
         let edge1 = Vector2D::new(v2.x - v1.x, v2.y - v1.y);
@@ -28,10 +35,21 @@ impl Triangle {
             shader,
             vertices: [v1, v2, v3],
             normals: [normal, normal, normal],
+            color: Color::WHITE,
+            colors: VertexColors::new(),
         };
         triangle.init();
         triangle
     }
+
+    // Köşelere sırasıyla v1, v2 ve v3 için birer renk verir; renkler yüzey boyunca karışır
+    pub fn set_vertex_colors(&mut self, colors: &[Color]) -> Result<(), String> {
+        if colors.len() != Triangle::VERTEX_COLORS {
+            return Err(format!("a triangle takes {} vertex colors, got {}", Triangle::VERTEX_COLORS, colors.len()));
+        }
+        self.colors.set(colors.to_vec());
+        Ok(())
+    }
 }
 
 impl Shape for Triangle {
@@ -66,17 +84,31 @@ impl Shape for Triangle {
             gl::VertexAttribPointer(1, 3, gl::FLOAT, gl::FALSE, 6 * std::mem::size_of::<GLfloat>() as i32, (3 * std::mem::size_of::<GLfloat>()) as *const _);
             gl::EnableVertexAttribArray(1);
 
+            self.colors.attach(2, 3);
+
             gl::BindVertexArray(0);
         }
     }
 
     // Üçgeni çizer
-    fn draw(&self) {
-        unsafe {
-            self.shader.use_program();  // Use the shader before drawing
+    fn draw(&self, ctx: &RenderContext, model: &Matrix4) {
+        self.shader.use_program();  // Use the shader before drawing
+        ctx.apply_2d(&self.shader, model);
+        self.shader.set_vec4("color", ctx.color(self.color));
+        color::draw_blended(self.color.a < 1.0 || self.colors.translucent(), || unsafe {
             gl::BindVertexArray(self.vao);
             gl::DrawArrays(gl::TRIANGLES, 0, 3);
             gl::BindVertexArray(0);
-        }
+        });
+    }
+
+    // Üçgeni z = 0 düzleminde döndürür
+    fn mesh_data(&self) -> Option<MeshData> {
+        Some(MeshData {
+            positions: self.vertices.iter().map(|v| [v.x, v.y, 0.0]).collect(),
+            normals: vec![[0.0, 0.0, 1.0]; 3],
+            uvs: Vec::new(),
+            indices: vec![0, 1, 2],
+        })
     }
 }
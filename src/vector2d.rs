@@ -13,19 +13,16 @@ impl Vector2D {
     }
 
     // Sıfır vektörü oluşturur
-    #[allow(dead_code)]
     pub fn zero() -> Self {
         Vector2D { x: 0.0, y: 0.0 }
     }
 
     // Vektörün büyüklüğünü hesaplar
-    #[allow(dead_code)]
     pub fn magnitude(&self) -> f32 {
         (self.x * self.x + self.y * self.y).sqrt()
     }
 
     // Vektörü normalleştirir
-    #[allow(dead_code)]
     pub fn normalize(&self) -> Self {
         let mag = self.magnitude();
         if mag != 0.0 {
@@ -39,7 +36,6 @@ impl Vector2D {
     }
 
     // İki vektörün nokta çarpımını hesaplar
-    #[allow(dead_code)]
     pub fn dot(&self, other: &Vector2D) -> f32 {
         self.x * other.x + self.y * other.y
     }
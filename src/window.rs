@@ -1,14 +1,21 @@
-use glfw::{fail_on_errors, Action, Context, Glfw, GlfwReceiver, Key, PWindow, WindowEvent, WindowMode};
+use glfw::{fail_on_errors, log_errors, Action, Context, ContextCreationApi, Glfw, GlfwReceiver, Key, PWindow, WindowEvent, WindowMode};
+
+use crate::color::Color;
+
+// Pencerenin (sRGB) arka plan rengi
+pub const CLEAR_COLOR: Color = Color { r: 0.2, g: 0.3, b: 0.3, a: 1.0 };
 
 pub struct GlWindow {
     pub glfw: Glfw,
     pub window: PWindow,
     pub events: GlfwReceiver<(f64, WindowEvent)>,
+    screenshot_requested: bool,
+    recording_toggle_requested: bool,
 }
 
 impl GlWindow {
-    // Yeni bir GlWindow oluşturur
-    pub fn new(title: &str, width: u32, height: u32) -> GlWindow {
+    // Yeni bir GlWindow oluşturur; `fullscreen` ise pencere birincil monitörde tam ekran açılır
+    pub fn new(title: &str, width: u32, height: u32, fullscreen: bool) -> GlWindow {
         let mut glfw = glfw::init(fail_on_errors!()).unwrap_or_else(|e| {
             panic!("Failed to initialize GLFW: {:?}", e);
         });
@@ -17,13 +24,52 @@ impl GlWindow {
         glfw.window_hint(glfw::WindowHint::OpenGlProfile(glfw::OpenGlProfileHint::Compat));
 
         let (mut window, events) = glfw
-            .create_window(width, height, title, WindowMode::Windowed)
+            .with_primary_monitor(|glfw, monitor| {
+                let mode = match monitor {
+                    Some(monitor) if fullscreen => WindowMode::FullScreen(monitor),
+                    _ => WindowMode::Windowed,
+                };
+                glfw.create_window(width, height, title, mode)
+            })
             .expect("Failed to create GLFW window.");
 
         window.make_current();
+        glfw.set_swap_interval(glfw::SwapInterval::Sync(1));
         window.set_key_polling(true);
+        window.set_framebuffer_size_polling(true);
+        // Mouse and text input feed the GUI overlay
+        window.set_cursor_pos_polling(true);
+        window.set_cursor_enter_polling(true);
+        window.set_mouse_button_polling(true);
+        window.set_scroll_polling(true);
+        window.set_char_polling(true);
+        window.set_focus_polling(true);
 
-        GlWindow { glfw, window, events }
+        GlWindow { glfw, window, events, screenshot_requested: false, recording_toggle_requested: false }
+    }
+
+    // Görünmez bir pencereyle ekransız (headless) bir GlWindow oluşturur.
+    // Egl ve OsMesa bağlamları Mesa'nın llvmpipe yazılım çizicisiyle GPU olmadan çalışabilir;
+    // Native bağlamda yazılım çizici için LIBGL_ALWAYS_SOFTWARE=1 ortam değişkeni kullanılabilir.
+    pub fn new_headless(width: u32, height: u32, api: ContextCreationApi) -> Result<GlWindow, String> {
+        // Errors are logged instead of panicking so callers can fall back when no context is available
+        let mut glfw = glfw::init(log_errors!()).map_err(|e| format!("Failed to initialize GLFW: {:?}", e))?;
+
+        glfw.window_hint(glfw::WindowHint::ContextVersion(3, 3));
+        glfw.window_hint(glfw::WindowHint::OpenGlProfile(glfw::OpenGlProfileHint::Compat));
+        glfw.window_hint(glfw::WindowHint::ContextCreationApi(api));
+        glfw.window_hint(glfw::WindowHint::Visible(false));
+        glfw.window_hint(glfw::WindowHint::Resizable(false));
+
+        let (mut window, events) = glfw
+            .create_window(width, height, "OpenGL Shape Renderer (headless)", WindowMode::Windowed)
+            .ok_or_else(|| format!("Failed to create a headless {:?} context", api))?;
+
+        window.make_current();
+        // Offscreen frames are never presented, so waiting for vertical sync would only slow them down
+        glfw.set_swap_interval(glfw::SwapInterval::None);
+
+        Ok(GlWindow { glfw, window, events, screenshot_requested: false, recording_toggle_requested: false })
     }
 
     // OpenGL'i başlatır
@@ -37,7 +83,8 @@ impl GlWindow {
         println!("OpenGL version: {}", version);
         
         unsafe {
-            gl::ClearColor(0.2, 0.3, 0.3, 1.0);
+            let [r, g, b, a] = CLEAR_COLOR.to_array();
+            gl::ClearColor(r, g, b, a);
     
             let (width, height) = self.window.get_size();
             gl::Viewport(0, 0, width, height);
@@ -50,7 +97,32 @@ impl GlWindow {
             WindowEvent::Key(Key::Escape, _, Action::Press, _) => {
                 self.window.set_should_close(true);
             }
+            WindowEvent::Key(Key::F12, _, Action::Press, _) => {
+                self.screenshot_requested = true;
+            }
+            WindowEvent::Key(Key::F9, _, Action::Press, _) => {
+                self.recording_toggle_requested = true;
+            }
+            WindowEvent::FramebufferSize(width, height) => unsafe {
+                gl::Viewport(0, 0, width, height);
+            },
             _ => {}
         }
     }
-}
\ No newline at end of file
+
+    // Dikey senkronizasyonu (swap interval) açar veya kapatır
+    pub fn set_vsync(&mut self, enabled: bool) {
+        let interval = if enabled { glfw::SwapInterval::Sync(1) } else { glfw::SwapInterval::None };
+        self.glfw.set_swap_interval(interval);
+    }
+
+    // Ekran görüntüsü istendiyse (F12) isteği tüketir ve true döndürür
+    pub fn take_screenshot_request(&mut self) -> bool {
+        std::mem::take(&mut self.screenshot_requested)
+    }
+
+    // Kayıt başlatma/durdurma istendiyse (F9) isteği tüketir ve true döndürür
+    pub fn take_recording_toggle_request(&mut self) -> bool {
+        std::mem::take(&mut self.recording_toggle_requested)
+    }
+}